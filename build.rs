@@ -0,0 +1,28 @@
+//! Exposes the versions of the arbiter crates the binary is built against, as
+//! resolved in `Cargo.lock`, so run directories can record them.
+
+use std::fs;
+
+fn main() {
+    println!("cargo:rerun-if-changed=Cargo.lock");
+    let lock = fs::read_to_string("Cargo.lock").unwrap_or_default();
+    for (package, variable) in [
+        ("arbiter-core", "ARBITER_CORE_VERSION"),
+        ("arbiter-engine", "ARBITER_ENGINE_VERSION"),
+    ] {
+        let version = locked_version(&lock, package).unwrap_or("unknown");
+        println!("cargo:rustc-env={}={}", variable, version);
+    }
+}
+
+/// The version of `package` in a `Cargo.lock`, which lists the `version` of
+/// each package on the line after its `name`.
+fn locked_version<'a>(lock: &'a str, package: &str) -> Option<&'a str> {
+    let name = format!("name = \"{}\"", package);
+    let mut lines = lock.lines();
+    lines.find(|line| *line == name)?;
+    lines
+        .next()?
+        .strip_prefix("version = \"")?
+        .strip_suffix('"')
+}
@@ -0,0 +1,121 @@
+// SPDX-License-Identifier: UNLICENSED
+pragma solidity ^0.8.13;
+
+interface IERC20 {
+    function transfer(address to, uint256 amount) external returns (bool);
+    function transferFrom(address from, address to, uint256 amount) external returns (bool);
+}
+
+/// A pool trading `base` against `quote` along `baseReserve * quoteReserve = k`,
+/// charging `feeBps` of every input.
+contract ConstantProductPool {
+    uint256 public constant FEE_DENOMINATOR = 10_000;
+
+    IERC20 public immutable base;
+    IERC20 public immutable quote;
+    uint256 public immutable feeBps;
+
+    uint256 public baseReserve;
+    uint256 public quoteReserve;
+    uint256 public totalShares;
+    mapping(address => uint256) public sharesOf;
+
+    event LiquidityAdded(address indexed provider, uint256 baseAmount, uint256 quoteAmount, uint256 shares);
+    event LiquidityRemoved(address indexed provider, uint256 baseAmount, uint256 quoteAmount, uint256 shares);
+    event Swap(address indexed trader, bool sellBase, uint256 amountIn, uint256 amountOut);
+    event Sync(uint256 baseReserve, uint256 quoteReserve);
+
+    error InvalidFee(uint256 feeBps);
+    error ZeroAmount();
+    error InsufficientShares(uint256 shares, uint256 needed);
+    error InsufficientOutput(uint256 amountOut, uint256 minAmountOut);
+
+    constructor(address base_, address quote_, uint256 feeBps_) {
+        if (feeBps_ >= FEE_DENOMINATOR) revert InvalidFee(feeBps_);
+        base = IERC20(base_);
+        quote = IERC20(quote_);
+        feeBps = feeBps_;
+    }
+
+    function getReserves() public view returns (uint256, uint256) {
+        return (baseReserve, quoteReserve);
+    }
+
+    /// Output of swapping `amountIn` of base for quote if `sellBase`, of quote
+    /// for base otherwise.
+    function getAmountOut(bool sellBase, uint256 amountIn) public view returns (uint256) {
+        (uint256 reserveIn, uint256 reserveOut) = sellBase ? (baseReserve, quoteReserve) : (quoteReserve, baseReserve);
+        uint256 amountInWithFee = amountIn * (FEE_DENOMINATOR - feeBps);
+        return (amountInWithFee * reserveOut) / (reserveIn * FEE_DENOMINATOR + amountInWithFee);
+    }
+
+    function addLiquidity(uint256 baseAmount, uint256 quoteAmount) external returns (uint256 shares) {
+        if (baseAmount == 0 || quoteAmount == 0) revert ZeroAmount();
+        if (totalShares == 0) {
+            shares = sqrt(baseAmount * quoteAmount);
+        } else {
+            shares = min((baseAmount * totalShares) / baseReserve, (quoteAmount * totalShares) / quoteReserve);
+        }
+        if (shares == 0) revert ZeroAmount();
+        base.transferFrom(msg.sender, address(this), baseAmount);
+        quote.transferFrom(msg.sender, address(this), quoteAmount);
+        baseReserve += baseAmount;
+        quoteReserve += quoteAmount;
+        totalShares += shares;
+        sharesOf[msg.sender] += shares;
+        emit LiquidityAdded(msg.sender, baseAmount, quoteAmount, shares);
+        emit Sync(baseReserve, quoteReserve);
+    }
+
+    function removeLiquidity(uint256 shares) external returns (uint256 baseAmount, uint256 quoteAmount) {
+        if (shares == 0) revert ZeroAmount();
+        uint256 owned = sharesOf[msg.sender];
+        if (owned < shares) revert InsufficientShares(owned, shares);
+        baseAmount = (shares * baseReserve) / totalShares;
+        quoteAmount = (shares * quoteReserve) / totalShares;
+        sharesOf[msg.sender] = owned - shares;
+        totalShares -= shares;
+        baseReserve -= baseAmount;
+        quoteReserve -= quoteAmount;
+        base.transfer(msg.sender, baseAmount);
+        quote.transfer(msg.sender, quoteAmount);
+        emit LiquidityRemoved(msg.sender, baseAmount, quoteAmount, shares);
+        emit Sync(baseReserve, quoteReserve);
+    }
+
+    function swap(bool sellBase, uint256 amountIn, uint256 minAmountOut) external returns (uint256 amountOut) {
+        if (amountIn == 0) revert ZeroAmount();
+        amountOut = getAmountOut(sellBase, amountIn);
+        if (amountOut == 0 || amountOut < minAmountOut) revert InsufficientOutput(amountOut, minAmountOut);
+        if (sellBase) {
+            base.transferFrom(msg.sender, address(this), amountIn);
+            quote.transfer(msg.sender, amountOut);
+            baseReserve += amountIn;
+            quoteReserve -= amountOut;
+        } else {
+            quote.transferFrom(msg.sender, address(this), amountIn);
+            base.transfer(msg.sender, amountOut);
+            quoteReserve += amountIn;
+            baseReserve -= amountOut;
+        }
+        emit Swap(msg.sender, sellBase, amountIn, amountOut);
+        emit Sync(baseReserve, quoteReserve);
+    }
+
+    function min(uint256 a, uint256 b) internal pure returns (uint256) {
+        return a < b ? a : b;
+    }
+
+    function sqrt(uint256 y) internal pure returns (uint256 z) {
+        if (y > 3) {
+            z = y;
+            uint256 x = y / 2 + 1;
+            while (x < z) {
+                z = x;
+                x = (y / x + x) / 2;
+            }
+        } else if (y != 0) {
+            z = 1;
+        }
+    }
+}
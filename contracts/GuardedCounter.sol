@@ -0,0 +1,65 @@
+// SPDX-License-Identifier: UNLICENSED
+pragma solidity ^0.8.13;
+
+/// A `ModifiedCounter` only its owner and the accounts it grants a role can
+/// change, capped at `maxNumber`.
+contract GuardedCounter {
+    bytes32 public constant SETTER_ROLE = keccak256("SETTER_ROLE");
+    bytes32 public constant INCREMENTER_ROLE = keccak256("INCREMENTER_ROLE");
+
+    address public owner;
+    uint256 public immutable maxNumber;
+    uint256 public number;
+    mapping(bytes32 => mapping(address => bool)) public hasRole;
+
+    event NumberSet(uint256 newNumber);
+    event Incremented(uint256 newNumber);
+    event RoleGranted(bytes32 indexed role, address indexed account);
+    event RoleRevoked(bytes32 indexed role, address indexed account);
+    event OwnershipTransferred(address indexed previousOwner, address indexed newOwner);
+
+    error Unauthorized(address caller);
+    error Overflow();
+
+    modifier onlyOwner() {
+        if (msg.sender != owner) revert Unauthorized(msg.sender);
+        _;
+    }
+
+    modifier onlyRole(bytes32 role) {
+        if (msg.sender != owner && !hasRole[role][msg.sender]) revert Unauthorized(msg.sender);
+        _;
+    }
+
+    constructor(uint256 maxNumber_) {
+        owner = msg.sender;
+        maxNumber = maxNumber_;
+    }
+
+    function transferOwnership(address newOwner) public onlyOwner {
+        emit OwnershipTransferred(owner, newOwner);
+        owner = newOwner;
+    }
+
+    function grantRole(bytes32 role, address account) public onlyOwner {
+        hasRole[role][account] = true;
+        emit RoleGranted(role, account);
+    }
+
+    function revokeRole(bytes32 role, address account) public onlyOwner {
+        hasRole[role][account] = false;
+        emit RoleRevoked(role, account);
+    }
+
+    function setNumber(uint256 newNumber) public onlyRole(SETTER_ROLE) {
+        if (newNumber > maxNumber) revert Overflow();
+        number = newNumber;
+        emit NumberSet(newNumber);
+    }
+
+    function increment() public onlyRole(INCREMENTER_ROLE) {
+        if (number >= maxNumber) revert Overflow();
+        number++;
+        emit Incremented(number);
+    }
+}
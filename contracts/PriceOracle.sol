@@ -0,0 +1,27 @@
+// SPDX-License-Identifier: UNLICENSED
+pragma solidity ^0.8.13;
+
+/// A reference price pushed by a single updater, with 18 decimals.
+contract PriceOracle {
+    uint8 public constant decimals = 18;
+
+    address public immutable updater;
+    uint256 public price;
+    uint256 public step;
+
+    event PriceUpdated(uint256 price, uint256 step);
+
+    error NotUpdater(address caller);
+
+    constructor(uint256 initialPrice) {
+        updater = msg.sender;
+        price = initialPrice;
+    }
+
+    function setPrice(uint256 newPrice) external {
+        if (msg.sender != updater) revert NotUpdater(msg.sender);
+        price = newPrice;
+        step += 1;
+        emit PriceUpdated(newPrice, step);
+    }
+}
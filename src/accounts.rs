@@ -0,0 +1,63 @@
+use std::{
+    collections::HashMap,
+    sync::{Mutex, OnceLock},
+    time::Duration,
+};
+
+use anyhow::{anyhow, Result};
+use ethers::types::Address;
+use tokio::sync::Notify;
+
+/// How long a lookup waits for an agent to announce its address. Behaviors
+/// announce at the start of `startup`, so this only needs to cover agents
+/// starting up concurrently.
+const ANNOUNCE_TIMEOUT: Duration = Duration::from_secs(5);
+
+static ACCOUNTS: OnceLock<Accounts> = OnceLock::new();
+
+/// The address of every agent that announced it with [`announce`].
+#[derive(Default)]
+struct Accounts {
+    addresses: Mutex<HashMap<String, Address>>,
+    announced: Notify,
+}
+
+fn accounts() -> &'static Accounts {
+    ACCOUNTS.get_or_init(Accounts::default)
+}
+
+/// Forgets the agents of earlier runs.
+pub fn reset() {
+    accounts().addresses.lock().unwrap().clear();
+}
+
+/// Makes the address of `agent` known to the other behaviors, e.g. so that a
+/// `TokenDeployer` can mint to it.
+pub fn announce(agent: &str, address: Address) {
+    let accounts = accounts();
+    accounts
+        .addresses
+        .lock()
+        .unwrap()
+        .insert(agent.to_owned(), address);
+    accounts.announced.notify_waiters();
+}
+
+/// Looks up the address of `agent`, waiting for it to be announced.
+pub async fn address_of(agent: &str) -> Result<Address> {
+    let accounts = accounts();
+    let deadline = tokio::time::Instant::now() + ANNOUNCE_TIMEOUT;
+    loop {
+        let announced = accounts.announced.notified();
+        if let Some(address) = accounts.addresses.lock().unwrap().get(agent) {
+            return Ok(*address);
+        }
+        if tokio::time::timeout_at(deadline, announced).await.is_err() {
+            return Err(anyhow!(
+                "agent `{}` never announced its address, only agents running a token \
+                 behavior can hold tokens",
+                agent
+            ));
+        }
+    }
+}
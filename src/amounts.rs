@@ -0,0 +1,30 @@
+use anyhow::{ensure, Result};
+use ethers::{
+    types::U256,
+    utils::{format_units, parse_units},
+};
+
+/// `amount` whole tokens in the smallest unit of a token with `decimals`.
+pub fn whole(amount: u64, decimals: u8) -> U256 {
+    U256::from(amount) * U256::exp10(decimals as usize)
+}
+
+/// `amount` tokens in the smallest unit of a token with `decimals`, rounded
+/// to that unit.
+pub fn parse(amount: f64, decimals: u8) -> Result<U256> {
+    ensure!(
+        amount.is_finite() && amount >= 0.0,
+        "token amount must be non-negative, got {}",
+        amount
+    );
+    let amount = format!("{:.*}", decimals as usize, amount);
+    Ok(parse_units(amount, decimals as u32)?.into())
+}
+
+/// `units` of a token with `decimals` in tokens.
+pub fn tokens(units: U256, decimals: u8) -> f64 {
+    format_units(units, decimals as u32)
+        .ok()
+        .and_then(|amount| amount.parse().ok())
+        .unwrap_or(f64::NAN)
+}
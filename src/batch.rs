@@ -0,0 +1,249 @@
+use std::{
+    fs::{self, File},
+    path::{Path, PathBuf},
+    process::Stdio,
+    sync::Arc,
+};
+
+use anyhow::{anyhow, bail, ensure, Context, Result};
+use tokio::{process::Command, sync::Semaphore, task::JoinSet};
+use tracing::{error, info};
+
+use crate::{config::SimulationConfig, report::RunReport, run_dir, shutdown, sweep};
+
+/// Options of the `batch` subcommand.
+#[derive(Debug, Clone)]
+pub struct BatchOptions {
+    pub runs: usize,
+    pub seed: u64,
+    /// Maximum number of runs executing at the same time.
+    pub concurrency: usize,
+    pub out: PathBuf,
+}
+
+/// Derives the seed of run `index` from the batch seed with SplitMix64, so
+/// every run gets a well mixed seed that only depends on `(seed, index)`.
+/// Seeds are kept below `2^63` so they round-trip through TOML integers.
+pub fn derive_seed(seed: u64, index: u64) -> u64 {
+    let mut z = seed.wrapping_add(index.wrapping_add(1).wrapping_mul(0x9e37_79b9_7f4a_7c15));
+    z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+    (z ^ (z >> 31)) >> 1
+}
+
+struct RunOutcome {
+    index: usize,
+    seed: u64,
+    report: Result<RunReport>,
+}
+
+/// Runs `config_path` `options.runs` times, each in its own `simulate`
+/// process with a seed derived from `options.seed`, and writes a summary of
+/// all runs to `summary.csv` in the batch directory.
+pub async fn run(config_path: &Path, options: BatchOptions) -> Result<()> {
+    ensure!(options.runs > 0, "a batch needs at least one run");
+    ensure!(options.concurrency > 0, "concurrency must be at least one");
+    let table = crate::config::read_table(config_path)?;
+    ensure!(
+        sweep::expand(&table)?.len() == 1,
+        "batches of swept configs are not supported, run the sweep instead"
+    );
+
+    let stem = config_path
+        .file_stem()
+        .map(|stem| stem.to_string_lossy().into_owned())
+        .unwrap_or_else(|| "batch".to_owned());
+    let batch_dir = options.out.join(format!("{}-batch", stem));
+    fs::create_dir_all(&batch_dir)
+        .with_context(|| format!("failed to create {}", batch_dir.display()))?;
+    let exe = std::env::current_exe().context("failed to locate the simulation binary")?;
+    let semaphore = Arc::new(Semaphore::new(options.concurrency));
+
+    let mut tasks = JoinSet::new();
+    for index in 0..options.runs {
+        let seed = derive_seed(options.seed, index as u64);
+        let run_dir = batch_dir.join(format!("run_{:04}", index));
+        // Seeded before interpreting so agents created by `count` differ
+        // between runs too.
+        let mut table = table.clone();
+        table.insert("seed".to_owned(), toml::Value::Integer(seed as i64));
+        let mut config = SimulationConfig::from_table(table)?;
+        config.place_outputs_in(&run_dir);
+
+        let exe = exe.clone();
+        let semaphore = semaphore.clone();
+        tasks.spawn(async move {
+            let _permit = semaphore.acquire_owned().await?;
+            let report = match shutdown::received() {
+                // Runs in progress receive the signal themselves.
+                Some(signal) => Err(anyhow!("skipped, batch interrupted by {}", signal)),
+                None => {
+                    info!("Starting batch run {} with seed {}", index, seed);
+                    run_one(&exe, &config, &run_dir).await
+                }
+            };
+            Ok::<_, anyhow::Error>(RunOutcome {
+                index,
+                seed,
+                report,
+            })
+        });
+    }
+
+    let mut outcomes = Vec::with_capacity(options.runs);
+    while let Some(outcome) = tasks.join_next().await {
+        let outcome = outcome??;
+        if let Err(e) = &outcome.report {
+            error!("Batch run {} failed: {:#}", outcome.index, e);
+        }
+        outcomes.push(outcome);
+    }
+    outcomes.sort_by_key(|outcome| outcome.index);
+
+    let summary_path = batch_dir.join("summary.csv");
+    write_summary(&summary_path, &outcomes)?;
+    print_summary(&outcomes);
+    println!("Batch summary written to {}", summary_path.display());
+
+    let failed = outcomes
+        .iter()
+        .filter(|outcome| !matches!(&outcome.report, Ok(report) if report.failure().is_none()))
+        .count();
+    if failed > 0 {
+        bail!("{} of {} batch runs failed", failed, outcomes.len());
+    }
+    Ok(())
+}
+
+async fn run_one(exe: &Path, config: &SimulationConfig, dir: &Path) -> Result<RunReport> {
+    fs::create_dir_all(dir).with_context(|| format!("failed to create {}", dir.display()))?;
+    let config_path = dir.join(run_dir::CONFIG_FILE);
+    let report_path = dir.join(run_dir::REPORT_FILE);
+    fs::write(&config_path, toml::to_string(config)?)?;
+    let log = File::create(dir.join("simulate.log"))?;
+
+    let status = Command::new(exe)
+        .arg("simulate")
+        .arg(&config_path)
+        .arg("--run-dir")
+        .arg(dir)
+        .stdout(Stdio::from(log.try_clone()?))
+        .stderr(Stdio::from(log))
+        .status()
+        .await?;
+    let report = fs::read_to_string(&report_path)
+        .with_context(|| format!("run exited with {} without writing a report", status))?;
+    Ok(serde_json::from_str(&report)?)
+}
+
+/// Columns of the summary table.
+const HEADER: [&str; 6] = [
+    "run",
+    "seed",
+    "status",
+    "increments",
+    "final_numbers",
+    "halting_reasons",
+];
+
+fn summary_row(outcome: &RunOutcome) -> [String; 6] {
+    let (status, increments, final_numbers, halting_reasons) = match &outcome.report {
+        Ok(report) => (
+            report.failure().unwrap_or_else(|| "completed".to_owned()),
+            report.increments().to_string(),
+            report
+                .counters
+                .iter()
+                .map(|counter| match counter.final_number {
+                    Some(number) => format!("{}={}", counter.deployer, number),
+                    None => format!("{}=?", counter.deployer),
+                })
+                .collect::<Vec<_>>()
+                .join(" "),
+            report
+                .agents
+                .iter()
+                .flat_map(|(agent, outcome)| {
+                    outcome
+                        .halts
+                        .iter()
+                        .map(move |halt| format!("{}/{}: {}", agent, halt.behavior, halt.reason))
+                })
+                .collect::<Vec<_>>()
+                .join("; "),
+        ),
+        Err(e) => (
+            format!("failed: {:#}", e),
+            String::new(),
+            String::new(),
+            String::new(),
+        ),
+    };
+    [
+        outcome.index.to_string(),
+        outcome.seed.to_string(),
+        status,
+        increments,
+        final_numbers,
+        halting_reasons,
+    ]
+}
+
+fn write_summary(path: &Path, outcomes: &[RunOutcome]) -> Result<()> {
+    let mut writer = csv::Writer::from_path(path)
+        .with_context(|| format!("failed to create {}", path.display()))?;
+    writer.write_record(HEADER)?;
+    for outcome in outcomes {
+        writer.write_record(summary_row(outcome))?;
+    }
+    writer.flush()?;
+    Ok(())
+}
+
+fn print_summary(outcomes: &[RunOutcome]) {
+    let rows: Vec<[String; 6]> = outcomes.iter().map(summary_row).collect();
+    let mut widths = HEADER.map(str::len);
+    for row in &rows {
+        for (width, cell) in widths.iter_mut().zip(row) {
+            *width = (*width).max(cell.len());
+        }
+    }
+    let print_row = |cells: &[&str]| {
+        let line: Vec<String> = cells
+            .iter()
+            .zip(widths)
+            .map(|(cell, width)| format!("{:<width$}", cell, width = width))
+            .collect();
+        println!("{}", line.join(" | ").trim_end());
+    };
+    print_row(&HEADER);
+    for row in &rows {
+        print_row(&row.each_ref().map(String::as_str));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use super::*;
+
+    #[test]
+    fn derived_seeds_are_stable() {
+        // The first two SplitMix64 outputs for seed 0 are 0xe220a8397b1dcdaf
+        // and 0x6e789e6aa1b965f4.
+        assert_eq!(derive_seed(0, 0), 0xe220_a839_7b1d_cdaf >> 1);
+        assert_eq!(derive_seed(0, 1), 0x6e78_9e6a_a1b9_65f4 >> 1);
+        assert_eq!(derive_seed(42, 0), 6_839_728_766_377_637_706);
+    }
+
+    #[test]
+    fn derived_seeds_are_distinct_per_run() {
+        for seed in [0, 1, 42, u64::MAX] {
+            let seeds: HashSet<u64> = (0..10_000).map(|index| derive_seed(seed, index)).collect();
+            assert_eq!(seeds.len(), 10_000);
+            assert!(seeds.iter().all(|seed| *seed < 1 << 63));
+        }
+        assert_ne!(derive_seed(0, 1), derive_seed(1, 0));
+    }
+}
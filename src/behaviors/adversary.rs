@@ -0,0 +1,185 @@
+use std::{fmt, sync::Arc};
+
+use anyhow::{bail, ensure, Result};
+use arbiter_core::middleware::ArbiterMiddleware;
+use arbiter_engine::{
+    machine::{Behavior, ControlFlow, EventStream},
+    messager::Messager,
+};
+use ethers::types::{Address, U256};
+use futures::{stream, StreamExt};
+use tracing::{debug, info};
+
+use super::*;
+use crate::{
+    accounts,
+    behaviors::{deployer, guarded_deployer},
+    bindings::guarded_counter::{GuardedCounter, GuardedCounterErrors, Unauthorized},
+    errors::{ErrorPolicy, SimulationError},
+    messages::{MessagerExt, Stop},
+    report, tx,
+};
+
+/// Sends transactions a `GuardedCounter` must reject and checks that each
+/// reverts with the custom error it should, failing like any other error
+/// handling an event if one does not. Makes every attack of `attacks` in
+/// order, `rounds` times, then halts.
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct Adversary {
+    /// Name of the counter to attack, as given to its `GuardedDeployer`.
+    #[serde(default = "default_counter")]
+    counter: String,
+    attacks: Vec<Attack>,
+    #[serde(default = "default_rounds")]
+    rounds: u64,
+    #[serde(default)]
+    on_error: ErrorPolicy,
+    #[serde(skip)]
+    contract: Option<GuardedCounter<ArbiterMiddleware>>,
+    #[serde(skip)]
+    agent: String,
+    #[serde(skip)]
+    address: Address,
+}
+
+fn default_counter() -> String {
+    guarded_deployer::GUARDED.to_owned()
+}
+
+fn default_rounds() -> u64 {
+    1
+}
+
+/// A transaction the counter must reject.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum Attack {
+    /// Calls `setNumber` without the setter role, expecting `Unauthorized`.
+    SetNumber,
+    /// Calls `increment` without the incrementer role, expecting
+    /// `Unauthorized`.
+    Increment,
+    /// Grants itself the setter role without owning the counter, expecting
+    /// `Unauthorized`.
+    GrantRole,
+    /// Takes over the counter without owning it, expecting `Unauthorized`.
+    TransferOwnership,
+    /// Sets a number above `maxNumber`, expecting `Overflow`, which needs the
+    /// setter role to get past the access check.
+    Overflow,
+}
+
+impl fmt::Display for Attack {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Attack::SetNumber => f.write_str("setNumber"),
+            Attack::Increment => f.write_str("increment"),
+            Attack::GrantRole => f.write_str("grantRole"),
+            Attack::TransferOwnership => f.write_str("transferOwnership"),
+            Attack::Overflow => f.write_str("setNumber above maxNumber"),
+        }
+    }
+}
+
+/// Events an adversary consumes.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum AdversaryEvent {
+    Attack(Attack),
+    /// Every attack has been made.
+    Done,
+    Stop(Stop),
+}
+
+impl Adversary {
+    async fn attack(&mut self, attack: Attack) -> Result<ControlFlow> {
+        let counter = self
+            .contract
+            .clone()
+            .ok_or(SimulationError::Uninitialized("counter"))?;
+        let result = match attack {
+            Attack::SetNumber => tx::send(&self.agent, counter.set_number(U256::zero())).await,
+            Attack::Increment => tx::send(&self.agent, counter.increment()).await,
+            Attack::GrantRole => {
+                let role = counter.setter_role().call().await?;
+                tx::send(&self.agent, counter.grant_role(role, self.address)).await
+            }
+            Attack::TransferOwnership => {
+                tx::send(&self.agent, counter.transfer_ownership(self.address)).await
+            }
+            Attack::Overflow => {
+                let max_number = counter.max_number().call().await?;
+                tx::send(&self.agent, counter.set_number(max_number + 1)).await
+            }
+        };
+        let error = match result {
+            Ok(_) => bail!("{} succeeded instead of reverting", attack),
+            Err(e) => e,
+        };
+        let expected = match (attack, error.revert::<GuardedCounterErrors>()) {
+            (Attack::Overflow, Some(GuardedCounterErrors::Overflow(_))) => true,
+            (Attack::Overflow, _) => false,
+            (_, Some(GuardedCounterErrors::Unauthorized(Unauthorized { caller }))) => {
+                caller == self.address
+            }
+            _ => false,
+        };
+        ensure!(expected, "{} failed unexpectedly: {}", attack, error);
+        info!("{} was rejected as expected: {}", attack, error);
+        Ok(ControlFlow::Continue)
+    }
+}
+
+#[async_trait::async_trait]
+impl Behavior<AdversaryEvent> for Adversary {
+    async fn startup(
+        &mut self,
+        client: Arc<ArbiterMiddleware>,
+        messager: Messager,
+    ) -> Result<Option<EventStream<AdversaryEvent>>> {
+        debug!("Adversary starting up");
+        ensure!(!self.attacks.is_empty(), "`attacks` must not be empty");
+
+        self.agent = messager.id.clone().unwrap_or_default();
+        self.address = client.address();
+        accounts::announce(&self.agent, self.address);
+        let stops = messager
+            .clone()
+            .subscribe::<Stop>()?
+            .map(|stop| AdversaryEvent::Stop(stop.message));
+        let address = deployer::deployed(&self.counter, messager).await?;
+        self.contract = Some(GuardedCounter::new(address, client));
+        let attacks = (0..self.rounds)
+            .flat_map(|_| self.attacks.clone())
+            .map(AdversaryEvent::Attack)
+            .chain([AdversaryEvent::Done]);
+        Ok(Some(Box::pin(stream::select(
+            stream::iter(attacks.collect::<Vec<_>>()),
+            stops,
+        ))))
+    }
+
+    async fn process(&mut self, event: AdversaryEvent) -> Result<ControlFlow> {
+        let attack = match event {
+            AdversaryEvent::Attack(attack) => attack,
+            AdversaryEvent::Done => {
+                info!("Adversary done");
+                report::halted(&self.agent, "Adversary", "attacks done");
+                return Ok(ControlFlow::Halt);
+            }
+            AdversaryEvent::Stop(stop) => {
+                info!("Adversary stopped: {}", stop.reason);
+                report::halted(
+                    &self.agent,
+                    "Adversary",
+                    format!("stopped: {}", stop.reason),
+                );
+                return Ok(ControlFlow::Halt);
+            }
+        };
+        let result = self.attack(attack).await;
+        self.on_error
+            .resolve(result, &self.agent, "Adversary")
+            .unwrap_or(Ok(ControlFlow::Continue))
+    }
+}
@@ -0,0 +1,369 @@
+use std::sync::Arc;
+
+use anyhow::{ensure, Result};
+use arbiter_core::{events::stream_event, middleware::ArbiterMiddleware};
+use arbiter_engine::{
+    machine::{Behavior, ControlFlow, EventStream},
+    messager::Messager,
+};
+use ethers::types::{Address, U256};
+use futures::{stream, StreamExt};
+use tracing::{debug, info};
+
+use super::*;
+use crate::{
+    accounts, amounts,
+    behaviors::{
+        deployer,
+        pool_deployer::{self, Market, FEE_DENOMINATOR},
+        price_process::{self, PRICE_DECIMALS},
+    },
+    bindings::price_oracle::{PriceOracle, PriceUpdatedFilter},
+    collector::{self, TradeRecord},
+    errors::{ErrorPolicy, SimulationError},
+    messages::{MessagerExt, Stop},
+    report, tx,
+};
+
+/// Trades a pool towards the price of an oracle on every `PriceUpdated`
+/// event, with the size that maximizes its profit against the pool's
+/// invariant, whenever that profit net of fees and gas is worth it. Keeps
+/// running until every other behavior has halted or a `Stop` message
+/// arrives.
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct Arbitrageur {
+    /// Name of the pool, as given to its `PoolDeployer`.
+    #[serde(default = "default_pool")]
+    pool: String,
+    /// Name of the oracle, as given to its `PriceProcess`.
+    #[serde(default = "default_oracle")]
+    oracle: String,
+    /// Cost of a unit of gas in quote tokens, e.g. `0.00002` for 10 gwei
+    /// when the native coin is worth 2000 quote tokens.
+    #[serde(default)]
+    gas_price: f64,
+    /// Gas a swap is assumed to use until the first one is mined, after which
+    /// the gas of the last swap is assumed.
+    #[serde(default = "default_swap_gas")]
+    swap_gas: u64,
+    /// Smallest expected profit net of gas, in quote tokens, worth trading
+    /// for.
+    #[serde(default)]
+    min_profit: f64,
+    #[serde(default)]
+    on_error: ErrorPolicy,
+    #[serde(skip)]
+    market: Option<Market>,
+    #[serde(skip)]
+    agent: String,
+    #[serde(skip)]
+    address: Address,
+    /// Share of every input the pool keeps after its fee.
+    #[serde(skip)]
+    gamma: f64,
+}
+
+fn default_pool() -> String {
+    pool_deployer::POOL.to_owned()
+}
+
+fn default_oracle() -> String {
+    price_process::ORACLE.to_owned()
+}
+
+fn default_swap_gas() -> u64 {
+    100_000
+}
+
+/// Events an arbitrageur consumes.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum ArbitrageEvent {
+    PriceUpdated(PriceUpdatedFilter),
+    Stop(Stop),
+}
+
+/// The swap maximizing the profit of trading against reserves of `base` and
+/// `quote` tokens when a base token is worth `price` quote tokens elsewhere
+/// and the pool keeps `gamma` of every input. Returns whether the swap sells
+/// base tokens and its input in tokens, or `None` if the gap is within the
+/// fee.
+///
+/// Buying base tokens with `dy` quote tokens makes
+/// `price * gamma * dy * base / (quote + gamma * dy) - dy`, which peaks at
+/// `dy = (sqrt(gamma * price * base * quote) - quote) / gamma`, and selling
+/// them mirrors it.
+fn optimal_trade(base: f64, quote: f64, price: f64, gamma: f64) -> Option<(bool, f64)> {
+    if gamma * price * base > quote {
+        let quote_in = ((gamma * price * base * quote).sqrt() - quote) / gamma;
+        (quote_in > 0.0).then_some((false, quote_in))
+    } else if gamma * quote > price * base {
+        let base_in = ((gamma * base * quote / price).sqrt() - base) / gamma;
+        (base_in > 0.0).then_some((true, base_in))
+    } else {
+        None
+    }
+}
+
+/// The input of a swap of `amount` tokens with `decimals`, capped at the
+/// arbitrageur's `balance`.
+fn capped_amount(amount: f64, decimals: u8, balance: U256) -> Result<U256> {
+    Ok(amounts::parse(amount, decimals)?.min(balance))
+}
+
+impl Arbitrageur {
+    async fn handle(&mut self, update: &PriceUpdatedFilter) -> Result<ControlFlow> {
+        let market = self
+            .market
+            .clone()
+            .ok_or(SimulationError::Uninitialized("pool"))?;
+        let price = amounts::tokens(update.price, PRICE_DECIMALS);
+        let (base_reserve, quote_reserve) = market.reserves().await?;
+        if base_reserve.is_zero() || price <= 0.0 {
+            return Ok(ControlFlow::Continue);
+        }
+        let base = amounts::tokens(base_reserve, market.base_decimals);
+        let quote = amounts::tokens(quote_reserve, market.quote_decimals);
+        let Some((sell_base, amount)) = optimal_trade(base, quote, price, self.gamma) else {
+            debug!("Pool price {} is within the fee of {}", quote / base, price);
+            return Ok(ControlFlow::Continue);
+        };
+
+        let (token_in, decimals_in) = match sell_base {
+            true => (&market.base, market.base_decimals),
+            false => (&market.quote, market.quote_decimals),
+        };
+        let balance = token_in.balance_of(self.address).call().await?;
+        let amount_in = capped_amount(amount, decimals_in, balance)?;
+        if amount_in.is_zero() {
+            debug!("Arbitrageur has no balance to trade");
+            return Ok(ControlFlow::Continue);
+        }
+        let amount_out = market
+            .pool
+            .get_amount_out(sell_base, amount_in)
+            .call()
+            .await?;
+        let (value_in, value_out) = match sell_base {
+            true => (
+                amounts::tokens(amount_in, market.base_decimals) * price,
+                amounts::tokens(amount_out, market.quote_decimals),
+            ),
+            false => (
+                amounts::tokens(amount_in, market.quote_decimals),
+                amounts::tokens(amount_out, market.base_decimals) * price,
+            ),
+        };
+        let expected_profit = value_out - value_in - self.gas_price * self.swap_gas as f64;
+        if expected_profit <= self.min_profit {
+            debug!("Arbitrage would make {}, not trading", expected_profit);
+            return Ok(ControlFlow::Continue);
+        }
+
+        let before = self.holdings(&market, price).await?;
+        let receipt = tx::send_retrying(
+            &self.agent,
+            market.pool.swap(sell_base, amount_in, amount_out),
+            self.on_error.retries(),
+        )
+        .await?;
+        let gas_used = receipt.gas_used.unwrap_or_default().as_u64();
+        if gas_used > 0 {
+            self.swap_gas = gas_used;
+        }
+        let gas_cost = self.gas_price * gas_used as f64;
+        let realized_profit = self.holdings(&market, price).await? - before - gas_cost;
+        info!(
+            "Arbitrageur {} base tokens for a profit of {} ({} expected)",
+            if sell_base { "sold" } else { "bought" },
+            realized_profit,
+            expected_profit
+        );
+        report::arbitraged(&self.agent, realized_profit);
+        collector::record_trade(&TradeRecord {
+            block_number: receipt.block_number.unwrap_or_default().as_u64(),
+            agent: self.agent.clone(),
+            tx_hash: receipt.transaction_hash,
+            sell_base,
+            amount_in: amount_in.to_string(),
+            amount_out: amount_out.to_string(),
+            reference_price: price,
+            pool_price_before: quote / base,
+            pool_price_after: market.price().await?.unwrap_or_default(),
+            expected_profit,
+            gas_used,
+            gas_cost,
+            realized_profit,
+        });
+        Ok(ControlFlow::Continue)
+    }
+
+    /// Token balances of the arbitrageur, valued in quote tokens at `price`.
+    async fn holdings(&self, market: &Market, price: f64) -> Result<f64> {
+        let base = market.base.balance_of(self.address).call().await?;
+        let quote = market.quote.balance_of(self.address).call().await?;
+        Ok(amounts::tokens(base, market.base_decimals) * price
+            + amounts::tokens(quote, market.quote_decimals))
+    }
+}
+
+#[async_trait::async_trait]
+impl Behavior<ArbitrageEvent> for Arbitrageur {
+    async fn startup(
+        &mut self,
+        client: Arc<ArbiterMiddleware>,
+        messager: Messager,
+    ) -> Result<Option<EventStream<ArbitrageEvent>>> {
+        debug!("Arbitrageur starting up");
+        ensure!(
+            self.gas_price.is_finite() && self.gas_price >= 0.0,
+            "gas_price must be non-negative, got {}",
+            self.gas_price
+        );
+        ensure!(self.min_profit.is_finite(), "min_profit must be finite");
+
+        self.agent = messager.id.clone().unwrap_or_default();
+        self.address = client.address();
+        accounts::announce(&self.agent, self.address);
+        let stops: EventStream<ArbitrageEvent> = Box::pin(
+            messager
+                .clone()
+                .subscribe::<Stop>()?
+                .map(|stop| ArbitrageEvent::Stop(stop.message)),
+        );
+        let done: EventStream<ArbitrageEvent> =
+            Box::pin(stream::once(report::workers_halted()).map(|_| {
+                ArbitrageEvent::Stop(Stop {
+                    reason: "all workers halted".to_owned(),
+                })
+            }));
+        let addresses = deployer::deployed_all(&[&self.pool, &self.oracle], messager).await?;
+        let oracle = PriceOracle::new(addresses[1], client.clone());
+        let updates: EventStream<ArbitrageEvent> =
+            Box::pin(stream_event(oracle.price_updated_filter()).map(ArbitrageEvent::PriceUpdated));
+        let market = Market::at(addresses[0], &self.agent, client).await?;
+        let fee_bps = market.pool.fee_bps().call().await?;
+        self.gamma = 1.0 - fee_bps.as_u64() as f64 / FEE_DENOMINATOR as f64;
+        self.market = Some(market);
+        Ok(Some(Box::pin(stream::select_all([updates, stops, done]))))
+    }
+
+    async fn process(&mut self, event: ArbitrageEvent) -> Result<ControlFlow> {
+        let update = match event {
+            ArbitrageEvent::Stop(stop) => {
+                info!("Arbitrageur stopped: {}", stop.reason);
+                report::halted(
+                    &self.agent,
+                    "Arbitrageur",
+                    format!("stopped: {}", stop.reason),
+                );
+                return Ok(ControlFlow::Halt);
+            }
+            ArbitrageEvent::PriceUpdated(update) => update,
+        };
+        let result = self.handle(&update).await;
+        self.on_error
+            .resolve(result, &self.agent, "Arbitrageur")
+            .unwrap_or(Ok(ControlFlow::Continue))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const GAMMA: f64 = 0.997;
+
+    /// Reserves after swapping `amount_in` the way `ConstantProductPool`
+    /// does, keeping the fee in the pool.
+    fn swap(base: f64, quote: f64, sell_base: bool, amount_in: f64) -> (f64, f64) {
+        if sell_base {
+            let amount_out = GAMMA * amount_in * quote / (base + GAMMA * amount_in);
+            (base + amount_in, quote - amount_out)
+        } else {
+            let amount_out = GAMMA * amount_in * base / (quote + GAMMA * amount_in);
+            (base - amount_out, quote + amount_in)
+        }
+    }
+
+    /// Profit in quote tokens of swapping `amount_in` when a base token is
+    /// worth `price`.
+    fn profit(base: f64, quote: f64, price: f64, sell_base: bool, amount_in: f64) -> f64 {
+        let (base_after, quote_after) = swap(base, quote, sell_base, amount_in);
+        match sell_base {
+            true => quote - quote_after - amount_in * price,
+            false => (base - base_after) * price - amount_in,
+        }
+    }
+
+    fn assert_close(actual: f64, expected: f64, tolerance: f64) {
+        assert!(
+            (actual / expected - 1.0).abs() < tolerance,
+            "{} is not within {} of {}",
+            actual,
+            tolerance,
+            expected
+        );
+    }
+
+    #[test]
+    fn selling_base_moves_pool_price_to_price_over_gamma() {
+        let (base, quote, price) = (1_000.0, 2_200_000.0, 2_000.0);
+        let (sell_base, amount) = optimal_trade(base, quote, price, GAMMA).unwrap();
+        assert!(sell_base);
+        let expected = ((GAMMA * base * quote / price).sqrt() - base) / GAMMA;
+        assert_close(amount, expected, 1e-12);
+        let (base_after, quote_after) = swap(base, quote, sell_base, amount);
+        assert_close(quote_after / base_after, price / GAMMA, 1.0 - GAMMA);
+    }
+
+    #[test]
+    fn buying_base_moves_pool_price_to_price_times_gamma() {
+        let (base, quote, price) = (1_000.0, 1_800_000.0, 2_000.0);
+        let (sell_base, amount) = optimal_trade(base, quote, price, GAMMA).unwrap();
+        assert!(!sell_base);
+        let expected = ((GAMMA * price * base * quote).sqrt() - quote) / GAMMA;
+        assert_close(amount, expected, 1e-12);
+        let (base_after, quote_after) = swap(base, quote, sell_base, amount);
+        assert_close(quote_after / base_after, price * GAMMA, 1.0 - GAMMA);
+    }
+
+    #[test]
+    fn optimal_trade_maximizes_profit() {
+        for (quote, price) in [(2_200_000.0, 2_000.0), (1_800_000.0, 2_000.0)] {
+            let base = 1_000.0;
+            let (sell_base, amount) = optimal_trade(base, quote, price, GAMMA).unwrap();
+            let best = profit(base, quote, price, sell_base, amount);
+            assert!(best > 0.0);
+            for factor in [0.5, 0.9, 0.99, 1.01, 1.1, 2.0] {
+                assert!(profit(base, quote, price, sell_base, amount * factor) < best);
+            }
+        }
+    }
+
+    #[test]
+    fn no_trade_within_fee() {
+        let base = 1_000.0;
+        for pool_price in [2_000.0, 2_000.0 * GAMMA + 0.1, 2_000.0 / GAMMA - 0.1] {
+            assert_eq!(optimal_trade(base, base * pool_price, 2_000.0, GAMMA), None);
+        }
+    }
+
+    #[test]
+    fn trade_is_capped_at_balance() {
+        let balance = amounts::whole(1, 18);
+        assert_eq!(capped_amount(1.5, 18, balance).unwrap(), balance);
+        assert_eq!(
+            capped_amount(0.25, 18, balance).unwrap(),
+            balance / U256::from(4)
+        );
+
+        // A capped trade still makes a profit, only less of it.
+        let (base, quote, price) = (1_000.0, 2_200_000.0, 2_000.0);
+        let (sell_base, amount) = optimal_trade(base, quote, price, GAMMA).unwrap();
+        let capped = profit(base, quote, price, sell_base, 1.0);
+        assert!(amount > 1.0);
+        assert!(capped > 0.0);
+        assert!(capped < profit(base, quote, price, sell_base, amount));
+    }
+}
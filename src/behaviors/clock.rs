@@ -0,0 +1,210 @@
+use std::{sync::Arc, time::Duration};
+
+use anyhow::{ensure, Result};
+use arbiter_core::middleware::ArbiterMiddleware;
+use arbiter_engine::{
+    machine::{Behavior, ControlFlow, EventStream},
+    messager::Messager,
+};
+use ethers::providers::Middleware;
+use futures::{stream, StreamExt};
+use tracing::{debug, info};
+
+use super::*;
+use crate::{
+    behaviors::incrementer::Wait,
+    errors::{ErrorPolicy, SimulationError},
+    messages::{MessagerExt, NewBlock, Stop},
+    replay, report,
+    rng::{self, SimRng},
+    tx,
+};
+
+/// Moves the environment's block number and timestamp forward on a schedule
+/// and broadcasts a `NewBlock` message for every block it produces. After
+/// `max_blocks` blocks it broadcasts a `Stop` and halts. It also halts on a
+/// `Stop` message or once every behavior that is not an observer has halted.
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct Clock {
+    schedule: Schedule,
+    /// Blocks to move forward per tick of the schedule.
+    #[serde(default = "default_blocks_per_tick")]
+    blocks_per_tick: u64,
+    #[serde(default = "default_seconds_per_block")]
+    seconds_per_block: u64,
+    max_blocks: u64,
+    #[serde(default)]
+    on_error: ErrorPolicy,
+    #[serde(skip)]
+    client: Option<Arc<ArbiterMiddleware>>,
+    #[serde(skip)]
+    messager: Option<Messager>,
+    #[serde(skip)]
+    agent: String,
+    #[serde(skip)]
+    rng: Option<SimRng>,
+    /// Transactions still to be mined before the next tick.
+    #[serde(skip)]
+    remaining: u64,
+    #[serde(skip)]
+    blocks: u64,
+}
+
+fn default_blocks_per_tick() -> u64 {
+    1
+}
+
+fn default_seconds_per_block() -> u64 {
+    12
+}
+
+/// When the clock ticks.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum Schedule {
+    /// Every `n` mined transactions, e.g. `{ transactions = 5 }`.
+    Transactions(u64),
+    /// Every `n` milliseconds of wall-clock time, e.g. `{ interval_ms = 100 }`.
+    IntervalMs(u64),
+    /// After a number of mined transactions drawn anew for every tick, at
+    /// least one, e.g. `{ sampled = { poisson = 4.0 } }`.
+    Sampled(Wait),
+}
+
+/// What the clock reacts to.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum Tick {
+    Transaction,
+    Interval,
+    Stop(Stop),
+}
+
+impl Clock {
+    /// Number of transactions to wait for before the next tick.
+    fn transactions_until_tick(&mut self) -> Result<u64> {
+        Ok(match &self.schedule {
+            Schedule::Transactions(n) => *n,
+            Schedule::IntervalMs(_) => 0,
+            Schedule::Sampled(wait) => {
+                let rng = self
+                    .rng
+                    .as_mut()
+                    .ok_or(SimulationError::Uninitialized("rng"))?;
+                wait.sample(rng).max(1)
+            }
+        })
+    }
+
+    async fn handle(&mut self, tick: &Tick) -> Result<ControlFlow> {
+        match tick {
+            Tick::Stop(stop) => {
+                info!("Clock stopped: {}", stop.reason);
+                report::halted(&self.agent, "Clock", format!("stopped: {}", stop.reason));
+                return Ok(ControlFlow::Halt);
+            }
+            Tick::Transaction => {
+                self.remaining = self.remaining.saturating_sub(1);
+                if self.remaining > 0 {
+                    return Ok(ControlFlow::Continue);
+                }
+            }
+            Tick::Interval => {}
+        }
+        self.advance().await?;
+        if self.blocks >= self.max_blocks {
+            info!("Clock done");
+            // Behaviors waiting for the next `NewBlock` would wait forever.
+            let reason = "max_blocks reached".to_owned();
+            let messager = self
+                .messager
+                .as_ref()
+                .ok_or(SimulationError::Uninitialized("messager"))?;
+            messager
+                .broadcast(Stop {
+                    reason: reason.clone(),
+                })
+                .await?;
+            report::halted(&self.agent, "Clock", reason);
+            return Ok(ControlFlow::Halt);
+        }
+        self.remaining = self.transactions_until_tick()?;
+        Ok(ControlFlow::Continue)
+    }
+
+    async fn advance(&mut self) -> Result<()> {
+        let client = self
+            .client
+            .as_ref()
+            .ok_or(SimulationError::Uninitialized("client"))?;
+        let messager = self
+            .messager
+            .as_ref()
+            .ok_or(SimulationError::Uninitialized("messager"))?;
+        let blocks = self.blocks_per_tick.min(self.max_blocks - self.blocks);
+        let number = client.get_block_number().await?.as_u64() + blocks;
+        let timestamp = client.get_block_timestamp().await? + blocks * self.seconds_per_block;
+        replay::update_block(client, number, timestamp).await?;
+        self.blocks += blocks;
+        debug!("Clock moved to block {} at {}", number, timestamp);
+        messager.broadcast(NewBlock { number, timestamp }).await
+    }
+}
+
+#[async_trait::async_trait]
+impl Behavior<Tick> for Clock {
+    async fn startup(
+        &mut self,
+        client: Arc<ArbiterMiddleware>,
+        messager: Messager,
+    ) -> Result<Option<EventStream<Tick>>> {
+        debug!("Clock starting up");
+        ensure!(self.blocks_per_tick > 0, "blocks_per_tick must be positive");
+        ensure!(self.max_blocks > 0, "max_blocks must be positive");
+        let ticks: EventStream<Tick> = match &self.schedule {
+            Schedule::Transactions(n) => {
+                ensure!(*n > 0, "transactions schedule must be positive");
+                Box::pin(tx::stream_mined().map(|_| Tick::Transaction))
+            }
+            Schedule::Sampled(wait) => {
+                wait.validate()?;
+                Box::pin(tx::stream_mined().map(|_| Tick::Transaction))
+            }
+            Schedule::IntervalMs(millis) => {
+                ensure!(*millis > 0, "interval_ms must be positive");
+                let mut interval = tokio::time::interval(Duration::from_millis(*millis));
+                // The first tick of a tokio interval completes immediately.
+                interval.reset();
+                Box::pin(stream::unfold(interval, |mut interval| async move {
+                    interval.tick().await;
+                    Some((Tick::Interval, interval))
+                }))
+            }
+        };
+
+        self.agent = messager.id.clone().unwrap_or_default();
+        self.rng = Some(rng::stream(&self.agent, "Clock"));
+        self.remaining = self.transactions_until_tick()?;
+        let stops: EventStream<Tick> = Box::pin(
+            messager
+                .clone()
+                .subscribe::<Stop>()?
+                .map(|stop| Tick::Stop(stop.message)),
+        );
+        let done: EventStream<Tick> = Box::pin(stream::once(report::workers_halted()).map(|_| {
+            Tick::Stop(Stop {
+                reason: "all workers halted".to_owned(),
+            })
+        }));
+        self.client = Some(client);
+        self.messager = Some(messager);
+        Ok(Some(Box::pin(stream::select_all([ticks, stops, done]))))
+    }
+
+    async fn process(&mut self, tick: Tick) -> Result<ControlFlow> {
+        let result = self.handle(&tick).await;
+        self.on_error
+            .resolve(result, &self.agent, "Clock")
+            .unwrap_or(Ok(ControlFlow::Continue))
+    }
+}
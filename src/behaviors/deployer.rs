@@ -0,0 +1,150 @@
+use std::sync::Arc;
+
+use anyhow::Result;
+use arbiter_core::middleware::ArbiterMiddleware;
+use arbiter_engine::{
+    machine::{Behavior, ControlFlow, EventStream},
+    messager::Messager,
+};
+use ethers::types::Address;
+use futures::{stream, StreamExt};
+use tracing::{debug, info};
+
+use super::*;
+use crate::{
+    bindings::modified_counter::ModifiedCounter,
+    collector,
+    errors::SimulationError,
+    messages::{ContractDeployed, MessagerExt, Stop},
+    report, shutdown, tx,
+};
+
+/// Name of the counter a `Deployer` deploys when it is not given one.
+pub const SHARED: &str = "shared";
+
+/// Deploys a `ModifiedCounter` and broadcasts its address so that behaviors
+/// configured with `counter = "<name>"` can attach to it instead of deploying
+/// their own.
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct Deployer {
+    #[serde(default = "default_name")]
+    name: String,
+}
+
+fn default_name() -> String {
+    SHARED.to_owned()
+}
+
+#[async_trait::async_trait]
+impl Behavior<Stop> for Deployer {
+    async fn startup(
+        &mut self,
+        client: Arc<ArbiterMiddleware>,
+        messager: Messager,
+    ) -> Result<Option<EventStream<Stop>>> {
+        let agent = messager.id.clone().unwrap_or_default();
+        let counter = deploy(&agent, client).await?;
+        let deployed = ContractDeployed {
+            name: self.name.clone(),
+            address: counter.address(),
+        };
+        info!(
+            "Deployed counter `{}` at {:?}",
+            deployed.name, deployed.address
+        );
+        messager.broadcast(deployed).await?;
+        report::halted(&agent, "Deployer", "deployed");
+        Ok(None)
+    }
+
+    async fn process(&mut self, _event: Stop) -> Result<ControlFlow> {
+        Ok(ControlFlow::Halt)
+    }
+}
+
+/// Deploys a counter owned by `agent` and registers it with the run report,
+/// the collector and the transaction log.
+pub async fn deploy(
+    agent: &str,
+    client: Arc<ArbiterMiddleware>,
+) -> Result<ModifiedCounter<ArbiterMiddleware>> {
+    let counter = tx::deploy(agent, ModifiedCounter::deploy(client, ())?).await?;
+    report::counter_deployed(agent, &counter);
+    collector::watch(&counter).await?;
+    Ok(counter)
+}
+
+/// Waits for the `Deployer` of the counter called `name` to broadcast its
+/// address and attaches to it.
+pub async fn attach(
+    name: &str,
+    client: Arc<ArbiterMiddleware>,
+    messager: Messager,
+) -> Result<ModifiedCounter<ArbiterMiddleware>> {
+    let address = deployed(name, messager).await?;
+    debug!("Attached to counter `{}` at {:?}", name, address);
+    Ok(ModifiedCounter::new(address, client))
+}
+
+/// Waits for the contract called `name` to be deployed and returns its
+/// address, whichever behavior deploys it.
+pub async fn deployed(name: &str, messager: Messager) -> Result<Address> {
+    Ok(deployed_all(&[name], messager).await?[0])
+}
+
+/// Waits for every contract of `names` to be deployed and returns their
+/// addresses in the same order. Fails with [`SimulationError::Stopped`] if the
+/// run is stopped first.
+pub async fn deployed_all(names: &[&str], messager: Messager) -> Result<Vec<Address>> {
+    debug!("Waiting for contracts {:?}", names);
+    let mut addresses = vec![None; names.len()];
+    let deployments = messager
+        .clone()
+        .subscribe::<ContractDeployed>()?
+        .map(|deployed| Ok(deployed.message));
+    let stops = messager.subscribe::<Stop>()?.map(|stop| Err(stop.message));
+    let mut messages = stream::select(deployments, stops);
+    // A signal received before subscribing was broadcast to nobody.
+    if let Some(signal) = shutdown::received() {
+        return Err(SimulationError::Stopped(format!("interrupted by {}", signal)).into());
+    }
+    while addresses.iter().any(Option::is_none) {
+        let deployed = match messages.next().await {
+            Some(Ok(deployed)) => deployed,
+            Some(Err(stop)) => return Err(SimulationError::Stopped(stop.reason).into()),
+            None => {
+                let missing = names
+                    .iter()
+                    .zip(&addresses)
+                    .find_map(|(name, address)| address.is_none().then_some(*name))
+                    .unwrap_or_default();
+                return Err(SimulationError::Messaging(format!(
+                    "messager closed before contract `{}` was deployed",
+                    missing
+                ))
+                .into());
+            }
+        };
+        for (name, address) in names.iter().zip(&mut addresses) {
+            if deployed.name == *name {
+                *address = Some(deployed.address);
+            }
+        }
+    }
+    Ok(addresses.into_iter().flatten().collect())
+}
+
+/// Deploys a private counter for `agent` if `reference` is `None`, otherwise
+/// attaches to the shared counter it names.
+pub async fn counter(
+    reference: Option<&str>,
+    agent: &str,
+    client: Arc<ArbiterMiddleware>,
+    messager: Messager,
+) -> Result<ModifiedCounter<ArbiterMiddleware>> {
+    match reference {
+        Some(name) => attach(name, client, messager).await,
+        None => deploy(agent, client).await,
+    }
+}
@@ -0,0 +1,86 @@
+use std::sync::Arc;
+
+use anyhow::Result;
+use arbiter_core::middleware::ArbiterMiddleware;
+use arbiter_engine::{
+    machine::{Behavior, ControlFlow, EventStream},
+    messager::Messager,
+};
+use ethers::types::U256;
+use tracing::{debug, info};
+
+use super::*;
+use crate::{
+    accounts,
+    bindings::guarded_counter::GuardedCounter,
+    messages::{ContractDeployed, MessagerExt, Stop},
+    report, tx,
+};
+
+/// Name of the counter a `GuardedDeployer` deploys when it is not given one.
+pub const GUARDED: &str = "guarded";
+
+/// Deploys a `GuardedCounter` owned by its agent, grants roles to named
+/// agents and broadcasts its address so that behaviors configured with
+/// `counter = "<name>"` can attack it.
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct GuardedDeployer {
+    #[serde(default = "default_name")]
+    name: String,
+    /// Highest number the counter accepts.
+    max_number: u64,
+    /// Agents granted the role to call `setNumber`.
+    #[serde(default)]
+    setters: Vec<String>,
+    /// Agents granted the role to call `increment`.
+    #[serde(default)]
+    incrementers: Vec<String>,
+}
+
+fn default_name() -> String {
+    GUARDED.to_owned()
+}
+
+#[async_trait::async_trait]
+impl Behavior<Stop> for GuardedDeployer {
+    async fn startup(
+        &mut self,
+        client: Arc<ArbiterMiddleware>,
+        messager: Messager,
+    ) -> Result<Option<EventStream<Stop>>> {
+        let agent = messager.id.clone().unwrap_or_default();
+        let max_number = U256::from(self.max_number);
+        let counter = tx::deploy(&agent, GuardedCounter::deploy(client, max_number)?).await?;
+        let roles = [
+            ("setter", counter.setter_role().call().await?, &self.setters),
+            (
+                "incrementer",
+                counter.incrementer_role().call().await?,
+                &self.incrementers,
+            ),
+        ];
+        for (name, role, holders) in roles {
+            for holder in holders {
+                let address = accounts::address_of(holder).await?;
+                tx::send(&agent, counter.grant_role(role, address)).await?;
+                debug!("Granted the {} role to {}", name, holder);
+            }
+        }
+        let deployed = ContractDeployed {
+            name: self.name.clone(),
+            address: counter.address(),
+        };
+        info!(
+            "Deployed guarded counter `{}` at {:?}",
+            deployed.name, deployed.address
+        );
+        messager.broadcast(deployed).await?;
+        report::halted(&agent, "GuardedDeployer", "deployed");
+        Ok(None)
+    }
+
+    async fn process(&mut self, _event: Stop) -> Result<ControlFlow> {
+        Ok(ControlFlow::Halt)
+    }
+}
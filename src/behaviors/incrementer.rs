@@ -1,55 +1,319 @@
-use std::sync::Arc;
+use std::{collections::HashSet, sync::Arc};
 
-use anyhow::Result;
-use arbiter_core::{events::stream_event, middleware::ArbiterMiddleware};
+use anyhow::{ensure, Result};
+use arbiter_core::middleware::ArbiterMiddleware;
 use arbiter_engine::{
     machine::{Behavior, ControlFlow, EventStream},
     messager::Messager,
 };
+use ethers::{providers::Middleware, types::H256};
+use futures::{stream, StreamExt};
+use rand::Rng;
+use rand_distr::{Distribution, Exp, Poisson};
 use tracing::{debug, info};
 
 use super::*;
-use crate::bindings::modified_counter::{IncrementedFilter, ModifiedCounter};
+use crate::{
+    behaviors::deployer,
+    bindings::modified_counter::{IncrementedFilter, ModifiedCounter},
+    errors::{ErrorPolicy, SimulationError},
+    messages::{MessagerExt, NewBlock, Stop},
+    replay, report,
+    rng::{self, SimRng},
+    tx,
+};
+
+/// Seconds the block timestamp moves forward per block an incrementer or a
+/// noise trader waits.
+pub(crate) const SECONDS_PER_BLOCK: u64 = 12;
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
 pub struct Incrementer {
     #[serde(default)]
     curr_number_of_times: u64,
     max_number_of_times: u64,
+    /// What counts as an arrival.
+    #[serde(default)]
+    on: Trigger,
+    /// Chance that an arrival turns into a batch of increments. A rejected
+    /// arrival waits at least one block before the next one is drawn, so every
+    /// event still leads to a batch eventually.
+    #[serde(default = "default_probability")]
+    probability: f64,
+    /// Blocks between an event and the next arrival, none if omitted. Only
+    /// for `on = "incremented"`, a `Clock` moves time otherwise.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    wait: Option<Wait>,
+    /// Number of increments per batch, one if omitted.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    burst: Option<Burst>,
+    /// Name of a shared counter to attach to, a private one is deployed if
+    /// omitted.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    counter: Option<String>,
+    #[serde(default)]
+    on_error: ErrorPolicy,
     #[serde(skip)]
-    counter: Option<ModifiedCounter<ArbiterMiddleware>>,
+    contract: Option<ModifiedCounter<ArbiterMiddleware>>,
+    #[serde(skip)]
+    agent: String,
+    #[serde(skip)]
+    rng: Option<SimRng>,
+    /// Transactions of its own increments whose events are still to come, the
+    /// last of which is the next arrival.
+    #[serde(skip)]
+    pending_increments: HashSet<H256>,
+}
+
+fn default_probability() -> f64 {
+    1.0
+}
+
+/// The event an incrementer reacts to.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum Trigger {
+    /// The counter's `Incremented` events, including its own. Of a batch of
+    /// its own increments only the last event is an arrival, while every event
+    /// of other agents sharing the counter is one.
+    #[default]
+    Incremented,
+    /// `NewBlock` messages broadcast by a `Clock`, each block being an arrival.
+    NewBlock,
+}
+
+/// Events an incrementer consumes, depending on its [`Trigger`].
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum IncrementerEvent {
+    Incremented {
+        event: IncrementedFilter,
+        tx_hash: H256,
+    },
+    NewBlock(NewBlock),
+    Stop(Stop),
+}
+
+/// Distribution of how long to wait, in blocks for the next arrival of an
+/// `Incrementer` and in transactions for the next block of a `Clock`.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum Wait {
+    /// Poisson distributed with the given mean, e.g. `{ poisson = 3.0 }`.
+    Poisson(f64),
+    /// Exponentially distributed with the given mean, rounded to whole blocks,
+    /// e.g. `{ exponential = 3.0 }`.
+    Exponential(f64),
+    /// Uniform over an inclusive range, e.g. `{ uniform = [1, 5] }`.
+    Uniform(u64, u64),
+}
+
+impl Wait {
+    pub(crate) fn validate(&self) -> Result<()> {
+        match *self {
+            Wait::Poisson(mean) | Wait::Exponential(mean) => {
+                ensure!(
+                    mean.is_finite() && mean > 0.0,
+                    "wait mean must be positive, got {}",
+                    mean
+                )
+            }
+            Wait::Uniform(min, max) => {
+                ensure!(min <= max, "wait range [{}, {}] is empty", min, max)
+            }
+        }
+        Ok(())
+    }
+
+    pub(crate) fn sample(&self, rng: &mut SimRng) -> u64 {
+        match *self {
+            Wait::Poisson(mean) => Poisson::new(mean).unwrap().sample(rng) as u64,
+            Wait::Exponential(mean) => Exp::new(1.0 / mean).unwrap().sample(rng).round() as u64,
+            Wait::Uniform(min, max) => rng.gen_range(min..=max),
+        }
+    }
+}
+
+/// Inclusive range the size of each batch of increments is drawn from
+/// uniformly, e.g. `{ min = 1, max = 3 }`.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct Burst {
+    min: u64,
+    max: u64,
+}
+
+impl Incrementer {
+    async fn handle(&mut self) -> Result<ControlFlow> {
+        let counter = self
+            .contract
+            .clone()
+            .ok_or(SimulationError::Uninitialized("counter"))?;
+        if self.curr_number_of_times < self.max_number_of_times {
+            let batch = match self.on {
+                Trigger::Incremented => self.next_batch(&counter.client()).await?,
+                Trigger::NewBlock => self.block_batch()?,
+            };
+            let batch = batch.min(self.max_number_of_times - self.curr_number_of_times);
+            for _ in 0..batch {
+                self.increment(&counter).await?;
+            }
+            Ok(ControlFlow::Continue)
+        } else {
+            info!("Incrementer done");
+            report::halted(&self.agent, "Incrementer", "max_number_of_times reached");
+            return Ok(ControlFlow::Halt);
+        }
+    }
+
+    /// Sends one increment, which counts towards `max_number_of_times` even if
+    /// it fails so that skipping failures cannot go on forever.
+    async fn increment(&mut self, counter: &ModifiedCounter<ArbiterMiddleware>) -> Result<()> {
+        self.curr_number_of_times += 1;
+        let receipt =
+            tx::send_retrying(&self.agent, counter.increment(), self.on_error.retries()).await?;
+        if self.on == Trigger::Incremented {
+            self.pending_increments.insert(receipt.transaction_hash);
+        }
+        report::incremented(&self.agent);
+        let curr_number = counter.number().call().await?;
+        debug!("Incremented to: {}", curr_number);
+        Ok(())
+    }
+
+    /// Draws arrivals until one is accepted, moving the environment forward by
+    /// the blocks waited, and returns the size of the batch to send.
+    async fn next_batch(&mut self, client: &ArbiterMiddleware) -> Result<u64> {
+        let rng = self
+            .rng
+            .as_mut()
+            .ok_or(SimulationError::Uninitialized("rng"))?;
+        let mut blocks = 0;
+        let mut rejected = false;
+        loop {
+            let wait = self.wait.as_ref().map_or(0, |wait| wait.sample(rng));
+            blocks += if rejected { wait.max(1) } else { wait };
+            if rng.gen_bool(self.probability) {
+                break;
+            }
+            rejected = true;
+        }
+        if blocks > 0 {
+            let block_number = client.get_block_number().await?.as_u64() + blocks;
+            let timestamp = client.get_block_timestamp().await? + blocks * SECONDS_PER_BLOCK;
+            replay::update_block(client, block_number, timestamp).await?;
+            debug!("Incrementer waited {} blocks", blocks);
+        }
+        Ok(burst_size(self.burst.as_ref(), rng))
+    }
+
+    /// Size of the batch to send for a new block, zero if the block is not
+    /// an arrival.
+    fn block_batch(&mut self) -> Result<u64> {
+        let rng = self
+            .rng
+            .as_mut()
+            .ok_or(SimulationError::Uninitialized("rng"))?;
+        if rng.gen_bool(self.probability) {
+            Ok(burst_size(self.burst.as_ref(), rng))
+        } else {
+            Ok(0)
+        }
+    }
+}
+
+fn burst_size(burst: Option<&Burst>, rng: &mut SimRng) -> u64 {
+    match burst {
+        Some(burst) => rng.gen_range(burst.min..=burst.max),
+        None => 1,
+    }
 }
 
 #[async_trait::async_trait]
-impl Behavior<IncrementedFilter> for Incrementer {
+impl Behavior<IncrementerEvent> for Incrementer {
     async fn startup(
         &mut self,
         client: Arc<ArbiterMiddleware>,
-        _messager: Messager,
-    ) -> Result<Option<EventStream<IncrementedFilter>>> {
+        messager: Messager,
+    ) -> Result<Option<EventStream<IncrementerEvent>>> {
         debug!("Incrementer starting up");
-        let counter = ModifiedCounter::deploy(client.clone(), ())?.send().await?;
-        let stream = stream_event(counter.incremented_filter());
-        counter.increment().send().await?.await?;
-        self.curr_number_of_times += 1;
-        let curr_number = counter.number().call().await?;
-        debug!("Incremented to: {}", curr_number);
-        self.counter = Some(counter);
-        Ok(Some(stream))
+        ensure!(
+            self.probability > 0.0 && self.probability <= 1.0,
+            "probability must be within (0, 1], got {}",
+            self.probability
+        );
+        if let Some(wait) = &self.wait {
+            ensure!(
+                self.on == Trigger::Incremented,
+                "wait cannot be combined with on = \"new_block\""
+            );
+            wait.validate()?;
+        }
+        if let Some(burst) = &self.burst {
+            ensure!(
+                1 <= burst.min && burst.min <= burst.max,
+                "burst range [{}, {}] must be non-empty and start at 1 or more",
+                burst.min,
+                burst.max
+            );
+        }
+
+        self.agent = messager.id.clone().unwrap_or_default();
+        self.rng = Some(rng::stream(&self.agent, "Incrementer"));
+        let blocks = match self.on {
+            Trigger::Incremented => None,
+            Trigger::NewBlock => Some(messager.clone().subscribe::<NewBlock>()?),
+        };
+        let stops = messager
+            .clone()
+            .subscribe::<Stop>()?
+            .map(|stop| IncrementerEvent::Stop(stop.message));
+        let counter =
+            deployer::counter(self.counter.as_deref(), &self.agent, client, messager).await?;
+        let arrivals: EventStream<IncrementerEvent> = match blocks {
+            None => Box::pin(
+                tx::stream_with_meta(counter.incremented_filter())
+                    .await?
+                    .map(|(event, meta)| IncrementerEvent::Incremented {
+                        event,
+                        tx_hash: meta.transaction_hash,
+                    }),
+            ),
+            Some(blocks) => Box::pin(blocks.map(|block| IncrementerEvent::NewBlock(block.message))),
+        };
+        self.increment(&counter).await?;
+        self.contract = Some(counter);
+        Ok(Some(Box::pin(stream::select(arrivals, stops))))
     }
 
-    async fn process(&mut self, _event: IncrementedFilter) -> Result<ControlFlow> {
-        debug!("Incrementer processing event");
-        let counter = self.counter.as_ref().unwrap();
-        if self.curr_number_of_times < self.max_number_of_times {
-            counter.increment().send().await?.await?;
-            self.curr_number_of_times += 1;
-            let curr_number = counter.number().call().await?;
-            debug!("Incremented to: {}", curr_number);
-            Ok(ControlFlow::Continue)
-        } else {
-            info!("Incrementer done");
+    async fn process(&mut self, event: IncrementerEvent) -> Result<ControlFlow> {
+        debug!("Incrementer processing {:?}", event);
+        if let IncrementerEvent::Stop(stop) = event {
+            info!("Incrementer stopped: {}", stop.reason);
+            report::halted(
+                &self.agent,
+                "Incrementer",
+                format!("stopped: {}", stop.reason),
+            );
             return Ok(ControlFlow::Halt);
         }
+        if let IncrementerEvent::Incremented { tx_hash, .. } = event {
+            if self.pending_increments.remove(&tx_hash) && !self.pending_increments.is_empty() {
+                return Ok(ControlFlow::Continue);
+            }
+        }
+        loop {
+            let result = self.handle().await;
+            match self.on_error.resolve(result, &self.agent, "Incrementer") {
+                Some(flow) => return flow,
+                // Increments that went through, or the next block, trigger the
+                // next arrival. Without either nothing would, so the skipped
+                // arrival is followed by the next one right away.
+                None if self.on == Trigger::NewBlock || !self.pending_increments.is_empty() => {
+                    return Ok(ControlFlow::Continue)
+                }
+                None => {}
+            }
+        }
     }
 }
@@ -0,0 +1,197 @@
+use std::sync::Arc;
+
+use anyhow::{anyhow, Result};
+use arbiter_core::middleware::ArbiterMiddleware;
+use arbiter_engine::{
+    machine::{Behavior, ControlFlow, EventStream},
+    messager::Messager,
+};
+use ethers::types::H256;
+use futures::{stream, StreamExt};
+use tracing::{debug, error, info};
+
+use super::*;
+use crate::{
+    behaviors::deployer,
+    bindings::modified_counter::ModifiedCounterEvents,
+    errors::SimulationError,
+    invariants::{self, CounterState, Invariant, Operation, Violation},
+    messages::{MessagerExt, Stop},
+    report, tx,
+};
+
+/// Checks invariants of a shared counter after every event it emits. On the
+/// first violation it records it in the run report and broadcasts a `Stop` so
+/// the whole simulation halts.
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct InvariantChecker {
+    /// Name of the shared counter to check.
+    #[serde(default = "default_counter")]
+    counter: String,
+    /// Names of the invariants to check, every one in [`invariants::ALL`] if
+    /// omitted.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    invariants: Vec<String>,
+    #[serde(skip)]
+    state: Option<CounterState>,
+    #[serde(skip)]
+    agent: String,
+    #[serde(skip)]
+    messager: Option<Messager>,
+}
+
+fn default_counter() -> String {
+    deployer::SHARED.to_owned()
+}
+
+/// Events an invariant checker consumes.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum CheckerEvent {
+    Counter {
+        event: ModifiedCounterEvents,
+        block_number: u64,
+        tx_hash: H256,
+    },
+    Stop(Stop),
+}
+
+impl InvariantChecker {
+    fn checks(&self) -> Result<Vec<&'static Invariant>> {
+        if self.invariants.is_empty() {
+            return Ok(invariants::ALL.iter().collect());
+        }
+        self.invariants
+            .iter()
+            .map(|name| {
+                invariants::find(name).ok_or_else(|| {
+                    let known: Vec<_> = invariants::ALL.iter().map(|i| i.name).collect();
+                    anyhow!(
+                        "unknown invariant `{}`, expected one of: {}",
+                        name,
+                        known.join(", ")
+                    )
+                })
+            })
+            .collect()
+    }
+
+    /// Moves the model past the operation of `tx_hash`, which emitted `event`,
+    /// and returns the first invariant it breaks.
+    async fn check(
+        &mut self,
+        event: &ModifiedCounterEvents,
+        block_number: u64,
+        tx_hash: H256,
+    ) -> Result<Option<Violation>> {
+        let operation = tx::calldata_of(tx_hash)
+            .await
+            .and_then(|calldata| Operation::decode(&calldata))
+            .ok_or_else(|| {
+                anyhow!(
+                    "transaction {:?} emitted {:?} but its call to the counter is unknown",
+                    tx_hash,
+                    event
+                )
+            })?;
+        let before = self
+            .state
+            .take()
+            .unwrap_or_else(|| CounterState::before(operation, event));
+        let after = before.apply(operation, event);
+        let mut violation = None;
+        for invariant in self.checks()? {
+            if let Err(message) = (invariant.check)(&before, &after) {
+                violation = Some(Violation {
+                    invariant: invariant.name.to_owned(),
+                    message,
+                    block_number,
+                    tx_hash,
+                    agent: tx::sender_of(tx_hash).await.unwrap_or_default(),
+                    before: before.clone(),
+                    after: after.clone(),
+                });
+                break;
+            }
+        }
+        self.state = Some(after);
+        Ok(violation)
+    }
+}
+
+#[async_trait::async_trait]
+impl Behavior<CheckerEvent> for InvariantChecker {
+    async fn startup(
+        &mut self,
+        client: Arc<ArbiterMiddleware>,
+        messager: Messager,
+    ) -> Result<Option<EventStream<CheckerEvent>>> {
+        debug!("InvariantChecker starting up");
+        self.checks()?;
+        self.agent = messager.id.clone().unwrap_or_default();
+        let stops: EventStream<CheckerEvent> = Box::pin(
+            messager
+                .clone()
+                .subscribe::<Stop>()?
+                .map(|stop| CheckerEvent::Stop(stop.message)),
+        );
+        let done: EventStream<CheckerEvent> =
+            Box::pin(stream::once(report::workers_halted()).map(|_| {
+                CheckerEvent::Stop(Stop {
+                    reason: "all workers halted".to_owned(),
+                })
+            }));
+        let counter = deployer::attach(&self.counter, client, messager.clone()).await?;
+        let events: EventStream<CheckerEvent> = Box::pin(
+            tx::stream_with_meta(counter.events())
+                .await?
+                .map(|(event, meta)| CheckerEvent::Counter {
+                    event,
+                    block_number: meta.block_number.as_u64(),
+                    tx_hash: meta.transaction_hash,
+                }),
+        );
+        self.messager = Some(messager);
+        Ok(Some(Box::pin(stream::select_all([events, stops, done]))))
+    }
+
+    async fn process(&mut self, event: CheckerEvent) -> Result<ControlFlow> {
+        let (event, block_number, tx_hash) = match event {
+            CheckerEvent::Counter {
+                event,
+                block_number,
+                tx_hash,
+            } => (event, block_number, tx_hash),
+            CheckerEvent::Stop(stop) => {
+                info!("InvariantChecker stopped: {}", stop.reason);
+                report::halted(
+                    &self.agent,
+                    "InvariantChecker",
+                    format!("stopped: {}", stop.reason),
+                );
+                return Ok(ControlFlow::Halt);
+            }
+        };
+        let Some(violation) = self.check(&event, block_number, tx_hash).await? else {
+            return Ok(ControlFlow::Continue);
+        };
+
+        let reason = format!("invariant `{}` violated", violation.invariant);
+        error!(
+            "{} at block {} by {:?}: {}",
+            reason, violation.block_number, violation.agent, violation.message
+        );
+        report::violated(violation);
+        let messager = self
+            .messager
+            .as_ref()
+            .ok_or(SimulationError::Uninitialized("messager"))?;
+        messager
+            .broadcast(Stop {
+                reason: reason.clone(),
+            })
+            .await?;
+        report::halted(&self.agent, "InvariantChecker", reason);
+        Ok(ControlFlow::Halt)
+    }
+}
@@ -0,0 +1,276 @@
+use std::sync::Arc;
+
+use anyhow::{ensure, Result};
+use arbiter_core::{events::stream_event, middleware::ArbiterMiddleware};
+use arbiter_engine::{
+    machine::{Behavior, ControlFlow, EventStream},
+    messager::Messager,
+};
+use ethers::types::U256;
+use futures::{stream, StreamExt};
+use tracing::{debug, info};
+
+use super::*;
+use crate::{
+    accounts, amounts,
+    behaviors::pool_deployer::{self, Market},
+    bindings::constant_product_pool::ConstantProductPoolEvents,
+    errors::{ErrorPolicy, SimulationError},
+    messages::{MessagerExt, Stop},
+    report, tx,
+};
+
+/// Precision withdrawn fractions are rounded to.
+const FRACTION_PRECISION: u64 = 1_000_000;
+
+/// Deposits into and withdraws from a pool following its rules, checked after
+/// every swap. Keeps running until every other behavior has halted or a `Stop`
+/// message arrives.
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct LiquidityProvider {
+    /// Name of the pool, as given to its `PoolDeployer`.
+    #[serde(default = "default_pool")]
+    pool: String,
+    /// Deposit made on startup, in tokens of each side, which sets the price
+    /// of an empty pool.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    initial: Option<Deposit>,
+    /// Evaluated in order after every swap.
+    #[serde(default)]
+    rules: Vec<Rule>,
+    #[serde(default)]
+    on_error: ErrorPolicy,
+    #[serde(skip)]
+    market: Option<Market>,
+    #[serde(skip)]
+    agent: String,
+    #[serde(skip)]
+    swaps: u64,
+    /// Whether the condition of each rule held after the previous swap.
+    #[serde(skip)]
+    held: Vec<bool>,
+}
+
+fn default_pool() -> String {
+    pool_deployer::POOL.to_owned()
+}
+
+/// Amounts of both tokens of a pool.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct Deposit {
+    base: f64,
+    quote: f64,
+}
+
+/// An action taken whenever a condition is met, e.g.
+/// `{ when = { every_swaps = 10 }, action = { deposit = 5.0 } }`.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct Rule {
+    when: Condition,
+    action: Action,
+}
+
+/// When a rule fires.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum Condition {
+    /// After every `n`-th swap of the pool, e.g. `{ every_swaps = 10 }`.
+    EverySwaps(u64),
+    /// When the pool price of a base token rises above this many quote
+    /// tokens, e.g. `{ price_above = 2200.0 }`.
+    PriceAbove(f64),
+    /// When the pool price of a base token falls below this many quote
+    /// tokens, e.g. `{ price_below = 1800.0 }`.
+    PriceBelow(f64),
+}
+
+/// What a rule does.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum Action {
+    /// Deposit this many base tokens together with the quote tokens matching
+    /// the pool price, e.g. `{ deposit = 5.0 }`.
+    Deposit(f64),
+    /// Withdraw this fraction of the provider's shares, e.g.
+    /// `{ withdraw = 0.5 }`.
+    Withdraw(f64),
+}
+
+/// Events a liquidity provider consumes.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum LiquidityEvent {
+    Pool(ConstantProductPoolEvents),
+    Stop(Stop),
+}
+
+impl Rule {
+    fn validate(&self) -> Result<()> {
+        match self.when {
+            Condition::EverySwaps(n) => ensure!(n > 0, "every_swaps must be at least 1"),
+            Condition::PriceAbove(price) | Condition::PriceBelow(price) => ensure!(
+                price.is_finite() && price > 0.0,
+                "rule price must be positive, got {}",
+                price
+            ),
+        }
+        match self.action {
+            Action::Deposit(amount) => ensure!(
+                amount.is_finite() && amount > 0.0,
+                "deposit must be positive, got {}",
+                amount
+            ),
+            Action::Withdraw(fraction) => ensure!(
+                fraction > 0.0 && fraction <= 1.0,
+                "withdraw fraction must be within (0, 1], got {}",
+                fraction
+            ),
+        }
+        Ok(())
+    }
+}
+
+impl LiquidityProvider {
+    fn market(&self) -> Result<Market> {
+        Ok(self
+            .market
+            .clone()
+            .ok_or(SimulationError::Uninitialized("pool"))?)
+    }
+
+    /// Applies every rule whose condition the swap just met. Price rules fire
+    /// when the price crosses their threshold, not on every swap beyond it.
+    async fn handle(&mut self) -> Result<ControlFlow> {
+        let market = self.market()?;
+        let price = market.price().await?;
+        for (index, rule) in self.rules.iter().enumerate() {
+            let holds = match (&rule.when, price) {
+                (Condition::EverySwaps(n), _) => self.swaps % n == 0,
+                (Condition::PriceAbove(threshold), Some(price)) => price > *threshold,
+                (Condition::PriceBelow(threshold), Some(price)) => price < *threshold,
+                (_, None) => false,
+            };
+            let crossed = match rule.when {
+                Condition::EverySwaps(_) => holds,
+                _ => holds && !self.held[index],
+            };
+            self.held[index] = holds;
+            if crossed {
+                debug!("Rule {} of the liquidity provider fired", index);
+                self.act(&market, &rule.action).await?;
+            }
+        }
+        Ok(ControlFlow::Continue)
+    }
+
+    async fn act(&self, market: &Market, action: &Action) -> Result<()> {
+        match *action {
+            Action::Deposit(amount) => {
+                let base = amounts::parse(amount, market.base_decimals)?;
+                let (base_reserve, quote_reserve) = market.reserves().await?;
+                ensure!(
+                    !base_reserve.is_zero(),
+                    "cannot deposit at the price of an empty pool"
+                );
+                let quote = base * quote_reserve / base_reserve;
+                self.deposit(market, base, quote).await
+            }
+            Action::Withdraw(fraction) => {
+                let owned = market
+                    .pool
+                    .shares_of(market.pool.client().address())
+                    .call()
+                    .await?;
+                let shares = owned * U256::from((fraction * FRACTION_PRECISION as f64) as u64)
+                    / U256::from(FRACTION_PRECISION);
+                if shares.is_zero() {
+                    debug!("Liquidity provider has no shares to withdraw");
+                    return Ok(());
+                }
+                tx::send_retrying(
+                    &self.agent,
+                    market.pool.remove_liquidity(shares),
+                    self.on_error.retries(),
+                )
+                .await?;
+                info!("Withdrew {} shares", shares);
+                Ok(())
+            }
+        }
+    }
+
+    async fn deposit(&self, market: &Market, base: U256, quote: U256) -> Result<()> {
+        tx::send_retrying(
+            &self.agent,
+            market.pool.add_liquidity(base, quote),
+            self.on_error.retries(),
+        )
+        .await?;
+        info!("Deposited {} base and {} quote units", base, quote);
+        Ok(())
+    }
+}
+
+#[async_trait::async_trait]
+impl Behavior<LiquidityEvent> for LiquidityProvider {
+    async fn startup(
+        &mut self,
+        client: Arc<ArbiterMiddleware>,
+        messager: Messager,
+    ) -> Result<Option<EventStream<LiquidityEvent>>> {
+        debug!("LiquidityProvider starting up");
+        for rule in &self.rules {
+            rule.validate()?;
+        }
+        self.held = vec![false; self.rules.len()];
+        self.agent = messager.id.clone().unwrap_or_default();
+        accounts::announce(&self.agent, client.address());
+        let stops: EventStream<LiquidityEvent> = Box::pin(
+            messager
+                .clone()
+                .subscribe::<Stop>()?
+                .map(|stop| LiquidityEvent::Stop(stop.message)),
+        );
+        let done: EventStream<LiquidityEvent> =
+            Box::pin(stream::once(report::workers_halted()).map(|_| {
+                LiquidityEvent::Stop(Stop {
+                    reason: "all workers halted".to_owned(),
+                })
+            }));
+        let market = Market::attach(&self.pool, &self.agent, client, messager).await?;
+        let events: EventStream<LiquidityEvent> =
+            Box::pin(stream_event(market.pool.events()).map(LiquidityEvent::Pool));
+        if let Some(initial) = &self.initial {
+            let base = amounts::parse(initial.base, market.base_decimals)?;
+            let quote = amounts::parse(initial.quote, market.quote_decimals)?;
+            self.deposit(&market, base, quote).await?;
+        }
+        self.market = Some(market);
+        Ok(Some(Box::pin(stream::select_all([events, stops, done]))))
+    }
+
+    async fn process(&mut self, event: LiquidityEvent) -> Result<ControlFlow> {
+        let event = match event {
+            LiquidityEvent::Stop(stop) => {
+                info!("LiquidityProvider stopped: {}", stop.reason);
+                report::halted(
+                    &self.agent,
+                    "LiquidityProvider",
+                    format!("stopped: {}", stop.reason),
+                );
+                return Ok(ControlFlow::Halt);
+            }
+            LiquidityEvent::Pool(event) => event,
+        };
+        if !matches!(event, ConstantProductPoolEvents::SwapFilter(_)) {
+            return Ok(ControlFlow::Continue);
+        }
+        self.swaps += 1;
+        let result = self.handle().await;
+        self.on_error
+            .resolve(result, &self.agent, "LiquidityProvider")
+            .unwrap_or(Ok(ControlFlow::Continue))
+    }
+}
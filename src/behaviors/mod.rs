@@ -1,12 +1,97 @@
-use arbiter_engine::machine::{CreateStateMachine, Engine, StateMachine};
-use arbiter_macros::Behaviors;
+use arbiter_engine::machine::{Behavior, CreateStateMachine, Engine, StateMachine};
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
+use crate::{limits, report::Recorded};
+
+pub mod adversary;
+pub mod arbitrageur;
+pub mod clock;
+pub mod deployer;
+pub mod guarded_deployer;
 pub mod incrementer;
+pub mod invariant_checker;
+pub mod liquidity_provider;
+pub mod noise_trader;
+pub mod pool_deployer;
+pub mod price_process;
+pub mod setter;
+pub mod token_deployer;
+pub mod transferrer;
+
+/// Declares [`Behaviors`], with a variant per behavior named after its type,
+/// and everything listing them, so that a new behavior only needs its module
+/// and a line below. Behaviors marked `#[observer]` make up [`OBSERVERS`].
+macro_rules! behaviors {
+    (@observer observer $name:ident) => {
+        stringify!($name)
+    };
+    ($($(#[$observer:ident])? $module:ident::$name:ident,)*) => {
+        /// Behaviors that only watch the others and keep running until every
+        /// other behavior has halted, including the runtime's
+        /// [`limits::Limiter`].
+        pub const OBSERVERS: &[&str] = &[
+            $($(behaviors!(@observer $observer $name),)?)*
+            limits::BEHAVIOR,
+        ];
+
+        #[derive(Debug, Serialize, Deserialize, JsonSchema)]
+        pub enum Behaviors {
+            $($name($module::$name),)*
+        }
+
+        impl Behaviors {
+            /// Name of the behavior as written in configs.
+            pub fn name(&self) -> &'static str {
+                match self {
+                    $(Behaviors::$name(_) => stringify!($name),)*
+                }
+            }
 
-use incrementer::Incrementer;
+            /// Every behavior with the event type it consumes.
+            pub fn catalog() -> Vec<(&'static str, &'static str)> {
+                vec![$((stringify!($name), consumes::<$module::$name, _>()),)*]
+            }
+        }
+
+        impl CreateStateMachine for Behaviors {
+            /// Runs the behavior in an engine that records its errors in the
+            /// run report, see [`Recorded`].
+            fn create_state_machine(self) -> Box<dyn StateMachine> {
+                let name = self.name();
+                match self {
+                    $(Behaviors::$name(behavior) => {
+                        Box::new(Engine::new(Recorded::new(name, behavior)))
+                    })*
+                }
+            }
+        }
+    };
+}
+
+behaviors! {
+    adversary::Adversary,
+    #[observer]
+    arbitrageur::Arbitrageur,
+    #[observer]
+    clock::Clock,
+    deployer::Deployer,
+    guarded_deployer::GuardedDeployer,
+    incrementer::Incrementer,
+    #[observer]
+    invariant_checker::InvariantChecker,
+    #[observer]
+    liquidity_provider::LiquidityProvider,
+    noise_trader::NoiseTrader,
+    pool_deployer::PoolDeployer,
+    price_process::PriceProcess,
+    setter::Setter,
+    token_deployer::TokenDeployer,
+    transferrer::Transferrer,
+}
 
-#[derive(Debug, Serialize, Deserialize, Behaviors)]
-pub enum Behaviors {
-    Incrementer(Incrementer),
+/// Name of the event type `B` consumes, without its module path.
+fn consumes<B: Behavior<E>, E>() -> &'static str {
+    let name = std::any::type_name::<E>();
+    name.rsplit("::").next().unwrap_or(name)
 }
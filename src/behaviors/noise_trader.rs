@@ -0,0 +1,278 @@
+use std::sync::Arc;
+
+use anyhow::{ensure, Result};
+use arbiter_core::{events::stream_event, middleware::ArbiterMiddleware};
+use arbiter_engine::{
+    machine::{Behavior, ControlFlow, EventStream},
+    messager::Messager,
+};
+use ethers::{providers::Middleware, types::Address};
+use futures::{stream, StreamExt};
+use rand::Rng;
+use rand_distr::{Distribution, Exp, LogNormal};
+use tracing::{debug, info};
+
+use super::*;
+use crate::{
+    accounts, amounts,
+    behaviors::{
+        incrementer::{Wait, SECONDS_PER_BLOCK},
+        pool_deployer::{self, Market},
+    },
+    bindings::constant_product_pool::ConstantProductPoolEvents,
+    errors::{ErrorPolicy, SimulationError},
+    messages::{MessagerExt, Stop},
+    replay, report,
+    rng::{self, SimRng},
+    tx,
+};
+
+/// Swaps a random side and size on a pool, one swap per `Swap` event of its
+/// own, and halts after `max_swaps` or on a `Stop` message. Waits for the
+/// first deposit if the pool is empty when it starts.
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct NoiseTrader {
+    /// Name of the pool, as given to its `PoolDeployer`.
+    #[serde(default = "default_pool")]
+    pool: String,
+    /// Size of every swap in base tokens. Buys spend the quote tokens it is
+    /// worth at the pool price.
+    size: Size,
+    /// Chance that a swap buys base tokens rather than selling them.
+    #[serde(default = "default_buy_probability")]
+    buy_probability: f64,
+    max_swaps: u64,
+    /// Blocks between a swap and the next one, none if omitted.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    wait: Option<Wait>,
+    #[serde(default)]
+    on_error: ErrorPolicy,
+    #[serde(skip)]
+    market: Option<Market>,
+    #[serde(skip)]
+    agent: String,
+    #[serde(skip)]
+    address: Address,
+    #[serde(skip)]
+    rng: Option<SimRng>,
+    #[serde(skip)]
+    swaps: u64,
+    #[serde(skip)]
+    started: bool,
+}
+
+fn default_pool() -> String {
+    pool_deployer::POOL.to_owned()
+}
+
+fn default_buy_probability() -> f64 {
+    0.5
+}
+
+/// Distribution of swap sizes, in base tokens.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum Size {
+    /// The same size every time, e.g. `{ fixed = 1.0 }`.
+    Fixed(f64),
+    /// Uniform over a range, e.g. `{ uniform = [0.1, 2.0] }`.
+    Uniform(f64, f64),
+    /// Exponentially distributed with the given mean, e.g.
+    /// `{ exponential = 1.0 }`.
+    Exponential(f64),
+    /// Log-normally distributed, given the mean and standard deviation of the
+    /// size's logarithm, e.g. `{ log_normal = [0.0, 0.5] }`.
+    LogNormal(f64, f64),
+}
+
+impl Size {
+    fn validate(&self) -> Result<()> {
+        match *self {
+            Size::Fixed(size) => ensure!(
+                size.is_finite() && size > 0.0,
+                "size must be positive, got {}",
+                size
+            ),
+            Size::Uniform(min, max) => ensure!(
+                0.0 < min && min <= max && max.is_finite(),
+                "size range [{}, {}] must be non-empty and positive",
+                min,
+                max
+            ),
+            Size::Exponential(mean) => ensure!(
+                mean.is_finite() && mean > 0.0,
+                "size mean must be positive, got {}",
+                mean
+            ),
+            Size::LogNormal(mu, sigma) => ensure!(
+                mu.is_finite() && sigma.is_finite() && sigma >= 0.0,
+                "log_normal needs a finite mu and a non-negative sigma, got [{}, {}]",
+                mu,
+                sigma
+            ),
+        }
+        Ok(())
+    }
+
+    fn sample(&self, rng: &mut SimRng) -> f64 {
+        match *self {
+            Size::Fixed(size) => size,
+            Size::Uniform(min, max) => rng.gen_range(min..=max),
+            Size::Exponential(mean) => Exp::new(1.0 / mean).unwrap().sample(rng),
+            Size::LogNormal(mu, sigma) => LogNormal::new(mu, sigma).unwrap().sample(rng),
+        }
+    }
+}
+
+/// Events a noise trader consumes.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum TraderEvent {
+    Pool(ConstantProductPoolEvents),
+    Stop(Stop),
+}
+
+impl NoiseTrader {
+    async fn handle(&mut self) -> Result<ControlFlow> {
+        if self.swaps >= self.max_swaps {
+            info!("NoiseTrader done");
+            report::halted(&self.agent, "NoiseTrader", "max_swaps reached");
+            return Ok(ControlFlow::Halt);
+        }
+        self.wait().await?;
+        self.swap().await?;
+        Ok(ControlFlow::Continue)
+    }
+
+    /// Moves the environment forward by a number of blocks drawn from `wait`.
+    async fn wait(&mut self) -> Result<()> {
+        let (Some(wait), Some(market)) = (&self.wait, &self.market) else {
+            return Ok(());
+        };
+        let rng = self
+            .rng
+            .as_mut()
+            .ok_or(SimulationError::Uninitialized("rng"))?;
+        let blocks = wait.sample(rng);
+        if blocks > 0 {
+            let client = market.pool.client();
+            let block_number = client.get_block_number().await?.as_u64() + blocks;
+            let timestamp = client.get_block_timestamp().await? + blocks * SECONDS_PER_BLOCK;
+            replay::update_block(&client, block_number, timestamp).await?;
+            debug!("NoiseTrader waited {} blocks", blocks);
+        }
+        Ok(())
+    }
+
+    /// Sends the next swap, which counts towards `max_swaps` even if it fails.
+    /// Waits for liquidity again instead if the pool has been drained.
+    async fn swap(&mut self) -> Result<()> {
+        let market = self
+            .market
+            .clone()
+            .ok_or(SimulationError::Uninitialized("pool"))?;
+        let rng = self
+            .rng
+            .as_mut()
+            .ok_or(SimulationError::Uninitialized("rng"))?;
+        let sell_base = !rng.gen_bool(self.buy_probability);
+        let size = self.size.sample(rng);
+        let base = amounts::parse(size, market.base_decimals)?;
+        let (base_reserve, quote_reserve) = market.reserves().await?;
+        if base_reserve.is_zero() || quote_reserve.is_zero() {
+            debug!("NoiseTrader waiting for liquidity");
+            self.started = false;
+            return Ok(());
+        }
+        self.swaps += 1;
+        let amount_in = if sell_base {
+            base
+        } else {
+            base.checked_mul(quote_reserve)
+                .and_then(|quote| quote.checked_div(base_reserve))
+                .ok_or(SimulationError::Overflow("quote amount"))?
+        };
+        tx::send_retrying(
+            &self.agent,
+            market.pool.swap(sell_base, amount_in, 0.into()),
+            self.on_error.retries(),
+        )
+        .await?;
+        debug!(
+            "NoiseTrader {} {} base tokens",
+            if sell_base { "sold" } else { "bought" },
+            size
+        );
+        Ok(())
+    }
+}
+
+#[async_trait::async_trait]
+impl Behavior<TraderEvent> for NoiseTrader {
+    async fn startup(
+        &mut self,
+        client: Arc<ArbiterMiddleware>,
+        messager: Messager,
+    ) -> Result<Option<EventStream<TraderEvent>>> {
+        debug!("NoiseTrader starting up");
+        self.size.validate()?;
+        ensure!(
+            (0.0..=1.0).contains(&self.buy_probability),
+            "buy_probability must be within [0, 1], got {}",
+            self.buy_probability
+        );
+        ensure!(self.max_swaps > 0, "max_swaps must be at least 1");
+        if let Some(wait) = &self.wait {
+            wait.validate()?;
+        }
+
+        self.agent = messager.id.clone().unwrap_or_default();
+        self.address = client.address();
+        accounts::announce(&self.agent, self.address);
+        self.rng = Some(rng::stream(&self.agent, "NoiseTrader"));
+        let stops = messager
+            .clone()
+            .subscribe::<Stop>()?
+            .map(|stop| TraderEvent::Stop(stop.message));
+        let market = Market::attach(&self.pool, &self.agent, client, messager).await?;
+        let events = stream_event(market.pool.events()).map(TraderEvent::Pool);
+        self.started = market.price().await?.is_some();
+        self.market = Some(market);
+        if self.started {
+            self.swap().await?;
+        } else {
+            debug!("NoiseTrader waiting for liquidity");
+        }
+        Ok(Some(Box::pin(stream::select(events, stops))))
+    }
+
+    async fn process(&mut self, event: TraderEvent) -> Result<ControlFlow> {
+        let event = match event {
+            TraderEvent::Stop(stop) => {
+                info!("NoiseTrader stopped: {}", stop.reason);
+                report::halted(
+                    &self.agent,
+                    "NoiseTrader",
+                    format!("stopped: {}", stop.reason),
+                );
+                return Ok(ControlFlow::Halt);
+            }
+            TraderEvent::Pool(event) => event,
+        };
+        match event {
+            ConstantProductPoolEvents::LiquidityAddedFilter(_) if !self.started => {
+                self.started = true
+            }
+            ConstantProductPoolEvents::SwapFilter(swap) if swap.trader == self.address => {}
+            _ => return Ok(ControlFlow::Continue),
+        }
+        // Only its own swaps trigger the next one, so a skipped swap is followed
+        // by the next one right away.
+        loop {
+            let result = self.handle().await;
+            if let Some(flow) = self.on_error.resolve(result, &self.agent, "NoiseTrader") {
+                return flow;
+            }
+        }
+    }
+}
@@ -0,0 +1,147 @@
+use std::sync::Arc;
+
+use anyhow::{ensure, Result};
+use arbiter_core::middleware::ArbiterMiddleware;
+use arbiter_engine::{
+    machine::{Behavior, ControlFlow, EventStream},
+    messager::Messager,
+};
+use ethers::types::{Address, U256};
+use tracing::{debug, info};
+
+use super::*;
+use crate::{
+    amounts,
+    behaviors::deployer,
+    bindings::{constant_product_pool::ConstantProductPool, token::Token},
+    messages::{ContractDeployed, MessagerExt, Stop},
+    report, tx,
+};
+
+/// Name of the pool a `PoolDeployer` deploys when it is not given one.
+pub const POOL: &str = "pool";
+
+/// Basis points in a whole, which fees must stay below.
+pub const FEE_DENOMINATOR: u64 = 10_000;
+
+/// Waits for two tokens, deploys a `ConstantProductPool` trading them and
+/// broadcasts its address so that behaviors configured with
+/// `pool = "<name>"` can use it. The pool starts empty until a
+/// `LiquidityProvider` deposits.
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct PoolDeployer {
+    #[serde(default = "default_name")]
+    name: String,
+    /// Name of the token prices are quoted for.
+    base: String,
+    /// Name of the token prices are quoted in.
+    quote: String,
+    /// Fee charged on every swap input, in basis points.
+    #[serde(default = "default_fee_bps")]
+    fee_bps: u64,
+}
+
+fn default_name() -> String {
+    POOL.to_owned()
+}
+
+fn default_fee_bps() -> u64 {
+    30
+}
+
+#[async_trait::async_trait]
+impl Behavior<Stop> for PoolDeployer {
+    async fn startup(
+        &mut self,
+        client: Arc<ArbiterMiddleware>,
+        messager: Messager,
+    ) -> Result<Option<EventStream<Stop>>> {
+        ensure!(
+            self.fee_bps < FEE_DENOMINATOR,
+            "fee_bps must be below {}, got {}",
+            FEE_DENOMINATOR,
+            self.fee_bps
+        );
+        let agent = messager.id.clone().unwrap_or_default();
+        let sender = messager.clone();
+        let tokens = deployer::deployed_all(&[&self.base, &self.quote], messager).await?;
+        let args = (tokens[0], tokens[1], U256::from(self.fee_bps));
+        let pool = tx::deploy(&agent, ConstantProductPool::deploy(client, args)?).await?;
+        let deployed = ContractDeployed {
+            name: self.name.clone(),
+            address: pool.address(),
+        };
+        info!(
+            "Deployed pool `{}` at {:?}",
+            deployed.name, deployed.address
+        );
+        sender.broadcast(deployed).await?;
+        report::halted(&agent, "PoolDeployer", "deployed");
+        Ok(None)
+    }
+
+    async fn process(&mut self, _event: Stop) -> Result<ControlFlow> {
+        Ok(ControlFlow::Halt)
+    }
+}
+
+/// A pool together with the tokens it trades, as the behaviors trading on it
+/// see it.
+#[derive(Debug, Clone)]
+pub struct Market {
+    pub pool: ConstantProductPool<ArbiterMiddleware>,
+    pub base: Token<ArbiterMiddleware>,
+    pub quote: Token<ArbiterMiddleware>,
+    pub base_decimals: u8,
+    pub quote_decimals: u8,
+}
+
+impl Market {
+    /// Waits for the pool called `name` to be deployed and lets it spend both
+    /// its tokens on behalf of `agent`.
+    pub async fn attach(
+        name: &str,
+        agent: &str,
+        client: Arc<ArbiterMiddleware>,
+        messager: Messager,
+    ) -> Result<Self> {
+        let address = deployer::deployed(name, messager).await?;
+        debug!("Attaching to pool `{}` at {:?}", name, address);
+        Self::at(address, agent, client).await
+    }
+
+    /// Lets the pool at `address` spend both its tokens on behalf of `agent`.
+    pub async fn at(address: Address, agent: &str, client: Arc<ArbiterMiddleware>) -> Result<Self> {
+        let pool = ConstantProductPool::new(address, client.clone());
+        let base = Token::new(pool.base().call().await?, client.clone());
+        let quote = Token::new(pool.quote().call().await?, client);
+        for token in [&base, &quote] {
+            tx::send(agent, token.approve(address, U256::MAX)).await?;
+        }
+        Ok(Self {
+            base_decimals: base.decimals().call().await?,
+            quote_decimals: quote.decimals().call().await?,
+            pool,
+            base,
+            quote,
+        })
+    }
+
+    /// The base and quote reserves of the pool.
+    pub async fn reserves(&self) -> Result<(U256, U256)> {
+        Ok(self.pool.get_reserves().call().await?)
+    }
+
+    /// Price of a base token in quote tokens implied by the reserves, `None`
+    /// while the pool is empty.
+    pub async fn price(&self) -> Result<Option<f64>> {
+        let (base, quote) = self.reserves().await?;
+        if base.is_zero() {
+            return Ok(None);
+        }
+        Ok(Some(
+            amounts::tokens(quote, self.quote_decimals) / amounts::tokens(base, self.base_decimals),
+        ))
+    }
+}
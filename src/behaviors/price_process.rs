@@ -0,0 +1,384 @@
+use std::sync::Arc;
+
+use anyhow::{ensure, Result};
+use arbiter_core::{events::stream_event, middleware::ArbiterMiddleware};
+use arbiter_engine::{
+    machine::{Behavior, ControlFlow, EventStream},
+    messager::Messager,
+};
+use ethers::providers::Middleware;
+use futures::{stream, StreamExt};
+use rand_distr::{Distribution, Normal, Poisson, StandardNormal};
+use tracing::{debug, info};
+
+use super::*;
+use crate::{
+    amounts,
+    behaviors::incrementer::SECONDS_PER_BLOCK,
+    bindings::price_oracle::{PriceOracle, PriceUpdatedFilter},
+    errors::{ErrorPolicy, SimulationError},
+    messages::{ContractDeployed, MessagerExt, Stop},
+    replay, report,
+    rng::{self, SimRng},
+    tx,
+};
+
+/// Name of the oracle a `PriceProcess` deploys when it is not given one.
+pub const ORACLE: &str = "oracle";
+
+/// Decimals of the prices a `PriceOracle` stores.
+pub const PRICE_DECIMALS: u8 = 18;
+
+/// Deploys a `PriceOracle` and pushes a sampled path of a stochastic process
+/// to it, one step per `PriceUpdated` event of its own, moving the environment
+/// forward by `dt` blocks each step. Halts after `steps` or on a `Stop`
+/// message.
+///
+/// Time is measured in blocks, so drift, volatility, mean reversion and jump
+/// intensity are rates per block.
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct PriceProcess {
+    /// Name other behaviors refer to the oracle by.
+    #[serde(default = "default_name")]
+    name: String,
+    initial_price: f64,
+    process: Process,
+    /// Blocks per step.
+    #[serde(default = "default_dt")]
+    dt: u64,
+    steps: u64,
+    /// Seed of the path, derived from the run seed if omitted.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    seed: Option<u64>,
+    #[serde(default)]
+    on_error: ErrorPolicy,
+    #[serde(skip)]
+    contract: Option<PriceOracle<ArbiterMiddleware>>,
+    #[serde(skip)]
+    agent: String,
+    #[serde(skip)]
+    rng: Option<SimRng>,
+    #[serde(skip)]
+    price: f64,
+    #[serde(skip)]
+    step: u64,
+}
+
+fn default_name() -> String {
+    ORACLE.to_owned()
+}
+
+fn default_dt() -> u64 {
+    1
+}
+
+/// The stochastic process prices follow.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields, rename_all = "snake_case")]
+pub enum Process {
+    /// Geometric Brownian motion, e.g.
+    /// `{ gbm = { drift = 0.0, volatility = 0.01 } }`.
+    Gbm { drift: f64, volatility: f64 },
+    /// Ornstein-Uhlenbeck on the price itself, pulled towards `mean`, e.g.
+    /// `{ ornstein_uhlenbeck = { mean = 2000.0, mean_reversion = 0.1, volatility = 20.0 } }`.
+    /// Prices are floored at zero.
+    OrnsteinUhlenbeck {
+        mean: f64,
+        mean_reversion: f64,
+        volatility: f64,
+    },
+    /// Merton jump diffusion: geometric Brownian motion with jumps arriving
+    /// at `jump_intensity` per block, whose log sizes are normal with
+    /// `jump_mean` and `jump_volatility`. The drift is compensated so that
+    /// jumps do not change the expected return.
+    JumpDiffusion {
+        drift: f64,
+        volatility: f64,
+        jump_intensity: f64,
+        jump_mean: f64,
+        jump_volatility: f64,
+    },
+}
+
+/// Events a price process consumes.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum PriceEvent {
+    PriceUpdated(PriceUpdatedFilter),
+    Stop(Stop),
+}
+
+impl Process {
+    fn validate(&self) -> Result<()> {
+        let finite_non_negative = |name: &str, value: f64| -> Result<()> {
+            ensure!(
+                value.is_finite() && value >= 0.0,
+                "{} must be non-negative, got {}",
+                name,
+                value
+            );
+            Ok(())
+        };
+        match *self {
+            Process::Gbm { drift, volatility } => {
+                ensure!(drift.is_finite(), "drift must be finite");
+                finite_non_negative("volatility", volatility)?;
+            }
+            Process::OrnsteinUhlenbeck {
+                mean,
+                mean_reversion,
+                volatility,
+            } => {
+                finite_non_negative("mean", mean)?;
+                ensure!(
+                    mean_reversion.is_finite() && mean_reversion > 0.0,
+                    "mean_reversion must be positive, got {}",
+                    mean_reversion
+                );
+                finite_non_negative("volatility", volatility)?;
+            }
+            Process::JumpDiffusion {
+                drift,
+                volatility,
+                jump_intensity,
+                jump_mean,
+                jump_volatility,
+            } => {
+                ensure!(drift.is_finite(), "drift must be finite");
+                ensure!(jump_mean.is_finite(), "jump_mean must be finite");
+                finite_non_negative("volatility", volatility)?;
+                finite_non_negative("jump_intensity", jump_intensity)?;
+                finite_non_negative("jump_volatility", jump_volatility)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// The price `dt` blocks after `price`.
+    fn step(&self, price: f64, dt: f64, rng: &mut SimRng) -> f64 {
+        let z: f64 = StandardNormal.sample(rng);
+        match *self {
+            Process::Gbm { drift, volatility } => {
+                price * ((drift - volatility.powi(2) / 2.0) * dt + volatility * dt.sqrt() * z).exp()
+            }
+            Process::OrnsteinUhlenbeck {
+                mean,
+                mean_reversion,
+                volatility,
+            } => {
+                let decay = (-mean_reversion * dt).exp();
+                let spread = volatility * ((1.0 - decay.powi(2)) / (2.0 * mean_reversion)).sqrt();
+                (price * decay + mean * (1.0 - decay) + spread * z).max(0.0)
+            }
+            Process::JumpDiffusion {
+                drift,
+                volatility,
+                jump_intensity,
+                jump_mean,
+                jump_volatility,
+            } => {
+                let compensator =
+                    jump_intensity * ((jump_mean + jump_volatility.powi(2) / 2.0).exp() - 1.0);
+                let jumps = if jump_intensity > 0.0 {
+                    Poisson::new(jump_intensity * dt).unwrap().sample(rng) as u64
+                } else {
+                    0
+                };
+                let jump_size = Normal::new(jump_mean, jump_volatility).unwrap();
+                let jump: f64 = (0..jumps).map(|_| jump_size.sample(rng)).sum();
+                let diffusion = (drift - volatility.powi(2) / 2.0 - compensator) * dt
+                    + volatility * dt.sqrt() * z;
+                price * (diffusion + jump).exp()
+            }
+        }
+    }
+}
+
+impl PriceProcess {
+    async fn handle(&mut self) -> Result<ControlFlow> {
+        if self.step >= self.steps {
+            info!("PriceProcess done");
+            report::halted(&self.agent, "PriceProcess", "steps reached");
+            return Ok(ControlFlow::Halt);
+        }
+        self.advance().await?;
+        Ok(ControlFlow::Continue)
+    }
+
+    /// Moves the environment forward by `dt` blocks and pushes the price
+    /// sampled for the new block. The step counts towards `steps` and the path
+    /// moves on even if pushing the price fails.
+    async fn advance(&mut self) -> Result<()> {
+        let oracle = self
+            .contract
+            .clone()
+            .ok_or(SimulationError::Uninitialized("oracle"))?;
+        let rng = self
+            .rng
+            .as_mut()
+            .ok_or(SimulationError::Uninitialized("rng"))?;
+        let price = self.process.step(self.price, self.dt as f64, rng);
+        let client = oracle.client();
+        let block_number = client.get_block_number().await?.as_u64() + self.dt;
+        let timestamp = client.get_block_timestamp().await? + self.dt * SECONDS_PER_BLOCK;
+        replay::update_block(&client, block_number, timestamp).await?;
+        self.price = price;
+        self.step += 1;
+        tx::send_retrying(
+            &self.agent,
+            oracle.set_price(amounts::parse(price, PRICE_DECIMALS)?),
+            self.on_error.retries(),
+        )
+        .await?;
+        debug!("Price at block {} is {}", block_number, price);
+        Ok(())
+    }
+}
+
+#[async_trait::async_trait]
+impl Behavior<PriceEvent> for PriceProcess {
+    async fn startup(
+        &mut self,
+        client: Arc<ArbiterMiddleware>,
+        messager: Messager,
+    ) -> Result<Option<EventStream<PriceEvent>>> {
+        debug!("PriceProcess starting up");
+        ensure!(
+            self.initial_price.is_finite() && self.initial_price > 0.0,
+            "initial_price must be positive, got {}",
+            self.initial_price
+        );
+        ensure!(self.dt > 0, "dt must be at least 1 block");
+        ensure!(self.steps > 0, "steps must be at least 1");
+        self.process.validate()?;
+
+        self.agent = messager.id.clone().unwrap_or_default();
+        self.rng = Some(match self.seed {
+            Some(seed) => rng::seeded_stream(seed, &self.agent, "PriceProcess"),
+            None => rng::stream(&self.agent, "PriceProcess"),
+        });
+        self.price = self.initial_price;
+        let stops = messager
+            .clone()
+            .subscribe::<Stop>()?
+            .map(|stop| PriceEvent::Stop(stop.message));
+        let initial_price = amounts::parse(self.initial_price, PRICE_DECIMALS)?;
+        let oracle = tx::deploy(&self.agent, PriceOracle::deploy(client, initial_price)?).await?;
+        let deployed = ContractDeployed {
+            name: self.name.clone(),
+            address: oracle.address(),
+        };
+        info!(
+            "Deployed oracle `{}` at {:?}",
+            deployed.name, deployed.address
+        );
+        messager.broadcast(deployed).await?;
+        let updates = stream_event(oracle.price_updated_filter()).map(PriceEvent::PriceUpdated);
+        self.contract = Some(oracle);
+        self.advance().await?;
+        Ok(Some(Box::pin(stream::select(updates, stops))))
+    }
+
+    async fn process(&mut self, event: PriceEvent) -> Result<ControlFlow> {
+        debug!("PriceProcess processing {:?}", event);
+        if let PriceEvent::Stop(stop) = event {
+            info!("PriceProcess stopped: {}", stop.reason);
+            report::halted(
+                &self.agent,
+                "PriceProcess",
+                format!("stopped: {}", stop.reason),
+            );
+            return Ok(ControlFlow::Halt);
+        }
+        // Only its own updates trigger the next one, so a skipped step is
+        // followed by the next one right away.
+        loop {
+            let result = self.handle().await;
+            if let Some(flow) = self.on_error.resolve(result, &self.agent, "PriceProcess") {
+                return flow;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SAMPLES: usize = 200_000;
+
+    fn gbm() -> Process {
+        Process::Gbm {
+            drift: 0.001,
+            volatility: 0.02,
+        }
+    }
+
+    fn jump_diffusion() -> Process {
+        Process::JumpDiffusion {
+            drift: 0.0,
+            volatility: 0.01,
+            jump_intensity: 0.5,
+            jump_mean: -0.1,
+            jump_volatility: 0.2,
+        }
+    }
+
+    fn path(process: &Process, seed: u64, steps: usize) -> Vec<f64> {
+        let mut rng = rng::seeded_stream(seed, "oracle", "PriceProcess");
+        let mut price = 2_000.0;
+        (0..steps)
+            .map(|_| {
+                price = process.step(price, 1.0, &mut rng);
+                price
+            })
+            .collect()
+    }
+
+    /// Average of `price_{t+1} / price_t` over many independent steps.
+    fn mean_return(process: &Process) -> f64 {
+        let mut rng = rng::seeded_stream(1, "oracle", "PriceProcess");
+        (0..SAMPLES)
+            .map(|_| process.step(1.0, 1.0, &mut rng))
+            .sum::<f64>()
+            / SAMPLES as f64
+    }
+
+    #[test]
+    fn seeded_paths_are_deterministic() {
+        let ornstein_uhlenbeck = Process::OrnsteinUhlenbeck {
+            mean: 2_000.0,
+            mean_reversion: 0.1,
+            volatility: 20.0,
+        };
+        for process in [gbm(), ornstein_uhlenbeck, jump_diffusion()] {
+            assert_eq!(path(&process, 7, 100), path(&process, 7, 100));
+            assert_ne!(path(&process, 7, 100), path(&process, 8, 100));
+        }
+    }
+
+    #[test]
+    fn ornstein_uhlenbeck_is_floored_at_zero() {
+        let process = Process::OrnsteinUhlenbeck {
+            mean: 0.0,
+            mean_reversion: 0.5,
+            volatility: 1_000.0,
+        };
+        let prices = path(&process, 7, 1_000);
+        assert!(prices.iter().all(|price| *price >= 0.0));
+        assert!(prices.contains(&0.0));
+    }
+
+    #[test]
+    fn gbm_grows_at_its_drift() {
+        let expected = (0.001f64).exp();
+        assert!((mean_return(&gbm()) - expected).abs() < 1e-3);
+    }
+
+    #[test]
+    fn jumps_leave_the_expected_return_unchanged() {
+        // Without the compensator the negative jumps would pull the mean
+        // return down to about 0.96.
+        assert!((mean_return(&jump_diffusion()) - 1.0).abs() < 5e-3);
+    }
+}
@@ -0,0 +1,263 @@
+use std::{
+    collections::HashSet,
+    path::{Path, PathBuf},
+    sync::Arc,
+};
+
+use anyhow::{anyhow, ensure, Context, Result};
+use arbiter_core::middleware::ArbiterMiddleware;
+use arbiter_engine::{
+    machine::{Behavior, ControlFlow, EventStream},
+    messager::Messager,
+};
+use ethers::types::{H256, U256};
+use futures::{stream, StreamExt};
+use rand_distr::{Distribution, Normal};
+use tracing::{debug, info};
+
+use super::*;
+use crate::{
+    behaviors::deployer,
+    bindings::modified_counter::{ModifiedCounter, NumberSetFilter},
+    errors::{ErrorPolicy, SimulationError},
+    messages::{MessagerExt, Stop},
+    report,
+    rng::{self, SimRng},
+    tx,
+};
+
+/// Calls `setNumber` with the values of a schedule, one per `NumberSet`
+/// event of its own, and halts once the schedule is exhausted or on a `Stop` message.
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct Setter {
+    schedule: Schedule,
+    /// Name of a shared counter to attach to, a private one is deployed if
+    /// omitted.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    counter: Option<String>,
+    #[serde(default)]
+    on_error: ErrorPolicy,
+    #[serde(skip)]
+    values: Vec<U256>,
+    #[serde(skip)]
+    next: usize,
+    #[serde(skip)]
+    contract: Option<ModifiedCounter<ArbiterMiddleware>>,
+    #[serde(skip)]
+    agent: String,
+    /// Transactions of its own `setNumber` calls whose events are still to
+    /// come.
+    #[serde(skip)]
+    pending_sets: HashSet<H256>,
+}
+
+/// Where the values passed to `setNumber` come from. Values produced by a
+/// function are rounded to the nearest integer and clamped at zero.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields, rename_all = "snake_case")]
+pub enum Schedule {
+    /// An explicit list, e.g. `{ values = [1, 5, 3] }`.
+    Values(Vec<u64>),
+    /// A time series read from a CSV file with a header row.
+    Csv {
+        path: PathBuf,
+        /// Column holding the values, the first one if omitted.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        column: Option<String>,
+    },
+    /// `offset + amplitude * sin(2π * step / period)` for `steps` steps.
+    Sine {
+        amplitude: f64,
+        offset: f64,
+        period: f64,
+        steps: usize,
+    },
+    /// A walk starting at `start` with normally distributed steps of standard
+    /// deviation `volatility`, drawn from the setter's random stream.
+    RandomWalk {
+        start: f64,
+        volatility: f64,
+        steps: usize,
+    },
+}
+
+impl Schedule {
+    fn values(&self, rng: &mut SimRng) -> Result<Vec<U256>> {
+        let values = match self {
+            Schedule::Values(values) => values.iter().map(|&value| U256::from(value)).collect(),
+            Schedule::Csv { path, column } => read_csv(path, column.as_deref())?,
+            Schedule::Sine {
+                amplitude,
+                offset,
+                period,
+                steps,
+            } => {
+                ensure!(*period > 0.0, "sine period must be positive");
+                (0..*steps)
+                    .map(|step| {
+                        let phase = 2.0 * std::f64::consts::PI * step as f64 / period;
+                        to_u256(offset + amplitude * phase.sin())
+                    })
+                    .collect()
+            }
+            Schedule::RandomWalk {
+                start,
+                volatility,
+                steps,
+            } => {
+                let normal = Normal::new(0.0, *volatility)
+                    .map_err(|e| anyhow!("invalid random walk volatility: {}", e))?;
+                let mut level = *start;
+                (0..*steps)
+                    .map(|_| {
+                        let value = to_u256(level);
+                        level = (level + normal.sample(rng)).max(0.0);
+                        value
+                    })
+                    .collect()
+            }
+        };
+        Ok(values)
+    }
+}
+
+/// Events a setter consumes.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum SetterEvent {
+    NumberSet {
+        event: NumberSetFilter,
+        tx_hash: H256,
+    },
+    Stop(Stop),
+}
+
+fn to_u256(value: f64) -> U256 {
+    U256::from(value.max(0.0).round() as u128)
+}
+
+fn read_csv(path: &Path, column: Option<&str>) -> Result<Vec<U256>> {
+    let mut reader = csv::Reader::from_path(path)
+        .with_context(|| format!("failed to open schedule {}", path.display()))?;
+    let index = match column {
+        Some(column) => reader
+            .headers()?
+            .iter()
+            .position(|header| header == column)
+            .ok_or_else(|| anyhow!("{} has no column `{}`", path.display(), column))?,
+        None => 0,
+    };
+    let mut values = Vec::new();
+    for (row, record) in reader.records().enumerate() {
+        let record = record?;
+        let field = record
+            .get(index)
+            .ok_or_else(|| anyhow!("{} row {} is missing a value", path.display(), row + 1))?
+            .trim();
+        let value = match U256::from_dec_str(field) {
+            Ok(value) => value,
+            Err(_) => to_u256(field.parse::<f64>().with_context(|| {
+                format!(
+                    "{} row {}: `{}` is not a number",
+                    path.display(),
+                    row + 1,
+                    field
+                )
+            })?),
+        };
+        values.push(value);
+    }
+    Ok(values)
+}
+
+impl Setter {
+    async fn handle(&mut self) -> Result<ControlFlow> {
+        if self.next < self.values.len() {
+            let counter = self
+                .contract
+                .clone()
+                .ok_or(SimulationError::Uninitialized("counter"))?;
+            self.set_next(&counter).await?;
+            Ok(ControlFlow::Continue)
+        } else {
+            info!("Setter done");
+            report::halted(&self.agent, "Setter", "schedule exhausted");
+            Ok(ControlFlow::Halt)
+        }
+    }
+
+    /// Sends the next value of the schedule, which is used up even if sending
+    /// it fails.
+    async fn set_next(&mut self, counter: &ModifiedCounter<ArbiterMiddleware>) -> Result<()> {
+        let value = self.values[self.next];
+        self.next += 1;
+        let receipt = tx::send_retrying(
+            &self.agent,
+            counter.set_number(value),
+            self.on_error.retries(),
+        )
+        .await?;
+        self.pending_sets.insert(receipt.transaction_hash);
+        report::number_set(&self.agent);
+        debug!("Set number to: {}", value);
+        Ok(())
+    }
+}
+
+#[async_trait::async_trait]
+impl Behavior<SetterEvent> for Setter {
+    async fn startup(
+        &mut self,
+        client: Arc<ArbiterMiddleware>,
+        messager: Messager,
+    ) -> Result<Option<EventStream<SetterEvent>>> {
+        debug!("Setter starting up");
+        self.agent = messager.id.clone().unwrap_or_default();
+        let mut rng = rng::stream(&self.agent, "Setter");
+        self.values = self.schedule.values(&mut rng)?;
+        ensure!(!self.values.is_empty(), "setter schedule has no values");
+
+        let stops = messager
+            .clone()
+            .subscribe::<Stop>()?
+            .map(|stop| SetterEvent::Stop(stop.message));
+        let counter =
+            deployer::counter(self.counter.as_deref(), &self.agent, client, messager).await?;
+        let sets = tx::stream_with_meta(counter.number_set_filter())
+            .await?
+            .map(|(event, meta)| SetterEvent::NumberSet {
+                event,
+                tx_hash: meta.transaction_hash,
+            });
+        self.set_next(&counter).await?;
+        self.contract = Some(counter);
+        Ok(Some(Box::pin(stream::select(sets, stops))))
+    }
+
+    async fn process(&mut self, event: SetterEvent) -> Result<ControlFlow> {
+        let (event, tx_hash) = match event {
+            SetterEvent::NumberSet { event, tx_hash } => (event, tx_hash),
+            SetterEvent::Stop(stop) => {
+                info!("Setter stopped: {}", stop.reason);
+                report::halted(&self.agent, "Setter", format!("stopped: {}", stop.reason));
+                return Ok(ControlFlow::Halt);
+            }
+        };
+        if !self.pending_sets.remove(&tx_hash) {
+            debug!(
+                "Setter saw another agent set number to: {}",
+                event.new_number
+            );
+            return Ok(ControlFlow::Continue);
+        }
+        debug!("Setter saw number set to: {}", event.new_number);
+        // Only its own `setNumber` triggers the next one, so a skipped value is
+        // followed by the next one right away.
+        loop {
+            let result = self.handle().await;
+            if let Some(flow) = self.on_error.resolve(result, &self.agent, "Setter") {
+                return flow;
+            }
+        }
+    }
+}
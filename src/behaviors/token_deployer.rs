@@ -0,0 +1,90 @@
+use std::{collections::BTreeMap, sync::Arc};
+
+use anyhow::Result;
+use arbiter_core::middleware::ArbiterMiddleware;
+use arbiter_engine::{
+    machine::{Behavior, ControlFlow, EventStream},
+    messager::Messager,
+};
+use tracing::{debug, info};
+
+use super::*;
+use crate::{
+    accounts, amounts,
+    bindings::token::Token,
+    messages::{ContractDeployed, MessagerExt, Stop},
+    report, tx,
+};
+
+/// Name of the token a `TokenDeployer` deploys when it is not given one.
+pub const TOKEN: &str = "token";
+
+/// Deploys a `Token`, mints the configured initial balances to named agents
+/// and broadcasts its address so that behaviors configured with
+/// `token = "<name>"` can use it.
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct TokenDeployer {
+    /// Name other behaviors refer to the token by, also its on-chain name.
+    #[serde(default = "default_name")]
+    name: String,
+    #[serde(default = "default_symbol")]
+    symbol: String,
+    #[serde(default = "default_decimals")]
+    decimals: u8,
+    /// Whole tokens minted to each agent before the address is broadcast,
+    /// e.g. `{ alice = 1000, bob = 500 }`. Agents must run a token behavior
+    /// to be given a balance.
+    #[serde(default)]
+    balances: BTreeMap<String, u64>,
+}
+
+fn default_name() -> String {
+    TOKEN.to_owned()
+}
+
+fn default_symbol() -> String {
+    "TKN".to_owned()
+}
+
+fn default_decimals() -> u8 {
+    18
+}
+
+#[async_trait::async_trait]
+impl Behavior<Stop> for TokenDeployer {
+    async fn startup(
+        &mut self,
+        client: Arc<ArbiterMiddleware>,
+        messager: Messager,
+    ) -> Result<Option<EventStream<Stop>>> {
+        let agent = messager.id.clone().unwrap_or_default();
+        accounts::announce(&agent, client.address());
+        let args = (self.name.clone(), self.symbol.clone(), self.decimals);
+        let token = tx::deploy(&agent, Token::deploy(client, args)?).await?;
+        for (holder, amount) in &self.balances {
+            let address = accounts::address_of(holder).await?;
+            tx::send(
+                &agent,
+                token.mint(address, amounts::whole(*amount, self.decimals)),
+            )
+            .await?;
+            debug!("Minted {} {} to {}", amount, self.symbol, holder);
+        }
+        let deployed = ContractDeployed {
+            name: self.name.clone(),
+            address: token.address(),
+        };
+        info!(
+            "Deployed token `{}` at {:?}",
+            deployed.name, deployed.address
+        );
+        messager.broadcast(deployed).await?;
+        report::halted(&agent, "TokenDeployer", "deployed");
+        Ok(None)
+    }
+
+    async fn process(&mut self, _event: Stop) -> Result<ControlFlow> {
+        Ok(ControlFlow::Halt)
+    }
+}
@@ -0,0 +1,192 @@
+use std::sync::Arc;
+
+use anyhow::{ensure, Result};
+use arbiter_core::{events::stream_event, middleware::ArbiterMiddleware};
+use arbiter_engine::{
+    machine::{Behavior, ControlFlow, EventStream},
+    messager::Messager,
+};
+use ethers::types::Address;
+use futures::{future, stream, StreamExt};
+use rand::Rng;
+use tracing::{debug, info};
+
+use super::*;
+use crate::{
+    accounts, amounts,
+    behaviors::{deployer, token_deployer},
+    bindings::token::{Token, TransferFilter},
+    errors::{ErrorPolicy, SimulationError},
+    messages::{MessagerExt, Stop},
+    report,
+    rng::{self, SimRng},
+    tx,
+};
+
+/// Sends tokens to other agents, one transfer per `Transfer` event of its
+/// own, and halts after `max_transfers` or on a `Stop` message.
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct Transferrer {
+    /// Name of the token to send, as given to its `TokenDeployer`.
+    #[serde(default = "default_token")]
+    token: String,
+    /// Agents to send to.
+    to: Vec<String>,
+    /// Whole tokens per transfer.
+    amount: Amount,
+    /// Which agent of `to` each transfer goes to.
+    #[serde(default)]
+    pattern: Pattern,
+    max_transfers: u64,
+    #[serde(default)]
+    on_error: ErrorPolicy,
+    #[serde(skip)]
+    contract: Option<Token<ArbiterMiddleware>>,
+    #[serde(skip)]
+    agent: String,
+    #[serde(skip)]
+    address: Address,
+    #[serde(skip)]
+    recipients: Vec<Address>,
+    #[serde(skip)]
+    decimals: u8,
+    #[serde(skip)]
+    rng: Option<SimRng>,
+    #[serde(skip)]
+    transfers: u64,
+}
+
+fn default_token() -> String {
+    token_deployer::TOKEN.to_owned()
+}
+
+/// How the recipient of each transfer is picked.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum Pattern {
+    /// Every agent of `to` in turn.
+    #[default]
+    RoundRobin,
+    /// An agent of `to` drawn uniformly for every transfer.
+    Random,
+}
+
+/// Size of each transfer in whole tokens.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum Amount {
+    /// The same amount every time, e.g. `{ fixed = 10 }`.
+    Fixed(u64),
+    /// Uniform over an inclusive range, e.g. `{ uniform = [1, 10] }`.
+    Uniform(u64, u64),
+}
+
+/// Events a transferrer consumes.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum TransferrerEvent {
+    Transfer(TransferFilter),
+    Stop(Stop),
+}
+
+impl Transferrer {
+    async fn handle(&mut self) -> Result<ControlFlow> {
+        if self.transfers >= self.max_transfers {
+            info!("Transferrer done");
+            report::halted(&self.agent, "Transferrer", "max_transfers reached");
+            return Ok(ControlFlow::Halt);
+        }
+        self.transfer().await?;
+        Ok(ControlFlow::Continue)
+    }
+
+    /// Sends the next transfer, which counts towards `max_transfers` even if
+    /// it fails.
+    async fn transfer(&mut self) -> Result<()> {
+        let token = self
+            .contract
+            .clone()
+            .ok_or(SimulationError::Uninitialized("token"))?;
+        let rng = self
+            .rng
+            .as_mut()
+            .ok_or(SimulationError::Uninitialized("rng"))?;
+        let index = match self.pattern {
+            Pattern::RoundRobin => (self.transfers % self.recipients.len() as u64) as usize,
+            Pattern::Random => rng.gen_range(0..self.recipients.len()),
+        };
+        let amount = match self.amount {
+            Amount::Fixed(amount) => amount,
+            Amount::Uniform(min, max) => rng.gen_range(min..=max),
+        };
+        let value = amounts::whole(amount, self.decimals);
+        self.transfers += 1;
+        tx::send_retrying(
+            &self.agent,
+            token.transfer(self.recipients[index], value),
+            self.on_error.retries(),
+        )
+        .await?;
+        debug!("Transferred {} tokens to {}", amount, self.to[index]);
+        Ok(())
+    }
+}
+
+#[async_trait::async_trait]
+impl Behavior<TransferrerEvent> for Transferrer {
+    async fn startup(
+        &mut self,
+        client: Arc<ArbiterMiddleware>,
+        messager: Messager,
+    ) -> Result<Option<EventStream<TransferrerEvent>>> {
+        debug!("Transferrer starting up");
+        ensure!(!self.to.is_empty(), "`to` must name at least one agent");
+        ensure!(self.max_transfers > 0, "max_transfers must be at least 1");
+        if let Amount::Uniform(min, max) = self.amount {
+            ensure!(min <= max, "amount range [{}, {}] is empty", min, max);
+        }
+
+        self.agent = messager.id.clone().unwrap_or_default();
+        self.address = client.address();
+        accounts::announce(&self.agent, self.address);
+        self.rng = Some(rng::stream(&self.agent, "Transferrer"));
+        let stops = messager
+            .clone()
+            .subscribe::<Stop>()?
+            .map(|stop| TransferrerEvent::Stop(stop.message));
+        let address = deployer::deployed(&self.token, messager).await?;
+        let token = Token::new(address, client);
+        for agent in &self.to {
+            self.recipients.push(accounts::address_of(agent).await?);
+        }
+        self.decimals = token.decimals().call().await?;
+        let sender = self.address;
+        let transfers = stream_event(token.transfer_filter())
+            .filter(move |transfer| future::ready(transfer.from == sender))
+            .map(TransferrerEvent::Transfer);
+        self.contract = Some(token);
+        self.transfer().await?;
+        Ok(Some(Box::pin(stream::select(transfers, stops))))
+    }
+
+    async fn process(&mut self, event: TransferrerEvent) -> Result<ControlFlow> {
+        debug!("Transferrer processing {:?}", event);
+        if let TransferrerEvent::Stop(stop) = event {
+            info!("Transferrer stopped: {}", stop.reason);
+            report::halted(
+                &self.agent,
+                "Transferrer",
+                format!("stopped: {}", stop.reason),
+            );
+            return Ok(ControlFlow::Halt);
+        }
+        // Only its own transfers trigger the next one, so a skipped transfer is
+        // followed by the next one right away.
+        loop {
+            let result = self.handle().await;
+            if let Some(flow) = self.on_error.resolve(result, &self.agent, "Transferrer") {
+                return flow;
+            }
+        }
+    }
+}
@@ -0,0 +1,1723 @@
+pub use constant_product_pool::*;
+/// This module was auto-generated with ethers-rs Abigen.
+/// More information at: <https://github.com/gakonst/ethers-rs>
+#[allow(
+    clippy::enum_variant_names,
+    clippy::too_many_arguments,
+    clippy::upper_case_acronyms,
+    clippy::type_complexity,
+    dead_code,
+    non_camel_case_types
+)]
+pub mod constant_product_pool {
+    #[allow(deprecated)]
+    fn __abi() -> ::ethers::core::abi::Abi {
+        ::ethers::core::abi::ethabi::Contract {
+            constructor: ::core::option::Option::Some(::ethers::core::abi::ethabi::Constructor {
+                inputs: ::std::vec![
+                    ::ethers::core::abi::ethabi::Param {
+                        name: ::std::borrow::ToOwned::to_owned("base_"),
+                        kind: ::ethers::core::abi::ethabi::ParamType::Address,
+                        internal_type: ::core::option::Option::Some(
+                            ::std::borrow::ToOwned::to_owned("address"),
+                        ),
+                    },
+                    ::ethers::core::abi::ethabi::Param {
+                        name: ::std::borrow::ToOwned::to_owned("quote_"),
+                        kind: ::ethers::core::abi::ethabi::ParamType::Address,
+                        internal_type: ::core::option::Option::Some(
+                            ::std::borrow::ToOwned::to_owned("address"),
+                        ),
+                    },
+                    ::ethers::core::abi::ethabi::Param {
+                        name: ::std::borrow::ToOwned::to_owned("feeBps_"),
+                        kind: ::ethers::core::abi::ethabi::ParamType::Uint(256usize),
+                        internal_type: ::core::option::Option::Some(
+                            ::std::borrow::ToOwned::to_owned("uint256"),
+                        ),
+                    },
+                ],
+            }),
+            functions: ::core::convert::From::from([
+                (
+                    ::std::borrow::ToOwned::to_owned("FEE_DENOMINATOR"),
+                    ::std::vec![::ethers::core::abi::ethabi::Function {
+                        name: ::std::borrow::ToOwned::to_owned("FEE_DENOMINATOR"),
+                        inputs: ::std::vec![],
+                        outputs: ::std::vec![::ethers::core::abi::ethabi::Param {
+                            name: ::std::string::String::new(),
+                            kind: ::ethers::core::abi::ethabi::ParamType::Uint(256usize,),
+                            internal_type: ::core::option::Option::Some(
+                                ::std::borrow::ToOwned::to_owned("uint256"),
+                            ),
+                        },],
+                        constant: ::core::option::Option::None,
+                        state_mutability: ::ethers::core::abi::ethabi::StateMutability::View,
+                    },],
+                ),
+                (
+                    ::std::borrow::ToOwned::to_owned("addLiquidity"),
+                    ::std::vec![::ethers::core::abi::ethabi::Function {
+                        name: ::std::borrow::ToOwned::to_owned("addLiquidity"),
+                        inputs: ::std::vec![
+                            ::ethers::core::abi::ethabi::Param {
+                                name: ::std::borrow::ToOwned::to_owned("baseAmount"),
+                                kind: ::ethers::core::abi::ethabi::ParamType::Uint(256usize,),
+                                internal_type: ::core::option::Option::Some(
+                                    ::std::borrow::ToOwned::to_owned("uint256"),
+                                ),
+                            },
+                            ::ethers::core::abi::ethabi::Param {
+                                name: ::std::borrow::ToOwned::to_owned("quoteAmount"),
+                                kind: ::ethers::core::abi::ethabi::ParamType::Uint(256usize,),
+                                internal_type: ::core::option::Option::Some(
+                                    ::std::borrow::ToOwned::to_owned("uint256"),
+                                ),
+                            },
+                        ],
+                        outputs: ::std::vec![::ethers::core::abi::ethabi::Param {
+                            name: ::std::borrow::ToOwned::to_owned("shares"),
+                            kind: ::ethers::core::abi::ethabi::ParamType::Uint(256usize,),
+                            internal_type: ::core::option::Option::Some(
+                                ::std::borrow::ToOwned::to_owned("uint256"),
+                            ),
+                        },],
+                        constant: ::core::option::Option::None,
+                        state_mutability: ::ethers::core::abi::ethabi::StateMutability::NonPayable,
+                    },],
+                ),
+                (
+                    ::std::borrow::ToOwned::to_owned("base"),
+                    ::std::vec![::ethers::core::abi::ethabi::Function {
+                        name: ::std::borrow::ToOwned::to_owned("base"),
+                        inputs: ::std::vec![],
+                        outputs: ::std::vec![::ethers::core::abi::ethabi::Param {
+                            name: ::std::string::String::new(),
+                            kind: ::ethers::core::abi::ethabi::ParamType::Address,
+                            internal_type: ::core::option::Option::Some(
+                                ::std::borrow::ToOwned::to_owned("contract IERC20"),
+                            ),
+                        },],
+                        constant: ::core::option::Option::None,
+                        state_mutability: ::ethers::core::abi::ethabi::StateMutability::View,
+                    },],
+                ),
+                (
+                    ::std::borrow::ToOwned::to_owned("baseReserve"),
+                    ::std::vec![::ethers::core::abi::ethabi::Function {
+                        name: ::std::borrow::ToOwned::to_owned("baseReserve"),
+                        inputs: ::std::vec![],
+                        outputs: ::std::vec![::ethers::core::abi::ethabi::Param {
+                            name: ::std::string::String::new(),
+                            kind: ::ethers::core::abi::ethabi::ParamType::Uint(256usize,),
+                            internal_type: ::core::option::Option::Some(
+                                ::std::borrow::ToOwned::to_owned("uint256"),
+                            ),
+                        },],
+                        constant: ::core::option::Option::None,
+                        state_mutability: ::ethers::core::abi::ethabi::StateMutability::View,
+                    },],
+                ),
+                (
+                    ::std::borrow::ToOwned::to_owned("feeBps"),
+                    ::std::vec![::ethers::core::abi::ethabi::Function {
+                        name: ::std::borrow::ToOwned::to_owned("feeBps"),
+                        inputs: ::std::vec![],
+                        outputs: ::std::vec![::ethers::core::abi::ethabi::Param {
+                            name: ::std::string::String::new(),
+                            kind: ::ethers::core::abi::ethabi::ParamType::Uint(256usize,),
+                            internal_type: ::core::option::Option::Some(
+                                ::std::borrow::ToOwned::to_owned("uint256"),
+                            ),
+                        },],
+                        constant: ::core::option::Option::None,
+                        state_mutability: ::ethers::core::abi::ethabi::StateMutability::View,
+                    },],
+                ),
+                (
+                    ::std::borrow::ToOwned::to_owned("getAmountOut"),
+                    ::std::vec![::ethers::core::abi::ethabi::Function {
+                        name: ::std::borrow::ToOwned::to_owned("getAmountOut"),
+                        inputs: ::std::vec![
+                            ::ethers::core::abi::ethabi::Param {
+                                name: ::std::borrow::ToOwned::to_owned("sellBase"),
+                                kind: ::ethers::core::abi::ethabi::ParamType::Bool,
+                                internal_type: ::core::option::Option::Some(
+                                    ::std::borrow::ToOwned::to_owned("bool"),
+                                ),
+                            },
+                            ::ethers::core::abi::ethabi::Param {
+                                name: ::std::borrow::ToOwned::to_owned("amountIn"),
+                                kind: ::ethers::core::abi::ethabi::ParamType::Uint(256usize,),
+                                internal_type: ::core::option::Option::Some(
+                                    ::std::borrow::ToOwned::to_owned("uint256"),
+                                ),
+                            },
+                        ],
+                        outputs: ::std::vec![::ethers::core::abi::ethabi::Param {
+                            name: ::std::string::String::new(),
+                            kind: ::ethers::core::abi::ethabi::ParamType::Uint(256usize,),
+                            internal_type: ::core::option::Option::Some(
+                                ::std::borrow::ToOwned::to_owned("uint256"),
+                            ),
+                        },],
+                        constant: ::core::option::Option::None,
+                        state_mutability: ::ethers::core::abi::ethabi::StateMutability::View,
+                    },],
+                ),
+                (
+                    ::std::borrow::ToOwned::to_owned("getReserves"),
+                    ::std::vec![::ethers::core::abi::ethabi::Function {
+                        name: ::std::borrow::ToOwned::to_owned("getReserves"),
+                        inputs: ::std::vec![],
+                        outputs: ::std::vec![
+                            ::ethers::core::abi::ethabi::Param {
+                                name: ::std::string::String::new(),
+                                kind: ::ethers::core::abi::ethabi::ParamType::Uint(256usize,),
+                                internal_type: ::core::option::Option::Some(
+                                    ::std::borrow::ToOwned::to_owned("uint256"),
+                                ),
+                            },
+                            ::ethers::core::abi::ethabi::Param {
+                                name: ::std::string::String::new(),
+                                kind: ::ethers::core::abi::ethabi::ParamType::Uint(256usize,),
+                                internal_type: ::core::option::Option::Some(
+                                    ::std::borrow::ToOwned::to_owned("uint256"),
+                                ),
+                            },
+                        ],
+                        constant: ::core::option::Option::None,
+                        state_mutability: ::ethers::core::abi::ethabi::StateMutability::View,
+                    },],
+                ),
+                (
+                    ::std::borrow::ToOwned::to_owned("quote"),
+                    ::std::vec![::ethers::core::abi::ethabi::Function {
+                        name: ::std::borrow::ToOwned::to_owned("quote"),
+                        inputs: ::std::vec![],
+                        outputs: ::std::vec![::ethers::core::abi::ethabi::Param {
+                            name: ::std::string::String::new(),
+                            kind: ::ethers::core::abi::ethabi::ParamType::Address,
+                            internal_type: ::core::option::Option::Some(
+                                ::std::borrow::ToOwned::to_owned("contract IERC20"),
+                            ),
+                        },],
+                        constant: ::core::option::Option::None,
+                        state_mutability: ::ethers::core::abi::ethabi::StateMutability::View,
+                    },],
+                ),
+                (
+                    ::std::borrow::ToOwned::to_owned("quoteReserve"),
+                    ::std::vec![::ethers::core::abi::ethabi::Function {
+                        name: ::std::borrow::ToOwned::to_owned("quoteReserve"),
+                        inputs: ::std::vec![],
+                        outputs: ::std::vec![::ethers::core::abi::ethabi::Param {
+                            name: ::std::string::String::new(),
+                            kind: ::ethers::core::abi::ethabi::ParamType::Uint(256usize,),
+                            internal_type: ::core::option::Option::Some(
+                                ::std::borrow::ToOwned::to_owned("uint256"),
+                            ),
+                        },],
+                        constant: ::core::option::Option::None,
+                        state_mutability: ::ethers::core::abi::ethabi::StateMutability::View,
+                    },],
+                ),
+                (
+                    ::std::borrow::ToOwned::to_owned("removeLiquidity"),
+                    ::std::vec![::ethers::core::abi::ethabi::Function {
+                        name: ::std::borrow::ToOwned::to_owned("removeLiquidity"),
+                        inputs: ::std::vec![::ethers::core::abi::ethabi::Param {
+                            name: ::std::borrow::ToOwned::to_owned("shares"),
+                            kind: ::ethers::core::abi::ethabi::ParamType::Uint(256usize,),
+                            internal_type: ::core::option::Option::Some(
+                                ::std::borrow::ToOwned::to_owned("uint256"),
+                            ),
+                        },],
+                        outputs: ::std::vec![
+                            ::ethers::core::abi::ethabi::Param {
+                                name: ::std::borrow::ToOwned::to_owned("baseAmount"),
+                                kind: ::ethers::core::abi::ethabi::ParamType::Uint(256usize,),
+                                internal_type: ::core::option::Option::Some(
+                                    ::std::borrow::ToOwned::to_owned("uint256"),
+                                ),
+                            },
+                            ::ethers::core::abi::ethabi::Param {
+                                name: ::std::borrow::ToOwned::to_owned("quoteAmount"),
+                                kind: ::ethers::core::abi::ethabi::ParamType::Uint(256usize,),
+                                internal_type: ::core::option::Option::Some(
+                                    ::std::borrow::ToOwned::to_owned("uint256"),
+                                ),
+                            },
+                        ],
+                        constant: ::core::option::Option::None,
+                        state_mutability: ::ethers::core::abi::ethabi::StateMutability::NonPayable,
+                    },],
+                ),
+                (
+                    ::std::borrow::ToOwned::to_owned("sharesOf"),
+                    ::std::vec![::ethers::core::abi::ethabi::Function {
+                        name: ::std::borrow::ToOwned::to_owned("sharesOf"),
+                        inputs: ::std::vec![::ethers::core::abi::ethabi::Param {
+                            name: ::std::string::String::new(),
+                            kind: ::ethers::core::abi::ethabi::ParamType::Address,
+                            internal_type: ::core::option::Option::Some(
+                                ::std::borrow::ToOwned::to_owned("address"),
+                            ),
+                        },],
+                        outputs: ::std::vec![::ethers::core::abi::ethabi::Param {
+                            name: ::std::string::String::new(),
+                            kind: ::ethers::core::abi::ethabi::ParamType::Uint(256usize,),
+                            internal_type: ::core::option::Option::Some(
+                                ::std::borrow::ToOwned::to_owned("uint256"),
+                            ),
+                        },],
+                        constant: ::core::option::Option::None,
+                        state_mutability: ::ethers::core::abi::ethabi::StateMutability::View,
+                    },],
+                ),
+                (
+                    ::std::borrow::ToOwned::to_owned("swap"),
+                    ::std::vec![::ethers::core::abi::ethabi::Function {
+                        name: ::std::borrow::ToOwned::to_owned("swap"),
+                        inputs: ::std::vec![
+                            ::ethers::core::abi::ethabi::Param {
+                                name: ::std::borrow::ToOwned::to_owned("sellBase"),
+                                kind: ::ethers::core::abi::ethabi::ParamType::Bool,
+                                internal_type: ::core::option::Option::Some(
+                                    ::std::borrow::ToOwned::to_owned("bool"),
+                                ),
+                            },
+                            ::ethers::core::abi::ethabi::Param {
+                                name: ::std::borrow::ToOwned::to_owned("amountIn"),
+                                kind: ::ethers::core::abi::ethabi::ParamType::Uint(256usize,),
+                                internal_type: ::core::option::Option::Some(
+                                    ::std::borrow::ToOwned::to_owned("uint256"),
+                                ),
+                            },
+                            ::ethers::core::abi::ethabi::Param {
+                                name: ::std::borrow::ToOwned::to_owned("minAmountOut"),
+                                kind: ::ethers::core::abi::ethabi::ParamType::Uint(256usize,),
+                                internal_type: ::core::option::Option::Some(
+                                    ::std::borrow::ToOwned::to_owned("uint256"),
+                                ),
+                            },
+                        ],
+                        outputs: ::std::vec![::ethers::core::abi::ethabi::Param {
+                            name: ::std::borrow::ToOwned::to_owned("amountOut"),
+                            kind: ::ethers::core::abi::ethabi::ParamType::Uint(256usize,),
+                            internal_type: ::core::option::Option::Some(
+                                ::std::borrow::ToOwned::to_owned("uint256"),
+                            ),
+                        },],
+                        constant: ::core::option::Option::None,
+                        state_mutability: ::ethers::core::abi::ethabi::StateMutability::NonPayable,
+                    },],
+                ),
+                (
+                    ::std::borrow::ToOwned::to_owned("totalShares"),
+                    ::std::vec![::ethers::core::abi::ethabi::Function {
+                        name: ::std::borrow::ToOwned::to_owned("totalShares"),
+                        inputs: ::std::vec![],
+                        outputs: ::std::vec![::ethers::core::abi::ethabi::Param {
+                            name: ::std::string::String::new(),
+                            kind: ::ethers::core::abi::ethabi::ParamType::Uint(256usize,),
+                            internal_type: ::core::option::Option::Some(
+                                ::std::borrow::ToOwned::to_owned("uint256"),
+                            ),
+                        },],
+                        constant: ::core::option::Option::None,
+                        state_mutability: ::ethers::core::abi::ethabi::StateMutability::View,
+                    },],
+                ),
+            ]),
+            events: ::core::convert::From::from([
+                (
+                    ::std::borrow::ToOwned::to_owned("LiquidityAdded"),
+                    ::std::vec![::ethers::core::abi::ethabi::Event {
+                        name: ::std::borrow::ToOwned::to_owned("LiquidityAdded"),
+                        inputs: ::std::vec![
+                            ::ethers::core::abi::ethabi::EventParam {
+                                name: ::std::borrow::ToOwned::to_owned("provider"),
+                                kind: ::ethers::core::abi::ethabi::ParamType::Address,
+                                indexed: true,
+                            },
+                            ::ethers::core::abi::ethabi::EventParam {
+                                name: ::std::borrow::ToOwned::to_owned("baseAmount"),
+                                kind: ::ethers::core::abi::ethabi::ParamType::Uint(256usize,),
+                                indexed: false,
+                            },
+                            ::ethers::core::abi::ethabi::EventParam {
+                                name: ::std::borrow::ToOwned::to_owned("quoteAmount"),
+                                kind: ::ethers::core::abi::ethabi::ParamType::Uint(256usize,),
+                                indexed: false,
+                            },
+                            ::ethers::core::abi::ethabi::EventParam {
+                                name: ::std::borrow::ToOwned::to_owned("shares"),
+                                kind: ::ethers::core::abi::ethabi::ParamType::Uint(256usize,),
+                                indexed: false,
+                            },
+                        ],
+                        anonymous: false,
+                    },],
+                ),
+                (
+                    ::std::borrow::ToOwned::to_owned("LiquidityRemoved"),
+                    ::std::vec![::ethers::core::abi::ethabi::Event {
+                        name: ::std::borrow::ToOwned::to_owned("LiquidityRemoved"),
+                        inputs: ::std::vec![
+                            ::ethers::core::abi::ethabi::EventParam {
+                                name: ::std::borrow::ToOwned::to_owned("provider"),
+                                kind: ::ethers::core::abi::ethabi::ParamType::Address,
+                                indexed: true,
+                            },
+                            ::ethers::core::abi::ethabi::EventParam {
+                                name: ::std::borrow::ToOwned::to_owned("baseAmount"),
+                                kind: ::ethers::core::abi::ethabi::ParamType::Uint(256usize,),
+                                indexed: false,
+                            },
+                            ::ethers::core::abi::ethabi::EventParam {
+                                name: ::std::borrow::ToOwned::to_owned("quoteAmount"),
+                                kind: ::ethers::core::abi::ethabi::ParamType::Uint(256usize,),
+                                indexed: false,
+                            },
+                            ::ethers::core::abi::ethabi::EventParam {
+                                name: ::std::borrow::ToOwned::to_owned("shares"),
+                                kind: ::ethers::core::abi::ethabi::ParamType::Uint(256usize,),
+                                indexed: false,
+                            },
+                        ],
+                        anonymous: false,
+                    },],
+                ),
+                (
+                    ::std::borrow::ToOwned::to_owned("Swap"),
+                    ::std::vec![::ethers::core::abi::ethabi::Event {
+                        name: ::std::borrow::ToOwned::to_owned("Swap"),
+                        inputs: ::std::vec![
+                            ::ethers::core::abi::ethabi::EventParam {
+                                name: ::std::borrow::ToOwned::to_owned("trader"),
+                                kind: ::ethers::core::abi::ethabi::ParamType::Address,
+                                indexed: true,
+                            },
+                            ::ethers::core::abi::ethabi::EventParam {
+                                name: ::std::borrow::ToOwned::to_owned("sellBase"),
+                                kind: ::ethers::core::abi::ethabi::ParamType::Bool,
+                                indexed: false,
+                            },
+                            ::ethers::core::abi::ethabi::EventParam {
+                                name: ::std::borrow::ToOwned::to_owned("amountIn"),
+                                kind: ::ethers::core::abi::ethabi::ParamType::Uint(256usize,),
+                                indexed: false,
+                            },
+                            ::ethers::core::abi::ethabi::EventParam {
+                                name: ::std::borrow::ToOwned::to_owned("amountOut"),
+                                kind: ::ethers::core::abi::ethabi::ParamType::Uint(256usize,),
+                                indexed: false,
+                            },
+                        ],
+                        anonymous: false,
+                    },],
+                ),
+                (
+                    ::std::borrow::ToOwned::to_owned("Sync"),
+                    ::std::vec![::ethers::core::abi::ethabi::Event {
+                        name: ::std::borrow::ToOwned::to_owned("Sync"),
+                        inputs: ::std::vec![
+                            ::ethers::core::abi::ethabi::EventParam {
+                                name: ::std::borrow::ToOwned::to_owned("baseReserve"),
+                                kind: ::ethers::core::abi::ethabi::ParamType::Uint(256usize,),
+                                indexed: false,
+                            },
+                            ::ethers::core::abi::ethabi::EventParam {
+                                name: ::std::borrow::ToOwned::to_owned("quoteReserve"),
+                                kind: ::ethers::core::abi::ethabi::ParamType::Uint(256usize,),
+                                indexed: false,
+                            },
+                        ],
+                        anonymous: false,
+                    },],
+                ),
+            ]),
+            errors: ::core::convert::From::from([
+                (
+                    ::std::borrow::ToOwned::to_owned("InsufficientOutput"),
+                    ::std::vec![::ethers::core::abi::ethabi::AbiError {
+                        name: ::std::borrow::ToOwned::to_owned("InsufficientOutput"),
+                        inputs: ::std::vec![
+                            ::ethers::core::abi::ethabi::Param {
+                                name: ::std::borrow::ToOwned::to_owned("amountOut"),
+                                kind: ::ethers::core::abi::ethabi::ParamType::Uint(256usize,),
+                                internal_type: ::core::option::Option::Some(
+                                    ::std::borrow::ToOwned::to_owned("uint256"),
+                                ),
+                            },
+                            ::ethers::core::abi::ethabi::Param {
+                                name: ::std::borrow::ToOwned::to_owned("minAmountOut"),
+                                kind: ::ethers::core::abi::ethabi::ParamType::Uint(256usize,),
+                                internal_type: ::core::option::Option::Some(
+                                    ::std::borrow::ToOwned::to_owned("uint256"),
+                                ),
+                            },
+                        ],
+                    },],
+                ),
+                (
+                    ::std::borrow::ToOwned::to_owned("InsufficientShares"),
+                    ::std::vec![::ethers::core::abi::ethabi::AbiError {
+                        name: ::std::borrow::ToOwned::to_owned("InsufficientShares"),
+                        inputs: ::std::vec![
+                            ::ethers::core::abi::ethabi::Param {
+                                name: ::std::borrow::ToOwned::to_owned("shares"),
+                                kind: ::ethers::core::abi::ethabi::ParamType::Uint(256usize,),
+                                internal_type: ::core::option::Option::Some(
+                                    ::std::borrow::ToOwned::to_owned("uint256"),
+                                ),
+                            },
+                            ::ethers::core::abi::ethabi::Param {
+                                name: ::std::borrow::ToOwned::to_owned("needed"),
+                                kind: ::ethers::core::abi::ethabi::ParamType::Uint(256usize,),
+                                internal_type: ::core::option::Option::Some(
+                                    ::std::borrow::ToOwned::to_owned("uint256"),
+                                ),
+                            },
+                        ],
+                    },],
+                ),
+                (
+                    ::std::borrow::ToOwned::to_owned("InvalidFee"),
+                    ::std::vec![::ethers::core::abi::ethabi::AbiError {
+                        name: ::std::borrow::ToOwned::to_owned("InvalidFee"),
+                        inputs: ::std::vec![::ethers::core::abi::ethabi::Param {
+                            name: ::std::borrow::ToOwned::to_owned("feeBps"),
+                            kind: ::ethers::core::abi::ethabi::ParamType::Uint(256usize,),
+                            internal_type: ::core::option::Option::Some(
+                                ::std::borrow::ToOwned::to_owned("uint256"),
+                            ),
+                        },],
+                    },],
+                ),
+                (
+                    ::std::borrow::ToOwned::to_owned("ZeroAmount"),
+                    ::std::vec![::ethers::core::abi::ethabi::AbiError {
+                        name: ::std::borrow::ToOwned::to_owned("ZeroAmount"),
+                        inputs: ::std::vec![],
+                    },],
+                ),
+            ]),
+            receive: false,
+            fallback: false,
+        }
+    }
+    /// The parsed JSON ABI of the contract.
+    pub static CONSTANTPRODUCTPOOL_ABI: ::ethers::contract::Lazy<::ethers::core::abi::Abi> =
+        ::ethers::contract::Lazy::new(__abi);
+    #[rustfmt::skip]
+    const __BYTECODE: &[u8] = b"`\x80`@R4a\x01\xC3Wa\x12\x1C8\x03\x80a\x02\xC0Ra\x12\x1Ca\x04\09a\x02\xC0Q``\x90\x10a\x01GWa\x04\0Q\x80`\xA0\x1Ca\x01BW`\x80Ra\x04 Q\x80`\xA0\x1Ca\x01BW`\xA0Ra\x04@Q`\xC0Ra'\x10`\xC0Q\x10a\0\x86W\x7F/8\xC6\xEE\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0`\0R`\xC0Q`\x04R`$`\0\xFD[a\x04\0a\x02\xC0Q\x01a\x02\xE0Ra\x0F\xDDa\x02?a\x02\xE0Q9`\x80Qa\x02\xE0Qa\x016\x01R`\x80Qa\x02\xE0Qa\x05\xB2\x01R`\x80Qa\x02\xE0Qa\x08\xE6\x01R`\x80Qa\x02\xE0Qa\x0B\xED\x01R`\x80Qa\x02\xE0Qa\r\x92\x01R`\xC0Qa\x02\xE0Qa\x01\x92\x01R`\xC0Qa\x02\xE0Qa\x02\x87\x01R`\xC0Qa\x02\xE0Qa\n\xAF\x01R`\xA0Qa\x02\xE0Qa\x01d\x01R`\xA0Qa\x02\xE0Qa\x063\x01R`\xA0Qa\x02\xE0Qa\tb\x01R`\xA0Qa\x02\xE0Qa\x0Ci\x01R`\xA0Qa\x02\xE0Qa\r\x16\x01Ra\x0F\xDDa\x02\xE0Q\xF3[`\0\x80\xFD[\x7F\x08\xC3y\xA0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0`\0R` `\x04R`\"`$R\x7FABI decoding: tuple data too sho`DR\x7Frt\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0`dR`\x84`\0\xFD[\x7F\x08\xC3y\xA0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0`\0R` `\x04R`\"`$R\x7FEther sent to non-payable functi`DR\x7Fon\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0`dR`\x84`\0\xFD`\x80`@R4a\x0FaW`\x046\x10a\0\xA7W`\x005`\xE0\x1C\x80c\x08\xC8L!\x14a\n'W\x80c\t\x02\xF1\xAC\x14a\x02%W\x80c$\xA9\xD8S\x14a\x01\x8FW\x80c:\x98\xEF9\x14a\x01\xDDW\x80cP\x01\xF3\xB5\x14a\x013W\x80c\x82\x90\xD9\xB8\x14a\x02<W\x80c\x99\x9B\x93\xAF\x14a\x01aW\x80c\x9C\x8F\x9F#\x14a\x07aW\x80c\x9C\xD4A\xDA\x14a\x03>W\x80c\x9D\xA7q\xF4\x14a\x01\xCDW\x80c\xD77\x92\xA9\x14a\x01#W\x80c\xDF\xDF*r\x14a\x01\xBDW\x80c\xF5\xEBB\xDC\x14a\x01\xEDW[\x7F\x08\xC3y\xA0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0`\0R` `\x04R`5`$R\x7FContract does not have fallback `DR\x7Fnor receive functions\0\0\0\0\0\0\0\0\0\0\0`dR`\x84`\0\xFD[Pa'\x10a\x03\0R` a\x03\0\xF3\0[P\x7F\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0a\x03\0R` a\x03\0\xF3\0[P\x7F\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0a\x03\0R` a\x03\0\xF3\0[P\x7F\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0a\x03\0R` a\x03\0\xF3\0[P`\0Ta\x03\0R` a\x03\0\xF3\0[P`\x01Ta\x03\0R` a\x03\0\xF3\0[P`\x02Ta\x03\0R` a\x03\0\xF3\0[P`\x046\x03` \x90\x10a\x0E\xE5W`\x045\x80`\xA0\x1Ca\x0E\xE0W`\x80R`\x80Q`\x03` R`\0R`@`\0 Ta\x03\0R` a\x03\0\xF3\0[P`\0Ta\x03\0R`\x01Ta\x03 R`@a\x03\0\xF3\0[P`\x046\x03`@\x90\x10a\x0E\xE5W`\x045\x80`\x01\x10a\x0E\xE0W`\x80R`$5`\xA0R`\x80Q\x15a\x02wW`\0T`\xE0R`\x01Ta\x01\0Ra\x02\x85V[`\x01T`\xE0R`\0Ta\x01\0R[\x7F\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0a'\x10\x81\x81\x10a\x0E\xB1W\x03`\xA0Q\x81\x81\x02\x81\x15a\x02\xCBW\x81\x81\x04\x83\x14a\x02\xCBWa\x0E\xB1V[\x91PPa\x01 Ra\x01 Qa'\x10`\xE0Q\x81\x81\x02\x81\x15a\x02\xF3W\x81\x81\x04\x83\x14a\x02\xF3Wa\x0E\xB1V[\x91PP\x81\x81\x01\x81\x81\x10a\x0E\xB1W\x91PPa\x01\0Qa\x01 Q\x81\x81\x02\x81\x15a\x03\"W\x81\x81\x04\x83\x14a\x03\"Wa\x0E\xB1V[\x91PP\x81\x15a\x0E\x82W\x04`\xC0R`\xC0Qa\x03\0R` a\x03\0\xF3\0[P`\x046\x03`@\x90\x10a\x0E\xE5W`\x045`\x80R`$5`\xA0R`\0`\xA0Q\x14`\0`\x80Q\x14\x17\x15a\x03\x93W\x7F\x1F* \x05\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0`\0R`\x04`\0\xFD[`\0`\x02T\x14\x15a\x04\xC6W`\0`\xC0R`\x03`\xA0Q`\x80Q\x81\x81\x02\x81\x15a\x03\xC2W\x81\x81\x04\x83\x14a\x03\xC2Wa\x0E\xB1V[\x91PP\x11\x15a\x04\x8CW`\xA0Q`\x80Q\x81\x81\x02\x81\x15a\x03\xE8W\x81\x81\x04\x83\x14a\x03\xE8Wa\x0E\xB1V[\x91PP`\xC0R`\x01`\x02`\xA0Q`\x80Q\x81\x81\x02\x81\x15a\x04\x0FW\x81\x81\x04\x83\x14a\x04\x0FWa\x0E\xB1V[\x91PP\x81\x15a\x0E\x82W\x04\x81\x81\x01\x81\x81\x10a\x0E\xB1W\x91PP`\xE0R[`\xC0Q`\xE0Q\x10\x15a\x04\x87W`\xE0Q`\xC0R`\x02`\xE0Q`\xE0Q`\xA0Q`\x80Q\x81\x81\x02\x81\x15a\x04aW\x81\x81\x04\x83\x14a\x04aWa\x0E\xB1V[\x91PP\x81\x15a\x0E\x82W\x04\x81\x81\x01\x81\x81\x10a\x0E\xB1W\x91PP\x81\x15a\x0E\x82W\x04`\xE0Ra\x04*V[a\x04\xC1V[`\0`\xA0Q`\x80Q\x81\x81\x02\x81\x15a\x04\xABW\x81\x81\x04\x83\x14a\x04\xABWa\x0E\xB1V[\x91PP\x14\x15\x15a\x04\xBFW`\x01`\xC0Ra\x04\xC0V[[[a\x059V[`\0T`\x02T`\x80Q\x81\x81\x02\x81\x15a\x04\xE6W\x81\x81\x04\x83\x14a\x04\xE6Wa\x0E\xB1V[\x91PP\x81\x15a\x0E\x82W\x04`\xC0R`\x01T`\x02T`\xA0Q\x81\x81\x02\x81\x15a\x05\x13W\x81\x81\x04\x83\x14a\x05\x13Wa\x0E\xB1V[\x91PP\x81\x15a\x0E\x82W\x04`\xE0R`\xC0Q`\xE0Q\x10\x15a\x057W`\xE0Q`\xC0Ra\x058V[[[`\0`\xC0Q\x14\x15a\x05nW\x7F\x1F* \x05\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0`\0R`\x04`\0\xFD[\x7F#\xB8r\xDD\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0a\x03\0R3a\x03\x04R0a\x03$R`\x80Qa\x03DR` a\x03\0`da\x03\0`\0\x7F\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0Z\xF1a\x05\xDCWa\x0ExV[` =\x10a\x0E\xE5Wa\x03\0Q`\x01\x10a\x0E\xE0W\x7F#\xB8r\xDD\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0a\x03\0R3a\x03\x04R0a\x03$R`\xA0Qa\x03DR` a\x03\0`da\x03\0`\0\x7F\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0Z\xF1a\x06]Wa\x0ExV[` =\x10a\x0E\xE5Wa\x03\0Q`\x01\x10a\x0E\xE0W`\x80Q`\0T\x81\x81\x01\x81\x81\x10a\x0E\xB1W\x91PP`\0U`\xA0Q`\x01T\x81\x81\x01\x81\x81\x10a\x0E\xB1W\x91PP`\x01U`\xC0Q`\x02T\x81\x81\x01\x81\x81\x10a\x0E\xB1W\x91PP`\x02U`\xC0Q3`\x03` R`\0R`@`\0 T\x81\x81\x01\x81\x81\x10a\x0E\xB1W\x91PP3`\x03` R`\0R`@`\0 U`\x80Qa\x03\0R`\xA0Qa\x03 R`\xC0Qa\x03@R3\x7Fd\xB89D\xE7\x9C<\xE8\xD4\xC2\x97A\x1D\xE67\xC3\xE1\x02\xD0dgz\xAC\x0C\x169v\xEB\xDC\xD6\xF5\x0E``a\x03\0\xA2`\0Ta\x03\0R`\x01Ta\x03 R\x7F\xCF*\xA5\x08v\xCD\xFB\xB5A o\x89\xAF\x0E\xE7\x8DD\xA2\xAB\xF8\xD3(\xE3\x7F\xA4\x91\x7F\x98!I\x84\x8A`@a\x03\0\xA1`\xC0Qa\x03\0R` a\x03\0\xF3\0[P`\x046\x03` \x90\x10a\x0E\xE5W`\x045`\x80R`\0`\x80Q\x14\x15a\x07\xA9W\x7F\x1F* \x05\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0`\0R`\x04`\0\xFD[3`\x03` R`\0R`@`\0 T`\xA0R`\x80Q`\xA0Q\x10\x15a\x07\xFDW\x7F\xCB\x1D\x8B\xBA\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0`\0R`\xA0Q`\x04R`\x80Q`$R`D`\0\xFD[`\x02T`\0T`\x80Q\x81\x81\x02\x81\x15a\x08\x1DW\x81\x81\x04\x83\x14a\x08\x1DWa\x0E\xB1V[\x91PP\x81\x15a\x0E\x82W\x04`\xC0R`\x02T`\x01T`\x80Q\x81\x81\x02\x81\x15a\x08JW\x81\x81\x04\x83\x14a\x08JWa\x0E\xB1V[\x91PP\x81\x15a\x0E\x82W\x04`\xE0R`\x80Q`\xA0Q\x81\x81\x10a\x0E\xB1W\x033`\x03` R`\0R`@`\0 U`\x80Q`\x02T\x81\x81\x10a\x0E\xB1W\x03`\x02U`\xC0Q`\0T\x81\x81\x10a\x0E\xB1W\x03`\0U`\xE0Q`\x01T\x81\x81\x10a\x0E\xB1W\x03`\x01U\x7F\xA9\x05\x9C\xBB\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0a\x03\0R3a\x03\x04R`\xC0Qa\x03$R` a\x03\0`Da\x03\0`\0\x7F\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0Z\xF1a\t\x10Wa\x0ExV[` =\x10a\x0E\xE5Wa\x03\0Q`\x01\x10a\x0E\xE0W\x7F\xA9\x05\x9C\xBB\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0a\x03\0R3a\x03\x04R`\xE0Qa\x03$R` a\x03\0`Da\x03\0`\0\x7F\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0Z\xF1a\t\x8CWa\x0ExV[` =\x10a\x0E\xE5Wa\x03\0Q`\x01\x10a\x0E\xE0W`\xC0Qa\x03\0R`\xE0Qa\x03 R`\x80Qa\x03@R3\x7F\x1D\xC8\xBBi\xDF+\x8E\x91\xFB\xDC\xBF\xCF\x93\xD9Q\xB3\xF0\0\x0F\x08Z\x95\xFE?yF\xD6\x16\x149$]``a\x03\0\xA2`\0Ta\x03\0R`\x01Ta\x03 R\x7F\xCF*\xA5\x08v\xCD\xFB\xB5A o\x89\xAF\x0E\xE7\x8DD\xA2\xAB\xF8\xD3(\xE3\x7F\xA4\x91\x7F\x98!I\x84\x8A`@a\x03\0\xA1`\xC0Qa\x03\0R`\xE0Qa\x03 R`@a\x03\0\xF3\0[P`\x046\x03``\x90\x10a\x0E\xE5W`\x045\x80`\x01\x10a\x0E\xE0W`\x80R`$5`\xA0R`D5`\xC0R`\0`\xA0Q\x14\x15a\n\x83W\x7F\x1F* \x05\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0`\0R`\x04`\0\xFD[`\x80Q\x15a\n\x9EW`\0Ta\x01\0R`\x01Ta\x01 Ra\n\xADV[`\x01Ta\x01\0R`\0Ta\x01 R[\x7F\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0a'\x10\x81\x81\x10a\x0E\xB1W\x03`\xA0Q\x81\x81\x02\x81\x15a\n\xF3W\x81\x81\x04\x83\x14a\n\xF3Wa\x0E\xB1V[\x91PPa\x01@Ra\x01@Qa'\x10a\x01\0Q\x81\x81\x02\x81\x15a\x0B\x1CW\x81\x81\x04\x83\x14a\x0B\x1CWa\x0E\xB1V[\x91PP\x81\x81\x01\x81\x81\x10a\x0E\xB1W\x91PPa\x01 Qa\x01@Q\x81\x81\x02\x81\x15a\x0BKW\x81\x81\x04\x83\x14a\x0BKWa\x0E\xB1V[\x91PP\x81\x15a\x0E\x82W\x04`\xE0R`\xC0Q`\xE0Q\x10`\0`\xE0Q\x14\x17\x15a\x0B\xA1W\x7F,\x19\xB8\xB8\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0`\0R`\xE0Q`\x04R`\xC0Q`$R`D`\0\xFD[`\x80Q\x15a\x0C\xD2W\x7F#\xB8r\xDD\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0a\x03\0R3a\x03\x04R0a\x03$R`\xA0Qa\x03DR` a\x03\0`da\x03\0`\0\x7F\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0Z\xF1a\x0C\x17Wa\x0ExV[` =\x10a\x0E\xE5Wa\x03\0Q`\x01\x10a\x0E\xE0W\x7F\xA9\x05\x9C\xBB\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0a\x03\0R3a\x03\x04R`\xE0Qa\x03$R` a\x03\0`Da\x03\0`\0\x7F\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0Z\xF1a\x0C\x93Wa\x0ExV[` =\x10a\x0E\xE5Wa\x03\0Q`\x01\x10a\x0E\xE0W`\xA0Q`\0T\x81\x81\x01\x81\x81\x10a\x0E\xB1W\x91PP`\0U`\xE0Q`\x01T\x81\x81\x10a\x0E\xB1W\x03`\x01Ua\r\xF7V[\x7F#\xB8r\xDD\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0a\x03\0R3a\x03\x04R0a\x03$R`\xA0Qa\x03DR` a\x03\0`da\x03\0`\0\x7F\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0Z\xF1a\r@Wa\x0ExV[` =\x10a\x0E\xE5Wa\x03\0Q`\x01\x10a\x0E\xE0W\x7F\xA9\x05\x9C\xBB\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0a\x03\0R3a\x03\x04R`\xE0Qa\x03$R` a\x03\0`Da\x03\0`\0\x7F\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0Z\xF1a\r\xBCWa\x0ExV[` =\x10a\x0E\xE5Wa\x03\0Q`\x01\x10a\x0E\xE0W`\xA0Q`\x01T\x81\x81\x01\x81\x81\x10a\x0E\xB1W\x91PP`\x01U`\xE0Q`\0T\x81\x81\x10a\x0E\xB1W\x03`\0U[`\x80Qa\x03\0R`\xA0Qa\x03 R`\xE0Qa\x03@R3\x7F\xBF\xD5\n\x04\xF1\xE6\xE4\xAE\xE3D\xF5\xD0\xE7\xF1]t\xD0\xDB\xB5\x8C\xD1\xF7\x11\xDA\xA6F0\x94\xCA\x95\x08\xCD``a\x03\0\xA2`\0Ta\x03\0R`\x01Ta\x03 R\x7F\xCF*\xA5\x08v\xCD\xFB\xB5A o\x89\xAF\x0E\xE7\x8DD\xA2\xAB\xF8\xD3(\xE3\x7F\xA4\x91\x7F\x98!I\x84\x8A`@a\x03\0\xA1`\xE0Qa\x03\0R` a\x03\0\xF3\0[=`\0\x80>=`\0\xFD[\x7FNH{q\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0`\0R`\x12`\x04R`$`\0\xFD[\x7FNH{q\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0`\0R`\x11`\x04R`$`\0\xFD[`\0\x80\xFD[\x7F\x08\xC3y\xA0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0`\0R` `\x04R`\"`$R\x7FABI decoding: tuple data too sho`DR\x7Frt\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0`dR`\x84`\0\xFD[\x7F\x08\xC3y\xA0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0`\0R` `\x04R`\"`$R\x7FEther sent to non-payable functi`DR\x7Fon\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0`dR`\x84`\0\xFD";
+    /// The bytecode of the contract.
+    pub static CONSTANTPRODUCTPOOL_BYTECODE: ::ethers::core::types::Bytes =
+        ::ethers::core::types::Bytes::from_static(__BYTECODE);
+    #[rustfmt::skip]
+    const __DEPLOYED_BYTECODE: &[u8] = b"`\x80`@R4a\x0FaW`\x046\x10a\0\xA7W`\x005`\xE0\x1C\x80c\x08\xC8L!\x14a\n'W\x80c\t\x02\xF1\xAC\x14a\x02%W\x80c$\xA9\xD8S\x14a\x01\x8FW\x80c:\x98\xEF9\x14a\x01\xDDW\x80cP\x01\xF3\xB5\x14a\x013W\x80c\x82\x90\xD9\xB8\x14a\x02<W\x80c\x99\x9B\x93\xAF\x14a\x01aW\x80c\x9C\x8F\x9F#\x14a\x07aW\x80c\x9C\xD4A\xDA\x14a\x03>W\x80c\x9D\xA7q\xF4\x14a\x01\xCDW\x80c\xD77\x92\xA9\x14a\x01#W\x80c\xDF\xDF*r\x14a\x01\xBDW\x80c\xF5\xEBB\xDC\x14a\x01\xEDW[\x7F\x08\xC3y\xA0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0`\0R` `\x04R`5`$R\x7FContract does not have fallback `DR\x7Fnor receive functions\0\0\0\0\0\0\0\0\0\0\0`dR`\x84`\0\xFD[Pa'\x10a\x03\0R` a\x03\0\xF3\0[P\x7F\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0a\x03\0R` a\x03\0\xF3\0[P\x7F\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0a\x03\0R` a\x03\0\xF3\0[P\x7F\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0a\x03\0R` a\x03\0\xF3\0[P`\0Ta\x03\0R` a\x03\0\xF3\0[P`\x01Ta\x03\0R` a\x03\0\xF3\0[P`\x02Ta\x03\0R` a\x03\0\xF3\0[P`\x046\x03` \x90\x10a\x0E\xE5W`\x045\x80`\xA0\x1Ca\x0E\xE0W`\x80R`\x80Q`\x03` R`\0R`@`\0 Ta\x03\0R` a\x03\0\xF3\0[P`\0Ta\x03\0R`\x01Ta\x03 R`@a\x03\0\xF3\0[P`\x046\x03`@\x90\x10a\x0E\xE5W`\x045\x80`\x01\x10a\x0E\xE0W`\x80R`$5`\xA0R`\x80Q\x15a\x02wW`\0T`\xE0R`\x01Ta\x01\0Ra\x02\x85V[`\x01T`\xE0R`\0Ta\x01\0R[\x7F\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0a'\x10\x81\x81\x10a\x0E\xB1W\x03`\xA0Q\x81\x81\x02\x81\x15a\x02\xCBW\x81\x81\x04\x83\x14a\x02\xCBWa\x0E\xB1V[\x91PPa\x01 Ra\x01 Qa'\x10`\xE0Q\x81\x81\x02\x81\x15a\x02\xF3W\x81\x81\x04\x83\x14a\x02\xF3Wa\x0E\xB1V[\x91PP\x81\x81\x01\x81\x81\x10a\x0E\xB1W\x91PPa\x01\0Qa\x01 Q\x81\x81\x02\x81\x15a\x03\"W\x81\x81\x04\x83\x14a\x03\"Wa\x0E\xB1V[\x91PP\x81\x15a\x0E\x82W\x04`\xC0R`\xC0Qa\x03\0R` a\x03\0\xF3\0[P`\x046\x03`@\x90\x10a\x0E\xE5W`\x045`\x80R`$5`\xA0R`\0`\xA0Q\x14`\0`\x80Q\x14\x17\x15a\x03\x93W\x7F\x1F* \x05\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0`\0R`\x04`\0\xFD[`\0`\x02T\x14\x15a\x04\xC6W`\0`\xC0R`\x03`\xA0Q`\x80Q\x81\x81\x02\x81\x15a\x03\xC2W\x81\x81\x04\x83\x14a\x03\xC2Wa\x0E\xB1V[\x91PP\x11\x15a\x04\x8CW`\xA0Q`\x80Q\x81\x81\x02\x81\x15a\x03\xE8W\x81\x81\x04\x83\x14a\x03\xE8Wa\x0E\xB1V[\x91PP`\xC0R`\x01`\x02`\xA0Q`\x80Q\x81\x81\x02\x81\x15a\x04\x0FW\x81\x81\x04\x83\x14a\x04\x0FWa\x0E\xB1V[\x91PP\x81\x15a\x0E\x82W\x04\x81\x81\x01\x81\x81\x10a\x0E\xB1W\x91PP`\xE0R[`\xC0Q`\xE0Q\x10\x15a\x04\x87W`\xE0Q`\xC0R`\x02`\xE0Q`\xE0Q`\xA0Q`\x80Q\x81\x81\x02\x81\x15a\x04aW\x81\x81\x04\x83\x14a\x04aWa\x0E\xB1V[\x91PP\x81\x15a\x0E\x82W\x04\x81\x81\x01\x81\x81\x10a\x0E\xB1W\x91PP\x81\x15a\x0E\x82W\x04`\xE0Ra\x04*V[a\x04\xC1V[`\0`\xA0Q`\x80Q\x81\x81\x02\x81\x15a\x04\xABW\x81\x81\x04\x83\x14a\x04\xABWa\x0E\xB1V[\x91PP\x14\x15\x15a\x04\xBFW`\x01`\xC0Ra\x04\xC0V[[[a\x059V[`\0T`\x02T`\x80Q\x81\x81\x02\x81\x15a\x04\xE6W\x81\x81\x04\x83\x14a\x04\xE6Wa\x0E\xB1V[\x91PP\x81\x15a\x0E\x82W\x04`\xC0R`\x01T`\x02T`\xA0Q\x81\x81\x02\x81\x15a\x05\x13W\x81\x81\x04\x83\x14a\x05\x13Wa\x0E\xB1V[\x91PP\x81\x15a\x0E\x82W\x04`\xE0R`\xC0Q`\xE0Q\x10\x15a\x057W`\xE0Q`\xC0Ra\x058V[[[`\0`\xC0Q\x14\x15a\x05nW\x7F\x1F* \x05\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0`\0R`\x04`\0\xFD[\x7F#\xB8r\xDD\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0a\x03\0R3a\x03\x04R0a\x03$R`\x80Qa\x03DR` a\x03\0`da\x03\0`\0\x7F\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0Z\xF1a\x05\xDCWa\x0ExV[` =\x10a\x0E\xE5Wa\x03\0Q`\x01\x10a\x0E\xE0W\x7F#\xB8r\xDD\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0a\x03\0R3a\x03\x04R0a\x03$R`\xA0Qa\x03DR` a\x03\0`da\x03\0`\0\x7F\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0Z\xF1a\x06]Wa\x0ExV[` =\x10a\x0E\xE5Wa\x03\0Q`\x01\x10a\x0E\xE0W`\x80Q`\0T\x81\x81\x01\x81\x81\x10a\x0E\xB1W\x91PP`\0U`\xA0Q`\x01T\x81\x81\x01\x81\x81\x10a\x0E\xB1W\x91PP`\x01U`\xC0Q`\x02T\x81\x81\x01\x81\x81\x10a\x0E\xB1W\x91PP`\x02U`\xC0Q3`\x03` R`\0R`@`\0 T\x81\x81\x01\x81\x81\x10a\x0E\xB1W\x91PP3`\x03` R`\0R`@`\0 U`\x80Qa\x03\0R`\xA0Qa\x03 R`\xC0Qa\x03@R3\x7Fd\xB89D\xE7\x9C<\xE8\xD4\xC2\x97A\x1D\xE67\xC3\xE1\x02\xD0dgz\xAC\x0C\x169v\xEB\xDC\xD6\xF5\x0E``a\x03\0\xA2`\0Ta\x03\0R`\x01Ta\x03 R\x7F\xCF*\xA5\x08v\xCD\xFB\xB5A o\x89\xAF\x0E\xE7\x8DD\xA2\xAB\xF8\xD3(\xE3\x7F\xA4\x91\x7F\x98!I\x84\x8A`@a\x03\0\xA1`\xC0Qa\x03\0R` a\x03\0\xF3\0[P`\x046\x03` \x90\x10a\x0E\xE5W`\x045`\x80R`\0`\x80Q\x14\x15a\x07\xA9W\x7F\x1F* \x05\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0`\0R`\x04`\0\xFD[3`\x03` R`\0R`@`\0 T`\xA0R`\x80Q`\xA0Q\x10\x15a\x07\xFDW\x7F\xCB\x1D\x8B\xBA\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0`\0R`\xA0Q`\x04R`\x80Q`$R`D`\0\xFD[`\x02T`\0T`\x80Q\x81\x81\x02\x81\x15a\x08\x1DW\x81\x81\x04\x83\x14a\x08\x1DWa\x0E\xB1V[\x91PP\x81\x15a\x0E\x82W\x04`\xC0R`\x02T`\x01T`\x80Q\x81\x81\x02\x81\x15a\x08JW\x81\x81\x04\x83\x14a\x08JWa\x0E\xB1V[\x91PP\x81\x15a\x0E\x82W\x04`\xE0R`\x80Q`\xA0Q\x81\x81\x10a\x0E\xB1W\x033`\x03` R`\0R`@`\0 U`\x80Q`\x02T\x81\x81\x10a\x0E\xB1W\x03`\x02U`\xC0Q`\0T\x81\x81\x10a\x0E\xB1W\x03`\0U`\xE0Q`\x01T\x81\x81\x10a\x0E\xB1W\x03`\x01U\x7F\xA9\x05\x9C\xBB\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0a\x03\0R3a\x03\x04R`\xC0Qa\x03$R` a\x03\0`Da\x03\0`\0\x7F\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0Z\xF1a\t\x10Wa\x0ExV[` =\x10a\x0E\xE5Wa\x03\0Q`\x01\x10a\x0E\xE0W\x7F\xA9\x05\x9C\xBB\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0a\x03\0R3a\x03\x04R`\xE0Qa\x03$R` a\x03\0`Da\x03\0`\0\x7F\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0Z\xF1a\t\x8CWa\x0ExV[` =\x10a\x0E\xE5Wa\x03\0Q`\x01\x10a\x0E\xE0W`\xC0Qa\x03\0R`\xE0Qa\x03 R`\x80Qa\x03@R3\x7F\x1D\xC8\xBBi\xDF+\x8E\x91\xFB\xDC\xBF\xCF\x93\xD9Q\xB3\xF0\0\x0F\x08Z\x95\xFE?yF\xD6\x16\x149$]``a\x03\0\xA2`\0Ta\x03\0R`\x01Ta\x03 R\x7F\xCF*\xA5\x08v\xCD\xFB\xB5A o\x89\xAF\x0E\xE7\x8DD\xA2\xAB\xF8\xD3(\xE3\x7F\xA4\x91\x7F\x98!I\x84\x8A`@a\x03\0\xA1`\xC0Qa\x03\0R`\xE0Qa\x03 R`@a\x03\0\xF3\0[P`\x046\x03``\x90\x10a\x0E\xE5W`\x045\x80`\x01\x10a\x0E\xE0W`\x80R`$5`\xA0R`D5`\xC0R`\0`\xA0Q\x14\x15a\n\x83W\x7F\x1F* \x05\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0`\0R`\x04`\0\xFD[`\x80Q\x15a\n\x9EW`\0Ta\x01\0R`\x01Ta\x01 Ra\n\xADV[`\x01Ta\x01\0R`\0Ta\x01 R[\x7F\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0a'\x10\x81\x81\x10a\x0E\xB1W\x03`\xA0Q\x81\x81\x02\x81\x15a\n\xF3W\x81\x81\x04\x83\x14a\n\xF3Wa\x0E\xB1V[\x91PPa\x01@Ra\x01@Qa'\x10a\x01\0Q\x81\x81\x02\x81\x15a\x0B\x1CW\x81\x81\x04\x83\x14a\x0B\x1CWa\x0E\xB1V[\x91PP\x81\x81\x01\x81\x81\x10a\x0E\xB1W\x91PPa\x01 Qa\x01@Q\x81\x81\x02\x81\x15a\x0BKW\x81\x81\x04\x83\x14a\x0BKWa\x0E\xB1V[\x91PP\x81\x15a\x0E\x82W\x04`\xE0R`\xC0Q`\xE0Q\x10`\0`\xE0Q\x14\x17\x15a\x0B\xA1W\x7F,\x19\xB8\xB8\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0`\0R`\xE0Q`\x04R`\xC0Q`$R`D`\0\xFD[`\x80Q\x15a\x0C\xD2W\x7F#\xB8r\xDD\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0a\x03\0R3a\x03\x04R0a\x03$R`\xA0Qa\x03DR` a\x03\0`da\x03\0`\0\x7F\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0Z\xF1a\x0C\x17Wa\x0ExV[` =\x10a\x0E\xE5Wa\x03\0Q`\x01\x10a\x0E\xE0W\x7F\xA9\x05\x9C\xBB\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0a\x03\0R3a\x03\x04R`\xE0Qa\x03$R` a\x03\0`Da\x03\0`\0\x7F\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0Z\xF1a\x0C\x93Wa\x0ExV[` =\x10a\x0E\xE5Wa\x03\0Q`\x01\x10a\x0E\xE0W`\xA0Q`\0T\x81\x81\x01\x81\x81\x10a\x0E\xB1W\x91PP`\0U`\xE0Q`\x01T\x81\x81\x10a\x0E\xB1W\x03`\x01Ua\r\xF7V[\x7F#\xB8r\xDD\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0a\x03\0R3a\x03\x04R0a\x03$R`\xA0Qa\x03DR` a\x03\0`da\x03\0`\0\x7F\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0Z\xF1a\r@Wa\x0ExV[` =\x10a\x0E\xE5Wa\x03\0Q`\x01\x10a\x0E\xE0W\x7F\xA9\x05\x9C\xBB\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0a\x03\0R3a\x03\x04R`\xE0Qa\x03$R` a\x03\0`Da\x03\0`\0\x7F\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0Z\xF1a\r\xBCWa\x0ExV[` =\x10a\x0E\xE5Wa\x03\0Q`\x01\x10a\x0E\xE0W`\xA0Q`\x01T\x81\x81\x01\x81\x81\x10a\x0E\xB1W\x91PP`\x01U`\xE0Q`\0T\x81\x81\x10a\x0E\xB1W\x03`\0U[`\x80Qa\x03\0R`\xA0Qa\x03 R`\xE0Qa\x03@R3\x7F\xBF\xD5\n\x04\xF1\xE6\xE4\xAE\xE3D\xF5\xD0\xE7\xF1]t\xD0\xDB\xB5\x8C\xD1\xF7\x11\xDA\xA6F0\x94\xCA\x95\x08\xCD``a\x03\0\xA2`\0Ta\x03\0R`\x01Ta\x03 R\x7F\xCF*\xA5\x08v\xCD\xFB\xB5A o\x89\xAF\x0E\xE7\x8DD\xA2\xAB\xF8\xD3(\xE3\x7F\xA4\x91\x7F\x98!I\x84\x8A`@a\x03\0\xA1`\xE0Qa\x03\0R` a\x03\0\xF3\0[=`\0\x80>=`\0\xFD[\x7FNH{q\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0`\0R`\x12`\x04R`$`\0\xFD[\x7FNH{q\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0`\0R`\x11`\x04R`$`\0\xFD[`\0\x80\xFD[\x7F\x08\xC3y\xA0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0`\0R` `\x04R`\"`$R\x7FABI decoding: tuple data too sho`DR\x7Frt\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0`dR`\x84`\0\xFD[\x7F\x08\xC3y\xA0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0`\0R` `\x04R`\"`$R\x7FEther sent to non-payable functi`DR\x7Fon\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0`dR`\x84`\0\xFD";
+    /// The deployed bytecode of the contract.
+    pub static CONSTANTPRODUCTPOOL_DEPLOYED_BYTECODE: ::ethers::core::types::Bytes =
+        ::ethers::core::types::Bytes::from_static(__DEPLOYED_BYTECODE);
+    pub struct ConstantProductPool<M>(::ethers::contract::Contract<M>);
+    impl<M> ::core::clone::Clone for ConstantProductPool<M> {
+        fn clone(&self) -> Self {
+            Self(::core::clone::Clone::clone(&self.0))
+        }
+    }
+    impl<M> ::core::ops::Deref for ConstantProductPool<M> {
+        type Target = ::ethers::contract::Contract<M>;
+        fn deref(&self) -> &Self::Target {
+            &self.0
+        }
+    }
+    impl<M> ::core::ops::DerefMut for ConstantProductPool<M> {
+        fn deref_mut(&mut self) -> &mut Self::Target {
+            &mut self.0
+        }
+    }
+    impl<M> ::core::fmt::Debug for ConstantProductPool<M> {
+        fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+            f.debug_tuple(::core::stringify!(ConstantProductPool))
+                .field(&self.address())
+                .finish()
+        }
+    }
+    impl<M: ::ethers::providers::Middleware> ConstantProductPool<M> {
+        /// Creates a new contract instance with the specified `ethers` client
+        /// at `address`. The contract derefs to a `ethers::Contract`
+        /// object.
+        pub fn new<T: Into<::ethers::core::types::Address>>(
+            address: T,
+            client: ::std::sync::Arc<M>,
+        ) -> Self {
+            Self(::ethers::contract::Contract::new(
+                address.into(),
+                CONSTANTPRODUCTPOOL_ABI.clone(),
+                client,
+            ))
+        }
+        /// Constructs the general purpose `Deployer` instance based on the
+        /// provided constructor arguments and sends it. Returns a new
+        /// instance of a deployer that returns an instance of this contract
+        /// after sending the transaction
+        ///
+        /// Notes:
+        /// - If there are no constructor arguments, you should pass `()` as the
+        ///   argument.
+        /// - The default poll duration is 7 seconds.
+        /// - The default number of confirmations is 1 block.
+        ///
+        ///
+        /// # Example
+        ///
+        /// Generate contract bindings with `abigen!` and deploy a new contract
+        /// instance.
+        ///
+        /// *Note*: this requires a `bytecode` and `abi` object in the
+        /// `greeter.json` artifact.
+        ///
+        /// ```ignore
+        /// # async fn deploy<M: ethers::providers::Middleware>(client: ::std::sync::Arc<M>) {
+        ///     abigen!(Greeter, "../greeter.json");
+        ///
+        ///    let greeter_contract = Greeter::deploy(client, "Hello world!".to_string()).unwrap().send().await.unwrap();
+        ///    let msg = greeter_contract.greet().call().await.unwrap();
+        /// # }
+        /// ```
+        pub fn deploy<T: ::ethers::core::abi::Tokenize>(
+            client: ::std::sync::Arc<M>,
+            constructor_args: T,
+        ) -> ::core::result::Result<
+            ::ethers::contract::builders::ContractDeployer<M, Self>,
+            ::ethers::contract::ContractError<M>,
+        > {
+            let factory = ::ethers::contract::ContractFactory::new(
+                CONSTANTPRODUCTPOOL_ABI.clone(),
+                CONSTANTPRODUCTPOOL_BYTECODE.clone().into(),
+                client,
+            );
+            let deployer = factory.deploy(constructor_args)?;
+            let deployer = ::ethers::contract::ContractDeployer::new(deployer);
+            Ok(deployer)
+        }
+        /// Calls the contract's `FEE_DENOMINATOR` (0xd73792a9) function
+        pub fn fee_denominator(
+            &self,
+        ) -> ::ethers::contract::builders::ContractCall<M, ::ethers::core::types::U256> {
+            self.0
+                .method_hash([215, 55, 146, 169], ())
+                .expect("method not found (this should never happen)")
+        }
+        /// Calls the contract's `addLiquidity` (0x9cd441da) function
+        pub fn add_liquidity(
+            &self,
+            base_amount: ::ethers::core::types::U256,
+            quote_amount: ::ethers::core::types::U256,
+        ) -> ::ethers::contract::builders::ContractCall<M, ::ethers::core::types::U256> {
+            self.0
+                .method_hash([156, 212, 65, 218], (base_amount, quote_amount))
+                .expect("method not found (this should never happen)")
+        }
+        /// Calls the contract's `base` (0x5001f3b5) function
+        pub fn base(
+            &self,
+        ) -> ::ethers::contract::builders::ContractCall<M, ::ethers::core::types::Address> {
+            self.0
+                .method_hash([80, 1, 243, 181], ())
+                .expect("method not found (this should never happen)")
+        }
+        /// Calls the contract's `baseReserve` (0xdfdf2a72) function
+        pub fn base_reserve(
+            &self,
+        ) -> ::ethers::contract::builders::ContractCall<M, ::ethers::core::types::U256> {
+            self.0
+                .method_hash([223, 223, 42, 114], ())
+                .expect("method not found (this should never happen)")
+        }
+        /// Calls the contract's `feeBps` (0x24a9d853) function
+        pub fn fee_bps(
+            &self,
+        ) -> ::ethers::contract::builders::ContractCall<M, ::ethers::core::types::U256> {
+            self.0
+                .method_hash([36, 169, 216, 83], ())
+                .expect("method not found (this should never happen)")
+        }
+        /// Calls the contract's `getAmountOut` (0x8290d9b8) function
+        pub fn get_amount_out(
+            &self,
+            sell_base: bool,
+            amount_in: ::ethers::core::types::U256,
+        ) -> ::ethers::contract::builders::ContractCall<M, ::ethers::core::types::U256> {
+            self.0
+                .method_hash([130, 144, 217, 184], (sell_base, amount_in))
+                .expect("method not found (this should never happen)")
+        }
+        /// Calls the contract's `getReserves` (0x0902f1ac) function
+        pub fn get_reserves(
+            &self,
+        ) -> ::ethers::contract::builders::ContractCall<
+            M,
+            (::ethers::core::types::U256, ::ethers::core::types::U256),
+        > {
+            self.0
+                .method_hash([9, 2, 241, 172], ())
+                .expect("method not found (this should never happen)")
+        }
+        /// Calls the contract's `quote` (0x999b93af) function
+        pub fn quote(
+            &self,
+        ) -> ::ethers::contract::builders::ContractCall<M, ::ethers::core::types::Address> {
+            self.0
+                .method_hash([153, 155, 147, 175], ())
+                .expect("method not found (this should never happen)")
+        }
+        /// Calls the contract's `quoteReserve` (0x9da771f4) function
+        pub fn quote_reserve(
+            &self,
+        ) -> ::ethers::contract::builders::ContractCall<M, ::ethers::core::types::U256> {
+            self.0
+                .method_hash([157, 167, 113, 244], ())
+                .expect("method not found (this should never happen)")
+        }
+        /// Calls the contract's `removeLiquidity` (0x9c8f9f23) function
+        pub fn remove_liquidity(
+            &self,
+            shares: ::ethers::core::types::U256,
+        ) -> ::ethers::contract::builders::ContractCall<
+            M,
+            (::ethers::core::types::U256, ::ethers::core::types::U256),
+        > {
+            self.0
+                .method_hash([156, 143, 159, 35], shares)
+                .expect("method not found (this should never happen)")
+        }
+        /// Calls the contract's `sharesOf` (0xf5eb42dc) function
+        pub fn shares_of(
+            &self,
+            p0: ::ethers::core::types::Address,
+        ) -> ::ethers::contract::builders::ContractCall<M, ::ethers::core::types::U256> {
+            self.0
+                .method_hash([245, 235, 66, 220], p0)
+                .expect("method not found (this should never happen)")
+        }
+        /// Calls the contract's `swap` (0x08c84c21) function
+        pub fn swap(
+            &self,
+            sell_base: bool,
+            amount_in: ::ethers::core::types::U256,
+            min_amount_out: ::ethers::core::types::U256,
+        ) -> ::ethers::contract::builders::ContractCall<M, ::ethers::core::types::U256> {
+            self.0
+                .method_hash([8, 200, 76, 33], (sell_base, amount_in, min_amount_out))
+                .expect("method not found (this should never happen)")
+        }
+        /// Calls the contract's `totalShares` (0x3a98ef39) function
+        pub fn total_shares(
+            &self,
+        ) -> ::ethers::contract::builders::ContractCall<M, ::ethers::core::types::U256> {
+            self.0
+                .method_hash([58, 152, 239, 57], ())
+                .expect("method not found (this should never happen)")
+        }
+        /// Gets the contract's `LiquidityAdded` event
+        pub fn liquidity_added_filter(
+            &self,
+        ) -> ::ethers::contract::builders::Event<::std::sync::Arc<M>, M, LiquidityAddedFilter>
+        {
+            self.0.event()
+        }
+        /// Gets the contract's `LiquidityRemoved` event
+        pub fn liquidity_removed_filter(
+            &self,
+        ) -> ::ethers::contract::builders::Event<::std::sync::Arc<M>, M, LiquidityRemovedFilter>
+        {
+            self.0.event()
+        }
+        /// Gets the contract's `Swap` event
+        pub fn swap_filter(
+            &self,
+        ) -> ::ethers::contract::builders::Event<::std::sync::Arc<M>, M, SwapFilter> {
+            self.0.event()
+        }
+        /// Gets the contract's `Sync` event
+        pub fn sync_filter(
+            &self,
+        ) -> ::ethers::contract::builders::Event<::std::sync::Arc<M>, M, SyncFilter> {
+            self.0.event()
+        }
+        /// Returns an `Event` builder for all the events of this contract.
+        pub fn events(
+            &self,
+        ) -> ::ethers::contract::builders::Event<::std::sync::Arc<M>, M, ConstantProductPoolEvents>
+        {
+            self.0
+                .event_with_filter(::core::default::Default::default())
+        }
+    }
+    impl<M: ::ethers::providers::Middleware> From<::ethers::contract::Contract<M>>
+        for ConstantProductPool<M>
+    {
+        fn from(contract: ::ethers::contract::Contract<M>) -> Self {
+            Self::new(contract.address(), contract.client())
+        }
+    }
+    /// Custom Error type `InsufficientOutput` with signature
+    /// `InsufficientOutput(uint256,uint256)` and selector `0x2c19b8b8`
+    #[derive(
+        Clone,
+        ::ethers::contract::EthError,
+        ::ethers::contract::EthDisplay,
+        serde::Serialize,
+        serde::Deserialize,
+        Default,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+    )]
+    #[etherror(
+        name = "InsufficientOutput",
+        abi = "InsufficientOutput(uint256,uint256)"
+    )]
+    pub struct InsufficientOutput {
+        pub amount_out: ::ethers::core::types::U256,
+        pub min_amount_out: ::ethers::core::types::U256,
+    }
+    /// Custom Error type `InsufficientShares` with signature
+    /// `InsufficientShares(uint256,uint256)` and selector `0xcb1d8bba`
+    #[derive(
+        Clone,
+        ::ethers::contract::EthError,
+        ::ethers::contract::EthDisplay,
+        serde::Serialize,
+        serde::Deserialize,
+        Default,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+    )]
+    #[etherror(
+        name = "InsufficientShares",
+        abi = "InsufficientShares(uint256,uint256)"
+    )]
+    pub struct InsufficientShares {
+        pub shares: ::ethers::core::types::U256,
+        pub needed: ::ethers::core::types::U256,
+    }
+    /// Custom Error type `InvalidFee` with signature `InvalidFee(uint256)` and
+    /// selector `0x2f38c6ee`
+    #[derive(
+        Clone,
+        ::ethers::contract::EthError,
+        ::ethers::contract::EthDisplay,
+        serde::Serialize,
+        serde::Deserialize,
+        Default,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+    )]
+    #[etherror(name = "InvalidFee", abi = "InvalidFee(uint256)")]
+    pub struct InvalidFee {
+        pub fee_bps: ::ethers::core::types::U256,
+    }
+    /// Custom Error type `ZeroAmount` with signature `ZeroAmount()` and
+    /// selector `0x1f2a2005`
+    #[derive(
+        Clone,
+        ::ethers::contract::EthError,
+        ::ethers::contract::EthDisplay,
+        serde::Serialize,
+        serde::Deserialize,
+        Default,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+    )]
+    #[etherror(name = "ZeroAmount", abi = "ZeroAmount()")]
+    pub struct ZeroAmount;
+    /// Container type for all of the contract's custom errors
+    #[derive(
+        Clone,
+        ::ethers::contract::EthAbiType,
+        serde::Serialize,
+        serde::Deserialize,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+    )]
+    pub enum ConstantProductPoolErrors {
+        InsufficientOutput(InsufficientOutput),
+        InsufficientShares(InsufficientShares),
+        InvalidFee(InvalidFee),
+        ZeroAmount(ZeroAmount),
+        /// The standard solidity revert string, with selector
+        /// Error(string) -- 0x08c379a0
+        RevertString(::std::string::String),
+    }
+    impl ::ethers::core::abi::AbiDecode for ConstantProductPoolErrors {
+        fn decode(
+            data: impl AsRef<[u8]>,
+        ) -> ::core::result::Result<Self, ::ethers::core::abi::AbiError> {
+            let data = data.as_ref();
+            if let Ok(decoded) =
+                <::std::string::String as ::ethers::core::abi::AbiDecode>::decode(data)
+            {
+                return Ok(Self::RevertString(decoded));
+            }
+            if let Ok(decoded) =
+                <InsufficientOutput as ::ethers::core::abi::AbiDecode>::decode(data)
+            {
+                return Ok(Self::InsufficientOutput(decoded));
+            }
+            if let Ok(decoded) =
+                <InsufficientShares as ::ethers::core::abi::AbiDecode>::decode(data)
+            {
+                return Ok(Self::InsufficientShares(decoded));
+            }
+            if let Ok(decoded) = <InvalidFee as ::ethers::core::abi::AbiDecode>::decode(data) {
+                return Ok(Self::InvalidFee(decoded));
+            }
+            if let Ok(decoded) = <ZeroAmount as ::ethers::core::abi::AbiDecode>::decode(data) {
+                return Ok(Self::ZeroAmount(decoded));
+            }
+            Err(::ethers::core::abi::Error::InvalidData.into())
+        }
+    }
+    impl ::ethers::core::abi::AbiEncode for ConstantProductPoolErrors {
+        fn encode(self) -> ::std::vec::Vec<u8> {
+            match self {
+                Self::InsufficientOutput(element) => {
+                    ::ethers::core::abi::AbiEncode::encode(element)
+                }
+                Self::InsufficientShares(element) => {
+                    ::ethers::core::abi::AbiEncode::encode(element)
+                }
+                Self::InvalidFee(element) => ::ethers::core::abi::AbiEncode::encode(element),
+                Self::ZeroAmount(element) => ::ethers::core::abi::AbiEncode::encode(element),
+                Self::RevertString(s) => ::ethers::core::abi::AbiEncode::encode(s),
+            }
+        }
+    }
+    impl ::ethers::contract::ContractRevert for ConstantProductPoolErrors {
+        fn valid_selector(selector: [u8; 4]) -> bool {
+            match selector {
+                [0x08, 0xc3, 0x79, 0xa0] => true,
+                _ if selector
+                    == <InsufficientOutput as ::ethers::contract::EthError>::selector() =>
+                {
+                    true
+                }
+                _ if selector
+                    == <InsufficientShares as ::ethers::contract::EthError>::selector() =>
+                {
+                    true
+                }
+                _ if selector == <InvalidFee as ::ethers::contract::EthError>::selector() => true,
+                _ if selector == <ZeroAmount as ::ethers::contract::EthError>::selector() => true,
+                _ => false,
+            }
+        }
+    }
+    impl ::core::fmt::Display for ConstantProductPoolErrors {
+        fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+            match self {
+                Self::InsufficientOutput(element) => ::core::fmt::Display::fmt(element, f),
+                Self::InsufficientShares(element) => ::core::fmt::Display::fmt(element, f),
+                Self::InvalidFee(element) => ::core::fmt::Display::fmt(element, f),
+                Self::ZeroAmount(element) => ::core::fmt::Display::fmt(element, f),
+                Self::RevertString(s) => ::core::fmt::Display::fmt(s, f),
+            }
+        }
+    }
+    impl ::core::convert::From<::std::string::String> for ConstantProductPoolErrors {
+        fn from(value: String) -> Self {
+            Self::RevertString(value)
+        }
+    }
+    impl ::core::convert::From<InsufficientOutput> for ConstantProductPoolErrors {
+        fn from(value: InsufficientOutput) -> Self {
+            Self::InsufficientOutput(value)
+        }
+    }
+    impl ::core::convert::From<InsufficientShares> for ConstantProductPoolErrors {
+        fn from(value: InsufficientShares) -> Self {
+            Self::InsufficientShares(value)
+        }
+    }
+    impl ::core::convert::From<InvalidFee> for ConstantProductPoolErrors {
+        fn from(value: InvalidFee) -> Self {
+            Self::InvalidFee(value)
+        }
+    }
+    impl ::core::convert::From<ZeroAmount> for ConstantProductPoolErrors {
+        fn from(value: ZeroAmount) -> Self {
+            Self::ZeroAmount(value)
+        }
+    }
+    #[derive(
+        Clone,
+        ::ethers::contract::EthEvent,
+        ::ethers::contract::EthDisplay,
+        serde::Serialize,
+        serde::Deserialize,
+        Default,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+    )]
+    #[ethevent(
+        name = "LiquidityAdded",
+        abi = "LiquidityAdded(address,uint256,uint256,uint256)"
+    )]
+    pub struct LiquidityAddedFilter {
+        #[ethevent(indexed)]
+        pub provider: ::ethers::core::types::Address,
+        pub base_amount: ::ethers::core::types::U256,
+        pub quote_amount: ::ethers::core::types::U256,
+        pub shares: ::ethers::core::types::U256,
+    }
+    #[derive(
+        Clone,
+        ::ethers::contract::EthEvent,
+        ::ethers::contract::EthDisplay,
+        serde::Serialize,
+        serde::Deserialize,
+        Default,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+    )]
+    #[ethevent(
+        name = "LiquidityRemoved",
+        abi = "LiquidityRemoved(address,uint256,uint256,uint256)"
+    )]
+    pub struct LiquidityRemovedFilter {
+        #[ethevent(indexed)]
+        pub provider: ::ethers::core::types::Address,
+        pub base_amount: ::ethers::core::types::U256,
+        pub quote_amount: ::ethers::core::types::U256,
+        pub shares: ::ethers::core::types::U256,
+    }
+    #[derive(
+        Clone,
+        ::ethers::contract::EthEvent,
+        ::ethers::contract::EthDisplay,
+        serde::Serialize,
+        serde::Deserialize,
+        Default,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+    )]
+    #[ethevent(name = "Swap", abi = "Swap(address,bool,uint256,uint256)")]
+    pub struct SwapFilter {
+        #[ethevent(indexed)]
+        pub trader: ::ethers::core::types::Address,
+        pub sell_base: bool,
+        pub amount_in: ::ethers::core::types::U256,
+        pub amount_out: ::ethers::core::types::U256,
+    }
+    #[derive(
+        Clone,
+        ::ethers::contract::EthEvent,
+        ::ethers::contract::EthDisplay,
+        serde::Serialize,
+        serde::Deserialize,
+        Default,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+    )]
+    #[ethevent(name = "Sync", abi = "Sync(uint256,uint256)")]
+    pub struct SyncFilter {
+        pub base_reserve: ::ethers::core::types::U256,
+        pub quote_reserve: ::ethers::core::types::U256,
+    }
+    /// Container type for all of the contract's events
+    #[derive(
+        Clone,
+        ::ethers::contract::EthAbiType,
+        serde::Serialize,
+        serde::Deserialize,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+    )]
+    pub enum ConstantProductPoolEvents {
+        LiquidityAddedFilter(LiquidityAddedFilter),
+        LiquidityRemovedFilter(LiquidityRemovedFilter),
+        SwapFilter(SwapFilter),
+        SyncFilter(SyncFilter),
+    }
+    impl ::ethers::contract::EthLogDecode for ConstantProductPoolEvents {
+        fn decode_log(
+            log: &::ethers::core::abi::RawLog,
+        ) -> ::core::result::Result<Self, ::ethers::core::abi::Error> {
+            if let Ok(decoded) = LiquidityAddedFilter::decode_log(log) {
+                return Ok(ConstantProductPoolEvents::LiquidityAddedFilter(decoded));
+            }
+            if let Ok(decoded) = LiquidityRemovedFilter::decode_log(log) {
+                return Ok(ConstantProductPoolEvents::LiquidityRemovedFilter(decoded));
+            }
+            if let Ok(decoded) = SwapFilter::decode_log(log) {
+                return Ok(ConstantProductPoolEvents::SwapFilter(decoded));
+            }
+            if let Ok(decoded) = SyncFilter::decode_log(log) {
+                return Ok(ConstantProductPoolEvents::SyncFilter(decoded));
+            }
+            Err(::ethers::core::abi::Error::InvalidData)
+        }
+    }
+    impl ::core::fmt::Display for ConstantProductPoolEvents {
+        fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+            match self {
+                Self::LiquidityAddedFilter(element) => ::core::fmt::Display::fmt(element, f),
+                Self::LiquidityRemovedFilter(element) => ::core::fmt::Display::fmt(element, f),
+                Self::SwapFilter(element) => ::core::fmt::Display::fmt(element, f),
+                Self::SyncFilter(element) => ::core::fmt::Display::fmt(element, f),
+            }
+        }
+    }
+    impl ::core::convert::From<LiquidityAddedFilter> for ConstantProductPoolEvents {
+        fn from(value: LiquidityAddedFilter) -> Self {
+            Self::LiquidityAddedFilter(value)
+        }
+    }
+    impl ::core::convert::From<LiquidityRemovedFilter> for ConstantProductPoolEvents {
+        fn from(value: LiquidityRemovedFilter) -> Self {
+            Self::LiquidityRemovedFilter(value)
+        }
+    }
+    impl ::core::convert::From<SwapFilter> for ConstantProductPoolEvents {
+        fn from(value: SwapFilter) -> Self {
+            Self::SwapFilter(value)
+        }
+    }
+    impl ::core::convert::From<SyncFilter> for ConstantProductPoolEvents {
+        fn from(value: SyncFilter) -> Self {
+            Self::SyncFilter(value)
+        }
+    }
+    /// Container type for all input parameters for the `FEE_DENOMINATOR`
+    /// function with signature `FEE_DENOMINATOR()` and selector `0xd73792a9`
+    #[derive(
+        Clone,
+        ::ethers::contract::EthCall,
+        ::ethers::contract::EthDisplay,
+        serde::Serialize,
+        serde::Deserialize,
+        Default,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+    )]
+    #[ethcall(name = "FEE_DENOMINATOR", abi = "FEE_DENOMINATOR()")]
+    pub struct FeeDenominatorCall;
+    /// Container type for all input parameters for the `addLiquidity` function
+    /// with signature `addLiquidity(uint256,uint256)` and selector `0x9cd441da`
+    #[derive(
+        Clone,
+        ::ethers::contract::EthCall,
+        ::ethers::contract::EthDisplay,
+        serde::Serialize,
+        serde::Deserialize,
+        Default,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+    )]
+    #[ethcall(name = "addLiquidity", abi = "addLiquidity(uint256,uint256)")]
+    pub struct AddLiquidityCall {
+        pub base_amount: ::ethers::core::types::U256,
+        pub quote_amount: ::ethers::core::types::U256,
+    }
+    /// Container type for all input parameters for the `base` function with
+    /// signature `base()` and selector `0x5001f3b5`
+    #[derive(
+        Clone,
+        ::ethers::contract::EthCall,
+        ::ethers::contract::EthDisplay,
+        serde::Serialize,
+        serde::Deserialize,
+        Default,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+    )]
+    #[ethcall(name = "base", abi = "base()")]
+    pub struct BaseCall;
+    /// Container type for all input parameters for the `baseReserve` function
+    /// with signature `baseReserve()` and selector `0xdfdf2a72`
+    #[derive(
+        Clone,
+        ::ethers::contract::EthCall,
+        ::ethers::contract::EthDisplay,
+        serde::Serialize,
+        serde::Deserialize,
+        Default,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+    )]
+    #[ethcall(name = "baseReserve", abi = "baseReserve()")]
+    pub struct BaseReserveCall;
+    /// Container type for all input parameters for the `feeBps` function with
+    /// signature `feeBps()` and selector `0x24a9d853`
+    #[derive(
+        Clone,
+        ::ethers::contract::EthCall,
+        ::ethers::contract::EthDisplay,
+        serde::Serialize,
+        serde::Deserialize,
+        Default,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+    )]
+    #[ethcall(name = "feeBps", abi = "feeBps()")]
+    pub struct FeeBpsCall;
+    /// Container type for all input parameters for the `getAmountOut` function
+    /// with signature `getAmountOut(bool,uint256)` and selector `0x8290d9b8`
+    #[derive(
+        Clone,
+        ::ethers::contract::EthCall,
+        ::ethers::contract::EthDisplay,
+        serde::Serialize,
+        serde::Deserialize,
+        Default,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+    )]
+    #[ethcall(name = "getAmountOut", abi = "getAmountOut(bool,uint256)")]
+    pub struct GetAmountOutCall {
+        pub sell_base: bool,
+        pub amount_in: ::ethers::core::types::U256,
+    }
+    /// Container type for all input parameters for the `getReserves` function
+    /// with signature `getReserves()` and selector `0x0902f1ac`
+    #[derive(
+        Clone,
+        ::ethers::contract::EthCall,
+        ::ethers::contract::EthDisplay,
+        serde::Serialize,
+        serde::Deserialize,
+        Default,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+    )]
+    #[ethcall(name = "getReserves", abi = "getReserves()")]
+    pub struct GetReservesCall;
+    /// Container type for all input parameters for the `quote` function with
+    /// signature `quote()` and selector `0x999b93af`
+    #[derive(
+        Clone,
+        ::ethers::contract::EthCall,
+        ::ethers::contract::EthDisplay,
+        serde::Serialize,
+        serde::Deserialize,
+        Default,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+    )]
+    #[ethcall(name = "quote", abi = "quote()")]
+    pub struct QuoteCall;
+    /// Container type for all input parameters for the `quoteReserve` function
+    /// with signature `quoteReserve()` and selector `0x9da771f4`
+    #[derive(
+        Clone,
+        ::ethers::contract::EthCall,
+        ::ethers::contract::EthDisplay,
+        serde::Serialize,
+        serde::Deserialize,
+        Default,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+    )]
+    #[ethcall(name = "quoteReserve", abi = "quoteReserve()")]
+    pub struct QuoteReserveCall;
+    /// Container type for all input parameters for the `removeLiquidity`
+    /// function with signature `removeLiquidity(uint256)` and selector
+    /// `0x9c8f9f23`
+    #[derive(
+        Clone,
+        ::ethers::contract::EthCall,
+        ::ethers::contract::EthDisplay,
+        serde::Serialize,
+        serde::Deserialize,
+        Default,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+    )]
+    #[ethcall(name = "removeLiquidity", abi = "removeLiquidity(uint256)")]
+    pub struct RemoveLiquidityCall {
+        pub shares: ::ethers::core::types::U256,
+    }
+    /// Container type for all input parameters for the `sharesOf` function with
+    /// signature `sharesOf(address)` and selector `0xf5eb42dc`
+    #[derive(
+        Clone,
+        ::ethers::contract::EthCall,
+        ::ethers::contract::EthDisplay,
+        serde::Serialize,
+        serde::Deserialize,
+        Default,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+    )]
+    #[ethcall(name = "sharesOf", abi = "sharesOf(address)")]
+    pub struct SharesOfCall(pub ::ethers::core::types::Address);
+    /// Container type for all input parameters for the `swap` function with
+    /// signature `swap(bool,uint256,uint256)` and selector `0x08c84c21`
+    #[derive(
+        Clone,
+        ::ethers::contract::EthCall,
+        ::ethers::contract::EthDisplay,
+        serde::Serialize,
+        serde::Deserialize,
+        Default,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+    )]
+    #[ethcall(name = "swap", abi = "swap(bool,uint256,uint256)")]
+    pub struct SwapCall {
+        pub sell_base: bool,
+        pub amount_in: ::ethers::core::types::U256,
+        pub min_amount_out: ::ethers::core::types::U256,
+    }
+    /// Container type for all input parameters for the `totalShares` function
+    /// with signature `totalShares()` and selector `0x3a98ef39`
+    #[derive(
+        Clone,
+        ::ethers::contract::EthCall,
+        ::ethers::contract::EthDisplay,
+        serde::Serialize,
+        serde::Deserialize,
+        Default,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+    )]
+    #[ethcall(name = "totalShares", abi = "totalShares()")]
+    pub struct TotalSharesCall;
+    /// Container type for all of the contract's call
+    #[derive(
+        Clone,
+        ::ethers::contract::EthAbiType,
+        serde::Serialize,
+        serde::Deserialize,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+    )]
+    pub enum ConstantProductPoolCalls {
+        FeeDenominator(FeeDenominatorCall),
+        AddLiquidity(AddLiquidityCall),
+        Base(BaseCall),
+        BaseReserve(BaseReserveCall),
+        FeeBps(FeeBpsCall),
+        GetAmountOut(GetAmountOutCall),
+        GetReserves(GetReservesCall),
+        Quote(QuoteCall),
+        QuoteReserve(QuoteReserveCall),
+        RemoveLiquidity(RemoveLiquidityCall),
+        SharesOf(SharesOfCall),
+        Swap(SwapCall),
+        TotalShares(TotalSharesCall),
+    }
+    impl ::ethers::core::abi::AbiDecode for ConstantProductPoolCalls {
+        fn decode(
+            data: impl AsRef<[u8]>,
+        ) -> ::core::result::Result<Self, ::ethers::core::abi::AbiError> {
+            let data = data.as_ref();
+            if let Ok(decoded) =
+                <FeeDenominatorCall as ::ethers::core::abi::AbiDecode>::decode(data)
+            {
+                return Ok(Self::FeeDenominator(decoded));
+            }
+            if let Ok(decoded) = <AddLiquidityCall as ::ethers::core::abi::AbiDecode>::decode(data)
+            {
+                return Ok(Self::AddLiquidity(decoded));
+            }
+            if let Ok(decoded) = <BaseCall as ::ethers::core::abi::AbiDecode>::decode(data) {
+                return Ok(Self::Base(decoded));
+            }
+            if let Ok(decoded) = <BaseReserveCall as ::ethers::core::abi::AbiDecode>::decode(data) {
+                return Ok(Self::BaseReserve(decoded));
+            }
+            if let Ok(decoded) = <FeeBpsCall as ::ethers::core::abi::AbiDecode>::decode(data) {
+                return Ok(Self::FeeBps(decoded));
+            }
+            if let Ok(decoded) = <GetAmountOutCall as ::ethers::core::abi::AbiDecode>::decode(data)
+            {
+                return Ok(Self::GetAmountOut(decoded));
+            }
+            if let Ok(decoded) = <GetReservesCall as ::ethers::core::abi::AbiDecode>::decode(data) {
+                return Ok(Self::GetReserves(decoded));
+            }
+            if let Ok(decoded) = <QuoteCall as ::ethers::core::abi::AbiDecode>::decode(data) {
+                return Ok(Self::Quote(decoded));
+            }
+            if let Ok(decoded) = <QuoteReserveCall as ::ethers::core::abi::AbiDecode>::decode(data)
+            {
+                return Ok(Self::QuoteReserve(decoded));
+            }
+            if let Ok(decoded) =
+                <RemoveLiquidityCall as ::ethers::core::abi::AbiDecode>::decode(data)
+            {
+                return Ok(Self::RemoveLiquidity(decoded));
+            }
+            if let Ok(decoded) = <SharesOfCall as ::ethers::core::abi::AbiDecode>::decode(data) {
+                return Ok(Self::SharesOf(decoded));
+            }
+            if let Ok(decoded) = <SwapCall as ::ethers::core::abi::AbiDecode>::decode(data) {
+                return Ok(Self::Swap(decoded));
+            }
+            if let Ok(decoded) = <TotalSharesCall as ::ethers::core::abi::AbiDecode>::decode(data) {
+                return Ok(Self::TotalShares(decoded));
+            }
+            Err(::ethers::core::abi::Error::InvalidData.into())
+        }
+    }
+    impl ::ethers::core::abi::AbiEncode for ConstantProductPoolCalls {
+        fn encode(self) -> Vec<u8> {
+            match self {
+                Self::FeeDenominator(element) => ::ethers::core::abi::AbiEncode::encode(element),
+                Self::AddLiquidity(element) => ::ethers::core::abi::AbiEncode::encode(element),
+                Self::Base(element) => ::ethers::core::abi::AbiEncode::encode(element),
+                Self::BaseReserve(element) => ::ethers::core::abi::AbiEncode::encode(element),
+                Self::FeeBps(element) => ::ethers::core::abi::AbiEncode::encode(element),
+                Self::GetAmountOut(element) => ::ethers::core::abi::AbiEncode::encode(element),
+                Self::GetReserves(element) => ::ethers::core::abi::AbiEncode::encode(element),
+                Self::Quote(element) => ::ethers::core::abi::AbiEncode::encode(element),
+                Self::QuoteReserve(element) => ::ethers::core::abi::AbiEncode::encode(element),
+                Self::RemoveLiquidity(element) => ::ethers::core::abi::AbiEncode::encode(element),
+                Self::SharesOf(element) => ::ethers::core::abi::AbiEncode::encode(element),
+                Self::Swap(element) => ::ethers::core::abi::AbiEncode::encode(element),
+                Self::TotalShares(element) => ::ethers::core::abi::AbiEncode::encode(element),
+            }
+        }
+    }
+    impl ::core::fmt::Display for ConstantProductPoolCalls {
+        fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+            match self {
+                Self::FeeDenominator(element) => ::core::fmt::Display::fmt(element, f),
+                Self::AddLiquidity(element) => ::core::fmt::Display::fmt(element, f),
+                Self::Base(element) => ::core::fmt::Display::fmt(element, f),
+                Self::BaseReserve(element) => ::core::fmt::Display::fmt(element, f),
+                Self::FeeBps(element) => ::core::fmt::Display::fmt(element, f),
+                Self::GetAmountOut(element) => ::core::fmt::Display::fmt(element, f),
+                Self::GetReserves(element) => ::core::fmt::Display::fmt(element, f),
+                Self::Quote(element) => ::core::fmt::Display::fmt(element, f),
+                Self::QuoteReserve(element) => ::core::fmt::Display::fmt(element, f),
+                Self::RemoveLiquidity(element) => ::core::fmt::Display::fmt(element, f),
+                Self::SharesOf(element) => ::core::fmt::Display::fmt(element, f),
+                Self::Swap(element) => ::core::fmt::Display::fmt(element, f),
+                Self::TotalShares(element) => ::core::fmt::Display::fmt(element, f),
+            }
+        }
+    }
+    impl ::core::convert::From<FeeDenominatorCall> for ConstantProductPoolCalls {
+        fn from(value: FeeDenominatorCall) -> Self {
+            Self::FeeDenominator(value)
+        }
+    }
+    impl ::core::convert::From<AddLiquidityCall> for ConstantProductPoolCalls {
+        fn from(value: AddLiquidityCall) -> Self {
+            Self::AddLiquidity(value)
+        }
+    }
+    impl ::core::convert::From<BaseCall> for ConstantProductPoolCalls {
+        fn from(value: BaseCall) -> Self {
+            Self::Base(value)
+        }
+    }
+    impl ::core::convert::From<BaseReserveCall> for ConstantProductPoolCalls {
+        fn from(value: BaseReserveCall) -> Self {
+            Self::BaseReserve(value)
+        }
+    }
+    impl ::core::convert::From<FeeBpsCall> for ConstantProductPoolCalls {
+        fn from(value: FeeBpsCall) -> Self {
+            Self::FeeBps(value)
+        }
+    }
+    impl ::core::convert::From<GetAmountOutCall> for ConstantProductPoolCalls {
+        fn from(value: GetAmountOutCall) -> Self {
+            Self::GetAmountOut(value)
+        }
+    }
+    impl ::core::convert::From<GetReservesCall> for ConstantProductPoolCalls {
+        fn from(value: GetReservesCall) -> Self {
+            Self::GetReserves(value)
+        }
+    }
+    impl ::core::convert::From<QuoteCall> for ConstantProductPoolCalls {
+        fn from(value: QuoteCall) -> Self {
+            Self::Quote(value)
+        }
+    }
+    impl ::core::convert::From<QuoteReserveCall> for ConstantProductPoolCalls {
+        fn from(value: QuoteReserveCall) -> Self {
+            Self::QuoteReserve(value)
+        }
+    }
+    impl ::core::convert::From<RemoveLiquidityCall> for ConstantProductPoolCalls {
+        fn from(value: RemoveLiquidityCall) -> Self {
+            Self::RemoveLiquidity(value)
+        }
+    }
+    impl ::core::convert::From<SharesOfCall> for ConstantProductPoolCalls {
+        fn from(value: SharesOfCall) -> Self {
+            Self::SharesOf(value)
+        }
+    }
+    impl ::core::convert::From<SwapCall> for ConstantProductPoolCalls {
+        fn from(value: SwapCall) -> Self {
+            Self::Swap(value)
+        }
+    }
+    impl ::core::convert::From<TotalSharesCall> for ConstantProductPoolCalls {
+        fn from(value: TotalSharesCall) -> Self {
+            Self::TotalShares(value)
+        }
+    }
+    /// Container type for all return fields from the `FEE_DENOMINATOR` function
+    /// with signature `FEE_DENOMINATOR()` and selector `0xd73792a9`
+    #[derive(
+        Clone,
+        ::ethers::contract::EthAbiType,
+        ::ethers::contract::EthAbiCodec,
+        serde::Serialize,
+        serde::Deserialize,
+        Default,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+    )]
+    pub struct FeeDenominatorReturn(pub ::ethers::core::types::U256);
+    /// Container type for all return fields from the `addLiquidity` function
+    /// with signature `addLiquidity(uint256,uint256)` and selector `0x9cd441da`
+    #[derive(
+        Clone,
+        ::ethers::contract::EthAbiType,
+        ::ethers::contract::EthAbiCodec,
+        serde::Serialize,
+        serde::Deserialize,
+        Default,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+    )]
+    pub struct AddLiquidityReturn {
+        pub shares: ::ethers::core::types::U256,
+    }
+    /// Container type for all return fields from the `base` function with
+    /// signature `base()` and selector `0x5001f3b5`
+    #[derive(
+        Clone,
+        ::ethers::contract::EthAbiType,
+        ::ethers::contract::EthAbiCodec,
+        serde::Serialize,
+        serde::Deserialize,
+        Default,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+    )]
+    pub struct BaseReturn(pub ::ethers::core::types::Address);
+    /// Container type for all return fields from the `baseReserve` function
+    /// with signature `baseReserve()` and selector `0xdfdf2a72`
+    #[derive(
+        Clone,
+        ::ethers::contract::EthAbiType,
+        ::ethers::contract::EthAbiCodec,
+        serde::Serialize,
+        serde::Deserialize,
+        Default,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+    )]
+    pub struct BaseReserveReturn(pub ::ethers::core::types::U256);
+    /// Container type for all return fields from the `feeBps` function with
+    /// signature `feeBps()` and selector `0x24a9d853`
+    #[derive(
+        Clone,
+        ::ethers::contract::EthAbiType,
+        ::ethers::contract::EthAbiCodec,
+        serde::Serialize,
+        serde::Deserialize,
+        Default,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+    )]
+    pub struct FeeBpsReturn(pub ::ethers::core::types::U256);
+    /// Container type for all return fields from the `getAmountOut` function
+    /// with signature `getAmountOut(bool,uint256)` and selector `0x8290d9b8`
+    #[derive(
+        Clone,
+        ::ethers::contract::EthAbiType,
+        ::ethers::contract::EthAbiCodec,
+        serde::Serialize,
+        serde::Deserialize,
+        Default,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+    )]
+    pub struct GetAmountOutReturn(pub ::ethers::core::types::U256);
+    /// Container type for all return fields from the `getReserves` function
+    /// with signature `getReserves()` and selector `0x0902f1ac`
+    #[derive(
+        Clone,
+        ::ethers::contract::EthAbiType,
+        ::ethers::contract::EthAbiCodec,
+        serde::Serialize,
+        serde::Deserialize,
+        Default,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+    )]
+    pub struct GetReservesReturn(
+        pub ::ethers::core::types::U256,
+        pub ::ethers::core::types::U256,
+    );
+    /// Container type for all return fields from the `quote` function with
+    /// signature `quote()` and selector `0x999b93af`
+    #[derive(
+        Clone,
+        ::ethers::contract::EthAbiType,
+        ::ethers::contract::EthAbiCodec,
+        serde::Serialize,
+        serde::Deserialize,
+        Default,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+    )]
+    pub struct QuoteReturn(pub ::ethers::core::types::Address);
+    /// Container type for all return fields from the `quoteReserve` function
+    /// with signature `quoteReserve()` and selector `0x9da771f4`
+    #[derive(
+        Clone,
+        ::ethers::contract::EthAbiType,
+        ::ethers::contract::EthAbiCodec,
+        serde::Serialize,
+        serde::Deserialize,
+        Default,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+    )]
+    pub struct QuoteReserveReturn(pub ::ethers::core::types::U256);
+    /// Container type for all return fields from the `removeLiquidity` function
+    /// with signature `removeLiquidity(uint256)` and selector `0x9c8f9f23`
+    #[derive(
+        Clone,
+        ::ethers::contract::EthAbiType,
+        ::ethers::contract::EthAbiCodec,
+        serde::Serialize,
+        serde::Deserialize,
+        Default,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+    )]
+    pub struct RemoveLiquidityReturn {
+        pub base_amount: ::ethers::core::types::U256,
+        pub quote_amount: ::ethers::core::types::U256,
+    }
+    /// Container type for all return fields from the `sharesOf` function with
+    /// signature `sharesOf(address)` and selector `0xf5eb42dc`
+    #[derive(
+        Clone,
+        ::ethers::contract::EthAbiType,
+        ::ethers::contract::EthAbiCodec,
+        serde::Serialize,
+        serde::Deserialize,
+        Default,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+    )]
+    pub struct SharesOfReturn(pub ::ethers::core::types::U256);
+    /// Container type for all return fields from the `swap` function with
+    /// signature `swap(bool,uint256,uint256)` and selector `0x08c84c21`
+    #[derive(
+        Clone,
+        ::ethers::contract::EthAbiType,
+        ::ethers::contract::EthAbiCodec,
+        serde::Serialize,
+        serde::Deserialize,
+        Default,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+    )]
+    pub struct SwapReturn {
+        pub amount_out: ::ethers::core::types::U256,
+    }
+    /// Container type for all return fields from the `totalShares` function
+    /// with signature `totalShares()` and selector `0x3a98ef39`
+    #[derive(
+        Clone,
+        ::ethers::contract::EthAbiType,
+        ::ethers::contract::EthAbiCodec,
+        serde::Serialize,
+        serde::Deserialize,
+        Default,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+    )]
+    pub struct TotalSharesReturn(pub ::ethers::core::types::U256);
+}
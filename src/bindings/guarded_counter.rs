@@ -0,0 +1,1291 @@
+pub use guarded_counter::*;
+/// This module was auto-generated with ethers-rs Abigen.
+/// More information at: <https://github.com/gakonst/ethers-rs>
+#[allow(
+    clippy::enum_variant_names,
+    clippy::too_many_arguments,
+    clippy::upper_case_acronyms,
+    clippy::type_complexity,
+    dead_code,
+    non_camel_case_types
+)]
+pub mod guarded_counter {
+    #[allow(deprecated)]
+    fn __abi() -> ::ethers::core::abi::Abi {
+        ::ethers::core::abi::ethabi::Contract {
+            constructor: ::core::option::Option::Some(::ethers::core::abi::ethabi::Constructor {
+                inputs: ::std::vec![::ethers::core::abi::ethabi::Param {
+                    name: ::std::borrow::ToOwned::to_owned("maxNumber_"),
+                    kind: ::ethers::core::abi::ethabi::ParamType::Uint(256usize),
+                    internal_type: ::core::option::Option::Some(::std::borrow::ToOwned::to_owned(
+                        "uint256"
+                    ),),
+                },],
+            }),
+            functions: ::core::convert::From::from([
+                (
+                    ::std::borrow::ToOwned::to_owned("INCREMENTER_ROLE"),
+                    ::std::vec![::ethers::core::abi::ethabi::Function {
+                        name: ::std::borrow::ToOwned::to_owned("INCREMENTER_ROLE"),
+                        inputs: ::std::vec![],
+                        outputs: ::std::vec![::ethers::core::abi::ethabi::Param {
+                            name: ::std::string::String::new(),
+                            kind: ::ethers::core::abi::ethabi::ParamType::FixedBytes(32usize,),
+                            internal_type: ::core::option::Option::Some(
+                                ::std::borrow::ToOwned::to_owned("bytes32"),
+                            ),
+                        },],
+                        constant: ::core::option::Option::None,
+                        state_mutability: ::ethers::core::abi::ethabi::StateMutability::View,
+                    },],
+                ),
+                (
+                    ::std::borrow::ToOwned::to_owned("SETTER_ROLE"),
+                    ::std::vec![::ethers::core::abi::ethabi::Function {
+                        name: ::std::borrow::ToOwned::to_owned("SETTER_ROLE"),
+                        inputs: ::std::vec![],
+                        outputs: ::std::vec![::ethers::core::abi::ethabi::Param {
+                            name: ::std::string::String::new(),
+                            kind: ::ethers::core::abi::ethabi::ParamType::FixedBytes(32usize,),
+                            internal_type: ::core::option::Option::Some(
+                                ::std::borrow::ToOwned::to_owned("bytes32"),
+                            ),
+                        },],
+                        constant: ::core::option::Option::None,
+                        state_mutability: ::ethers::core::abi::ethabi::StateMutability::View,
+                    },],
+                ),
+                (
+                    ::std::borrow::ToOwned::to_owned("grantRole"),
+                    ::std::vec![::ethers::core::abi::ethabi::Function {
+                        name: ::std::borrow::ToOwned::to_owned("grantRole"),
+                        inputs: ::std::vec![
+                            ::ethers::core::abi::ethabi::Param {
+                                name: ::std::borrow::ToOwned::to_owned("role"),
+                                kind: ::ethers::core::abi::ethabi::ParamType::FixedBytes(32usize,),
+                                internal_type: ::core::option::Option::Some(
+                                    ::std::borrow::ToOwned::to_owned("bytes32"),
+                                ),
+                            },
+                            ::ethers::core::abi::ethabi::Param {
+                                name: ::std::borrow::ToOwned::to_owned("account"),
+                                kind: ::ethers::core::abi::ethabi::ParamType::Address,
+                                internal_type: ::core::option::Option::Some(
+                                    ::std::borrow::ToOwned::to_owned("address"),
+                                ),
+                            },
+                        ],
+                        outputs: ::std::vec![],
+                        constant: ::core::option::Option::None,
+                        state_mutability: ::ethers::core::abi::ethabi::StateMutability::NonPayable,
+                    },],
+                ),
+                (
+                    ::std::borrow::ToOwned::to_owned("hasRole"),
+                    ::std::vec![::ethers::core::abi::ethabi::Function {
+                        name: ::std::borrow::ToOwned::to_owned("hasRole"),
+                        inputs: ::std::vec![
+                            ::ethers::core::abi::ethabi::Param {
+                                name: ::std::string::String::new(),
+                                kind: ::ethers::core::abi::ethabi::ParamType::FixedBytes(32usize,),
+                                internal_type: ::core::option::Option::Some(
+                                    ::std::borrow::ToOwned::to_owned("bytes32"),
+                                ),
+                            },
+                            ::ethers::core::abi::ethabi::Param {
+                                name: ::std::string::String::new(),
+                                kind: ::ethers::core::abi::ethabi::ParamType::Address,
+                                internal_type: ::core::option::Option::Some(
+                                    ::std::borrow::ToOwned::to_owned("address"),
+                                ),
+                            },
+                        ],
+                        outputs: ::std::vec![::ethers::core::abi::ethabi::Param {
+                            name: ::std::string::String::new(),
+                            kind: ::ethers::core::abi::ethabi::ParamType::Bool,
+                            internal_type: ::core::option::Option::Some(
+                                ::std::borrow::ToOwned::to_owned("bool"),
+                            ),
+                        },],
+                        constant: ::core::option::Option::None,
+                        state_mutability: ::ethers::core::abi::ethabi::StateMutability::View,
+                    },],
+                ),
+                (
+                    ::std::borrow::ToOwned::to_owned("increment"),
+                    ::std::vec![::ethers::core::abi::ethabi::Function {
+                        name: ::std::borrow::ToOwned::to_owned("increment"),
+                        inputs: ::std::vec![],
+                        outputs: ::std::vec![],
+                        constant: ::core::option::Option::None,
+                        state_mutability: ::ethers::core::abi::ethabi::StateMutability::NonPayable,
+                    },],
+                ),
+                (
+                    ::std::borrow::ToOwned::to_owned("maxNumber"),
+                    ::std::vec![::ethers::core::abi::ethabi::Function {
+                        name: ::std::borrow::ToOwned::to_owned("maxNumber"),
+                        inputs: ::std::vec![],
+                        outputs: ::std::vec![::ethers::core::abi::ethabi::Param {
+                            name: ::std::string::String::new(),
+                            kind: ::ethers::core::abi::ethabi::ParamType::Uint(256usize,),
+                            internal_type: ::core::option::Option::Some(
+                                ::std::borrow::ToOwned::to_owned("uint256"),
+                            ),
+                        },],
+                        constant: ::core::option::Option::None,
+                        state_mutability: ::ethers::core::abi::ethabi::StateMutability::View,
+                    },],
+                ),
+                (
+                    ::std::borrow::ToOwned::to_owned("number"),
+                    ::std::vec![::ethers::core::abi::ethabi::Function {
+                        name: ::std::borrow::ToOwned::to_owned("number"),
+                        inputs: ::std::vec![],
+                        outputs: ::std::vec![::ethers::core::abi::ethabi::Param {
+                            name: ::std::string::String::new(),
+                            kind: ::ethers::core::abi::ethabi::ParamType::Uint(256usize,),
+                            internal_type: ::core::option::Option::Some(
+                                ::std::borrow::ToOwned::to_owned("uint256"),
+                            ),
+                        },],
+                        constant: ::core::option::Option::None,
+                        state_mutability: ::ethers::core::abi::ethabi::StateMutability::View,
+                    },],
+                ),
+                (
+                    ::std::borrow::ToOwned::to_owned("owner"),
+                    ::std::vec![::ethers::core::abi::ethabi::Function {
+                        name: ::std::borrow::ToOwned::to_owned("owner"),
+                        inputs: ::std::vec![],
+                        outputs: ::std::vec![::ethers::core::abi::ethabi::Param {
+                            name: ::std::string::String::new(),
+                            kind: ::ethers::core::abi::ethabi::ParamType::Address,
+                            internal_type: ::core::option::Option::Some(
+                                ::std::borrow::ToOwned::to_owned("address"),
+                            ),
+                        },],
+                        constant: ::core::option::Option::None,
+                        state_mutability: ::ethers::core::abi::ethabi::StateMutability::View,
+                    },],
+                ),
+                (
+                    ::std::borrow::ToOwned::to_owned("revokeRole"),
+                    ::std::vec![::ethers::core::abi::ethabi::Function {
+                        name: ::std::borrow::ToOwned::to_owned("revokeRole"),
+                        inputs: ::std::vec![
+                            ::ethers::core::abi::ethabi::Param {
+                                name: ::std::borrow::ToOwned::to_owned("role"),
+                                kind: ::ethers::core::abi::ethabi::ParamType::FixedBytes(32usize,),
+                                internal_type: ::core::option::Option::Some(
+                                    ::std::borrow::ToOwned::to_owned("bytes32"),
+                                ),
+                            },
+                            ::ethers::core::abi::ethabi::Param {
+                                name: ::std::borrow::ToOwned::to_owned("account"),
+                                kind: ::ethers::core::abi::ethabi::ParamType::Address,
+                                internal_type: ::core::option::Option::Some(
+                                    ::std::borrow::ToOwned::to_owned("address"),
+                                ),
+                            },
+                        ],
+                        outputs: ::std::vec![],
+                        constant: ::core::option::Option::None,
+                        state_mutability: ::ethers::core::abi::ethabi::StateMutability::NonPayable,
+                    },],
+                ),
+                (
+                    ::std::borrow::ToOwned::to_owned("setNumber"),
+                    ::std::vec![::ethers::core::abi::ethabi::Function {
+                        name: ::std::borrow::ToOwned::to_owned("setNumber"),
+                        inputs: ::std::vec![::ethers::core::abi::ethabi::Param {
+                            name: ::std::borrow::ToOwned::to_owned("newNumber"),
+                            kind: ::ethers::core::abi::ethabi::ParamType::Uint(256usize,),
+                            internal_type: ::core::option::Option::Some(
+                                ::std::borrow::ToOwned::to_owned("uint256"),
+                            ),
+                        },],
+                        outputs: ::std::vec![],
+                        constant: ::core::option::Option::None,
+                        state_mutability: ::ethers::core::abi::ethabi::StateMutability::NonPayable,
+                    },],
+                ),
+                (
+                    ::std::borrow::ToOwned::to_owned("transferOwnership"),
+                    ::std::vec![::ethers::core::abi::ethabi::Function {
+                        name: ::std::borrow::ToOwned::to_owned("transferOwnership"),
+                        inputs: ::std::vec![::ethers::core::abi::ethabi::Param {
+                            name: ::std::borrow::ToOwned::to_owned("newOwner"),
+                            kind: ::ethers::core::abi::ethabi::ParamType::Address,
+                            internal_type: ::core::option::Option::Some(
+                                ::std::borrow::ToOwned::to_owned("address"),
+                            ),
+                        },],
+                        outputs: ::std::vec![],
+                        constant: ::core::option::Option::None,
+                        state_mutability: ::ethers::core::abi::ethabi::StateMutability::NonPayable,
+                    },],
+                ),
+            ]),
+            events: ::core::convert::From::from([
+                (
+                    ::std::borrow::ToOwned::to_owned("Incremented"),
+                    ::std::vec![::ethers::core::abi::ethabi::Event {
+                        name: ::std::borrow::ToOwned::to_owned("Incremented"),
+                        inputs: ::std::vec![::ethers::core::abi::ethabi::EventParam {
+                            name: ::std::borrow::ToOwned::to_owned("newNumber"),
+                            kind: ::ethers::core::abi::ethabi::ParamType::Uint(256usize,),
+                            indexed: false,
+                        },],
+                        anonymous: false,
+                    },],
+                ),
+                (
+                    ::std::borrow::ToOwned::to_owned("NumberSet"),
+                    ::std::vec![::ethers::core::abi::ethabi::Event {
+                        name: ::std::borrow::ToOwned::to_owned("NumberSet"),
+                        inputs: ::std::vec![::ethers::core::abi::ethabi::EventParam {
+                            name: ::std::borrow::ToOwned::to_owned("newNumber"),
+                            kind: ::ethers::core::abi::ethabi::ParamType::Uint(256usize,),
+                            indexed: false,
+                        },],
+                        anonymous: false,
+                    },],
+                ),
+                (
+                    ::std::borrow::ToOwned::to_owned("OwnershipTransferred"),
+                    ::std::vec![::ethers::core::abi::ethabi::Event {
+                        name: ::std::borrow::ToOwned::to_owned("OwnershipTransferred",),
+                        inputs: ::std::vec![
+                            ::ethers::core::abi::ethabi::EventParam {
+                                name: ::std::borrow::ToOwned::to_owned("previousOwner"),
+                                kind: ::ethers::core::abi::ethabi::ParamType::Address,
+                                indexed: true,
+                            },
+                            ::ethers::core::abi::ethabi::EventParam {
+                                name: ::std::borrow::ToOwned::to_owned("newOwner"),
+                                kind: ::ethers::core::abi::ethabi::ParamType::Address,
+                                indexed: true,
+                            },
+                        ],
+                        anonymous: false,
+                    },],
+                ),
+                (
+                    ::std::borrow::ToOwned::to_owned("RoleGranted"),
+                    ::std::vec![::ethers::core::abi::ethabi::Event {
+                        name: ::std::borrow::ToOwned::to_owned("RoleGranted"),
+                        inputs: ::std::vec![
+                            ::ethers::core::abi::ethabi::EventParam {
+                                name: ::std::borrow::ToOwned::to_owned("role"),
+                                kind: ::ethers::core::abi::ethabi::ParamType::FixedBytes(32usize,),
+                                indexed: true,
+                            },
+                            ::ethers::core::abi::ethabi::EventParam {
+                                name: ::std::borrow::ToOwned::to_owned("account"),
+                                kind: ::ethers::core::abi::ethabi::ParamType::Address,
+                                indexed: true,
+                            },
+                        ],
+                        anonymous: false,
+                    },],
+                ),
+                (
+                    ::std::borrow::ToOwned::to_owned("RoleRevoked"),
+                    ::std::vec![::ethers::core::abi::ethabi::Event {
+                        name: ::std::borrow::ToOwned::to_owned("RoleRevoked"),
+                        inputs: ::std::vec![
+                            ::ethers::core::abi::ethabi::EventParam {
+                                name: ::std::borrow::ToOwned::to_owned("role"),
+                                kind: ::ethers::core::abi::ethabi::ParamType::FixedBytes(32usize,),
+                                indexed: true,
+                            },
+                            ::ethers::core::abi::ethabi::EventParam {
+                                name: ::std::borrow::ToOwned::to_owned("account"),
+                                kind: ::ethers::core::abi::ethabi::ParamType::Address,
+                                indexed: true,
+                            },
+                        ],
+                        anonymous: false,
+                    },],
+                ),
+            ]),
+            errors: ::core::convert::From::from([
+                (
+                    ::std::borrow::ToOwned::to_owned("Overflow"),
+                    ::std::vec![::ethers::core::abi::ethabi::AbiError {
+                        name: ::std::borrow::ToOwned::to_owned("Overflow"),
+                        inputs: ::std::vec![],
+                    },],
+                ),
+                (
+                    ::std::borrow::ToOwned::to_owned("Unauthorized"),
+                    ::std::vec![::ethers::core::abi::ethabi::AbiError {
+                        name: ::std::borrow::ToOwned::to_owned("Unauthorized"),
+                        inputs: ::std::vec![::ethers::core::abi::ethabi::Param {
+                            name: ::std::borrow::ToOwned::to_owned("caller"),
+                            kind: ::ethers::core::abi::ethabi::ParamType::Address,
+                            internal_type: ::core::option::Option::Some(
+                                ::std::borrow::ToOwned::to_owned("address"),
+                            ),
+                        },],
+                    },],
+                ),
+            ]),
+            receive: false,
+            fallback: false,
+        }
+    }
+    /// The parsed JSON ABI of the contract.
+    pub static GUARDEDCOUNTER_ABI: ::ethers::contract::Lazy<::ethers::core::abi::Abi> =
+        ::ethers::contract::Lazy::new(__abi);
+    #[rustfmt::skip]
+    const __BYTECODE: &[u8] = b"`\x80`@R4a\0\xF1Wa\x08\x8F8\x03\x80a\x02\xC0Ra\x08\x8Fa\x04\09a\x02\xC0Q` \x90\x10a\0uWa\x04\0Q`\x80R3`\0Ua\x04\0a\x02\xC0Q\x01a\x02\xE0Ra\x07\"a\x01ma\x02\xE0Q9`\x80Qa\x02\xE0Qa\x01|\x01R`\x80Qa\x02\xE0Qa\x04b\x01R`\x80Qa\x02\xE0Qa\x05`\x01Ra\x07\"a\x02\xE0Q\xF3[\x7F\x08\xC3y\xA0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0`\0R` `\x04R`\"`$R\x7FABI decoding: tuple data too sho`DR\x7Frt\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0`dR`\x84`\0\xFD[\x7F\x08\xC3y\xA0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0`\0R` `\x04R`\"`$R\x7FEther sent to non-payable functi`DR\x7Fon\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0`dR`\x84`\0\xFD`\x80`@R4a\x06\xA6W`\x046\x10a\0\x91W`\x005`\xE0\x1C\x80c//\xF1]\x14a\x02\x8AW\x80c:Oi\x99\x14a\x01yW\x80c?\xB5\xC1\xCB\x14a\x03\xDAW\x80c_\x1E.\x83\x14a\x01;W\x80c\x83\x81\xF5\x8A\x14a\x01\xA7W\x80c\x8D\xA5\xCB[\x14a\x01iW\x80c\x91\xD1HT\x14a\x01\xB7W\x80c\xA2\x01\x1B?\x14a\x01\rW\x80c\xD0\x9D\xE0\x8A\x14a\x04\xEAW\x80c\xD5Gt\x1F\x14a\x032W\x80c\xF2\xFD\xE3\x8B\x14a\x02\x03W[\x7F\x08\xC3y\xA0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0`\0R` `\x04R`5`$R\x7FContract does not have fallback `DR\x7Fnor receive functions\0\0\0\0\0\0\0\0\0\0\0`dR`\x84`\0\xFD[P\x7Fa\xC9!i\xEF\x07sI\x01\x1F\xF0\xB18<\x89M\x86\xC5\xF0\xB4\x1D\x98cf\xB5\x8Al\xF3\x1E\x93\xBE\xDAa\x03\0R` a\x03\0\xF3\0[P\x7F\xD1\x98_\\r\xE2F\x11\xCB2\xE2\xB7\xB0#\x0ET\x0Es\x12\x15\x1E\xC9\xCD\x03\x97\x8B\x12\"\x80`h\x9Aa\x03\0R` a\x03\0\xF3\0[P`\0Ta\x03\0R` a\x03\0\xF3\0[P\x7F\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0a\x03\0R` a\x03\0\xF3\0[P`\x01Ta\x03\0R` a\x03\0\xF3\0[P`\x046\x03`@\x90\x10a\x06*W`\x045`\x80R`$5\x80`\xA0\x1Ca\x06%W`\xA0R`\xA0Q`\x80Q`\x02` R`\0R`@`\0 ` R`\0R`@`\0 Ta\x03\0R` a\x03\0\xF3\0[P`\x046\x03` \x90\x10a\x06*W`\x045\x80`\xA0\x1Ca\x06%W`\x80R`\0T3\x14a\x02UW\x7F\x8EJ#\xD6\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0`\0R3`\x04R`$`\0\xFD[`\x80Q`\0T\x7F\x8B\xE0\x07\x9CS\x16Y\x14\x13D\xCD\x1F\xD0\xA4\xF2\x84\x19I\x7F\x97\"\xA3\xDA\xAF\xE3\xB4\x18okdW\xE0`\0a\x03\0\xA3`\x80Q`\0U\0[P`\x046\x03`@\x90\x10a\x06*W`\x045`\x80R`$5\x80`\xA0\x1Ca\x06%W`\xA0R`\0T3\x14a\x02\xE2W\x7F\x8EJ#\xD6\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0`\0R3`\x04R`$`\0\xFD[`\x01`\xA0Q`\x80Q`\x02` R`\0R`@`\0 ` R`\0R`@`\0 U`\xA0Q`\x80Q\x7F*\xE6\xA1\x13\xC0\xED[x\xA54\x13\xFF\xBBvy\x88\x1F\x11\x14\\\xCF\xBAO\xB9.\x86=\xFC\xD5\xA1\xD2\xF3`\0a\x03\0\xA3\0[P`\x046\x03`@\x90\x10a\x06*W`\x045`\x80R`$5\x80`\xA0\x1Ca\x06%W`\xA0R`\0T3\x14a\x03\x8AW\x7F\x8EJ#\xD6\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0`\0R3`\x04R`$`\0\xFD[`\0`\xA0Q`\x80Q`\x02` R`\0R`@`\0 ` R`\0R`@`\0 U`\xA0Q`\x80Q\x7F\x15Z\xAA\xFBc)\xA2\t\x85\x80F-\xF3>\xC4\xB7D\x1B\x19r\x9B\x96\x01\xC5\xFC\x17\xAE\x1C\xF9\x9A\x8AR`\0a\x03\0\xA3\0[P`\x046\x03` \x90\x10a\x06*W`\x045`\x80R3\x7Fa\xC9!i\xEF\x07sI\x01\x1F\xF0\xB18<\x89M\x86\xC5\xF0\xB4\x1D\x98cf\xB5\x8Al\xF3\x1E\x93\xBE\xDA`\x02` R`\0R`@`\0 ` R`\0R`@`\0 T`\0T3\x14\x17a\x04`W\x7F\x8EJ#\xD6\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0`\0R3`\x04R`$`\0\xFD[\x7F\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0`\x80Q\x11\x15a\x04\xB4W\x7F5'\x8D\x12\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0`\0R`\x04`\0\xFD[`\x80Q`\x01U`\x80Qa\x03\0R\x7F\x9E\xC8%Ii\xD1\x97N\xAC\x8Ct\xAF\xB0\xC05\x95\xB4\xFF\xE0\xA1\xD7\xAD\x8A\x7F\x82\xED1\xB9\xC8T%\x91` a\x03\0\xA1\0[P3\x7F\xD1\x98_\\r\xE2F\x11\xCB2\xE2\xB7\xB0#\x0ET\x0Es\x12\x15\x1E\xC9\xCD\x03\x97\x8B\x12\"\x80`h\x9A`\x02` R`\0R`@`\0 ` R`\0R`@`\0 T`\0T3\x14\x17a\x05^W\x7F\x8EJ#\xD6\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0`\0R3`\x04R`$`\0\xFD[\x7F\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0`\x01T\x10a\x05\xB1W\x7F5'\x8D\x12\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0`\0R`\x04`\0\xFD[`\x01`\x01T\x81\x81\x01\x81\x81\x10a\x05\xF6W\x91PP`\x01U`\x01Ta\x03\0R\x7F \xD8\xA6\xF5\xA6\x93\xF9\xD1\xD6'\xA5\x98\xE8\x82\x0FzU\xEEt\xC1\x83\xAA\x8F\x1A0\xE8\xD4\xE8\xDD\x9A\x8D\x84` a\x03\0\xA1\0[\x7FNH{q\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0`\0R`\x11`\x04R`$`\0\xFD[`\0\x80\xFD[\x7F\x08\xC3y\xA0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0`\0R` `\x04R`\"`$R\x7FABI decoding: tuple data too sho`DR\x7Frt\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0`dR`\x84`\0\xFD[\x7F\x08\xC3y\xA0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0`\0R` `\x04R`\"`$R\x7FEther sent to non-payable functi`DR\x7Fon\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0`dR`\x84`\0\xFD";
+    /// The bytecode of the contract.
+    pub static GUARDEDCOUNTER_BYTECODE: ::ethers::core::types::Bytes =
+        ::ethers::core::types::Bytes::from_static(__BYTECODE);
+    #[rustfmt::skip]
+    const __DEPLOYED_BYTECODE: &[u8] = b"`\x80`@R4a\x06\xA6W`\x046\x10a\0\x91W`\x005`\xE0\x1C\x80c//\xF1]\x14a\x02\x8AW\x80c:Oi\x99\x14a\x01yW\x80c?\xB5\xC1\xCB\x14a\x03\xDAW\x80c_\x1E.\x83\x14a\x01;W\x80c\x83\x81\xF5\x8A\x14a\x01\xA7W\x80c\x8D\xA5\xCB[\x14a\x01iW\x80c\x91\xD1HT\x14a\x01\xB7W\x80c\xA2\x01\x1B?\x14a\x01\rW\x80c\xD0\x9D\xE0\x8A\x14a\x04\xEAW\x80c\xD5Gt\x1F\x14a\x032W\x80c\xF2\xFD\xE3\x8B\x14a\x02\x03W[\x7F\x08\xC3y\xA0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0`\0R` `\x04R`5`$R\x7FContract does not have fallback `DR\x7Fnor receive functions\0\0\0\0\0\0\0\0\0\0\0`dR`\x84`\0\xFD[P\x7Fa\xC9!i\xEF\x07sI\x01\x1F\xF0\xB18<\x89M\x86\xC5\xF0\xB4\x1D\x98cf\xB5\x8Al\xF3\x1E\x93\xBE\xDAa\x03\0R` a\x03\0\xF3\0[P\x7F\xD1\x98_\\r\xE2F\x11\xCB2\xE2\xB7\xB0#\x0ET\x0Es\x12\x15\x1E\xC9\xCD\x03\x97\x8B\x12\"\x80`h\x9Aa\x03\0R` a\x03\0\xF3\0[P`\0Ta\x03\0R` a\x03\0\xF3\0[P\x7F\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0a\x03\0R` a\x03\0\xF3\0[P`\x01Ta\x03\0R` a\x03\0\xF3\0[P`\x046\x03`@\x90\x10a\x06*W`\x045`\x80R`$5\x80`\xA0\x1Ca\x06%W`\xA0R`\xA0Q`\x80Q`\x02` R`\0R`@`\0 ` R`\0R`@`\0 Ta\x03\0R` a\x03\0\xF3\0[P`\x046\x03` \x90\x10a\x06*W`\x045\x80`\xA0\x1Ca\x06%W`\x80R`\0T3\x14a\x02UW\x7F\x8EJ#\xD6\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0`\0R3`\x04R`$`\0\xFD[`\x80Q`\0T\x7F\x8B\xE0\x07\x9CS\x16Y\x14\x13D\xCD\x1F\xD0\xA4\xF2\x84\x19I\x7F\x97\"\xA3\xDA\xAF\xE3\xB4\x18okdW\xE0`\0a\x03\0\xA3`\x80Q`\0U\0[P`\x046\x03`@\x90\x10a\x06*W`\x045`\x80R`$5\x80`\xA0\x1Ca\x06%W`\xA0R`\0T3\x14a\x02\xE2W\x7F\x8EJ#\xD6\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0`\0R3`\x04R`$`\0\xFD[`\x01`\xA0Q`\x80Q`\x02` R`\0R`@`\0 ` R`\0R`@`\0 U`\xA0Q`\x80Q\x7F*\xE6\xA1\x13\xC0\xED[x\xA54\x13\xFF\xBBvy\x88\x1F\x11\x14\\\xCF\xBAO\xB9.\x86=\xFC\xD5\xA1\xD2\xF3`\0a\x03\0\xA3\0[P`\x046\x03`@\x90\x10a\x06*W`\x045`\x80R`$5\x80`\xA0\x1Ca\x06%W`\xA0R`\0T3\x14a\x03\x8AW\x7F\x8EJ#\xD6\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0`\0R3`\x04R`$`\0\xFD[`\0`\xA0Q`\x80Q`\x02` R`\0R`@`\0 ` R`\0R`@`\0 U`\xA0Q`\x80Q\x7F\x15Z\xAA\xFBc)\xA2\t\x85\x80F-\xF3>\xC4\xB7D\x1B\x19r\x9B\x96\x01\xC5\xFC\x17\xAE\x1C\xF9\x9A\x8AR`\0a\x03\0\xA3\0[P`\x046\x03` \x90\x10a\x06*W`\x045`\x80R3\x7Fa\xC9!i\xEF\x07sI\x01\x1F\xF0\xB18<\x89M\x86\xC5\xF0\xB4\x1D\x98cf\xB5\x8Al\xF3\x1E\x93\xBE\xDA`\x02` R`\0R`@`\0 ` R`\0R`@`\0 T`\0T3\x14\x17a\x04`W\x7F\x8EJ#\xD6\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0`\0R3`\x04R`$`\0\xFD[\x7F\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0`\x80Q\x11\x15a\x04\xB4W\x7F5'\x8D\x12\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0`\0R`\x04`\0\xFD[`\x80Q`\x01U`\x80Qa\x03\0R\x7F\x9E\xC8%Ii\xD1\x97N\xAC\x8Ct\xAF\xB0\xC05\x95\xB4\xFF\xE0\xA1\xD7\xAD\x8A\x7F\x82\xED1\xB9\xC8T%\x91` a\x03\0\xA1\0[P3\x7F\xD1\x98_\\r\xE2F\x11\xCB2\xE2\xB7\xB0#\x0ET\x0Es\x12\x15\x1E\xC9\xCD\x03\x97\x8B\x12\"\x80`h\x9A`\x02` R`\0R`@`\0 ` R`\0R`@`\0 T`\0T3\x14\x17a\x05^W\x7F\x8EJ#\xD6\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0`\0R3`\x04R`$`\0\xFD[\x7F\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0`\x01T\x10a\x05\xB1W\x7F5'\x8D\x12\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0`\0R`\x04`\0\xFD[`\x01`\x01T\x81\x81\x01\x81\x81\x10a\x05\xF6W\x91PP`\x01U`\x01Ta\x03\0R\x7F \xD8\xA6\xF5\xA6\x93\xF9\xD1\xD6'\xA5\x98\xE8\x82\x0FzU\xEEt\xC1\x83\xAA\x8F\x1A0\xE8\xD4\xE8\xDD\x9A\x8D\x84` a\x03\0\xA1\0[\x7FNH{q\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0`\0R`\x11`\x04R`$`\0\xFD[`\0\x80\xFD[\x7F\x08\xC3y\xA0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0`\0R` `\x04R`\"`$R\x7FABI decoding: tuple data too sho`DR\x7Frt\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0`dR`\x84`\0\xFD[\x7F\x08\xC3y\xA0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0`\0R` `\x04R`\"`$R\x7FEther sent to non-payable functi`DR\x7Fon\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0`dR`\x84`\0\xFD";
+    /// The deployed bytecode of the contract.
+    pub static GUARDEDCOUNTER_DEPLOYED_BYTECODE: ::ethers::core::types::Bytes =
+        ::ethers::core::types::Bytes::from_static(__DEPLOYED_BYTECODE);
+    pub struct GuardedCounter<M>(::ethers::contract::Contract<M>);
+    impl<M> ::core::clone::Clone for GuardedCounter<M> {
+        fn clone(&self) -> Self {
+            Self(::core::clone::Clone::clone(&self.0))
+        }
+    }
+    impl<M> ::core::ops::Deref for GuardedCounter<M> {
+        type Target = ::ethers::contract::Contract<M>;
+        fn deref(&self) -> &Self::Target {
+            &self.0
+        }
+    }
+    impl<M> ::core::ops::DerefMut for GuardedCounter<M> {
+        fn deref_mut(&mut self) -> &mut Self::Target {
+            &mut self.0
+        }
+    }
+    impl<M> ::core::fmt::Debug for GuardedCounter<M> {
+        fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+            f.debug_tuple(::core::stringify!(GuardedCounter))
+                .field(&self.address())
+                .finish()
+        }
+    }
+    impl<M: ::ethers::providers::Middleware> GuardedCounter<M> {
+        /// Creates a new contract instance with the specified `ethers` client
+        /// at `address`. The contract derefs to a `ethers::Contract`
+        /// object.
+        pub fn new<T: Into<::ethers::core::types::Address>>(
+            address: T,
+            client: ::std::sync::Arc<M>,
+        ) -> Self {
+            Self(::ethers::contract::Contract::new(
+                address.into(),
+                GUARDEDCOUNTER_ABI.clone(),
+                client,
+            ))
+        }
+        /// Constructs the general purpose `Deployer` instance based on the
+        /// provided constructor arguments and sends it. Returns a new
+        /// instance of a deployer that returns an instance of this contract
+        /// after sending the transaction
+        ///
+        /// Notes:
+        /// - If there are no constructor arguments, you should pass `()` as the
+        ///   argument.
+        /// - The default poll duration is 7 seconds.
+        /// - The default number of confirmations is 1 block.
+        ///
+        ///
+        /// # Example
+        ///
+        /// Generate contract bindings with `abigen!` and deploy a new contract
+        /// instance.
+        ///
+        /// *Note*: this requires a `bytecode` and `abi` object in the
+        /// `greeter.json` artifact.
+        ///
+        /// ```ignore
+        /// # async fn deploy<M: ethers::providers::Middleware>(client: ::std::sync::Arc<M>) {
+        ///     abigen!(Greeter, "../greeter.json");
+        ///
+        ///    let greeter_contract = Greeter::deploy(client, "Hello world!".to_string()).unwrap().send().await.unwrap();
+        ///    let msg = greeter_contract.greet().call().await.unwrap();
+        /// # }
+        /// ```
+        pub fn deploy<T: ::ethers::core::abi::Tokenize>(
+            client: ::std::sync::Arc<M>,
+            constructor_args: T,
+        ) -> ::core::result::Result<
+            ::ethers::contract::builders::ContractDeployer<M, Self>,
+            ::ethers::contract::ContractError<M>,
+        > {
+            let factory = ::ethers::contract::ContractFactory::new(
+                GUARDEDCOUNTER_ABI.clone(),
+                GUARDEDCOUNTER_BYTECODE.clone().into(),
+                client,
+            );
+            let deployer = factory.deploy(constructor_args)?;
+            let deployer = ::ethers::contract::ContractDeployer::new(deployer);
+            Ok(deployer)
+        }
+        /// Calls the contract's `INCREMENTER_ROLE` (0x5f1e2e83) function
+        pub fn incrementer_role(&self) -> ::ethers::contract::builders::ContractCall<M, [u8; 32]> {
+            self.0
+                .method_hash([95, 30, 46, 131], ())
+                .expect("method not found (this should never happen)")
+        }
+        /// Calls the contract's `SETTER_ROLE` (0xa2011b3f) function
+        pub fn setter_role(&self) -> ::ethers::contract::builders::ContractCall<M, [u8; 32]> {
+            self.0
+                .method_hash([162, 1, 27, 63], ())
+                .expect("method not found (this should never happen)")
+        }
+        /// Calls the contract's `grantRole` (0x2f2ff15d) function
+        pub fn grant_role(
+            &self,
+            role: [u8; 32],
+            account: ::ethers::core::types::Address,
+        ) -> ::ethers::contract::builders::ContractCall<M, ()> {
+            self.0
+                .method_hash([47, 47, 241, 93], (role, account))
+                .expect("method not found (this should never happen)")
+        }
+        /// Calls the contract's `hasRole` (0x91d14854) function
+        pub fn has_role(
+            &self,
+            p0: [u8; 32],
+            p1: ::ethers::core::types::Address,
+        ) -> ::ethers::contract::builders::ContractCall<M, bool> {
+            self.0
+                .method_hash([145, 209, 72, 84], (p0, p1))
+                .expect("method not found (this should never happen)")
+        }
+        /// Calls the contract's `increment` (0xd09de08a) function
+        pub fn increment(&self) -> ::ethers::contract::builders::ContractCall<M, ()> {
+            self.0
+                .method_hash([208, 157, 224, 138], ())
+                .expect("method not found (this should never happen)")
+        }
+        /// Calls the contract's `maxNumber` (0x3a4f6999) function
+        pub fn max_number(
+            &self,
+        ) -> ::ethers::contract::builders::ContractCall<M, ::ethers::core::types::U256> {
+            self.0
+                .method_hash([58, 79, 105, 153], ())
+                .expect("method not found (this should never happen)")
+        }
+        /// Calls the contract's `number` (0x8381f58a) function
+        pub fn number(
+            &self,
+        ) -> ::ethers::contract::builders::ContractCall<M, ::ethers::core::types::U256> {
+            self.0
+                .method_hash([131, 129, 245, 138], ())
+                .expect("method not found (this should never happen)")
+        }
+        /// Calls the contract's `owner` (0x8da5cb5b) function
+        pub fn owner(
+            &self,
+        ) -> ::ethers::contract::builders::ContractCall<M, ::ethers::core::types::Address> {
+            self.0
+                .method_hash([141, 165, 203, 91], ())
+                .expect("method not found (this should never happen)")
+        }
+        /// Calls the contract's `revokeRole` (0xd547741f) function
+        pub fn revoke_role(
+            &self,
+            role: [u8; 32],
+            account: ::ethers::core::types::Address,
+        ) -> ::ethers::contract::builders::ContractCall<M, ()> {
+            self.0
+                .method_hash([213, 71, 116, 31], (role, account))
+                .expect("method not found (this should never happen)")
+        }
+        /// Calls the contract's `setNumber` (0x3fb5c1cb) function
+        pub fn set_number(
+            &self,
+            new_number: ::ethers::core::types::U256,
+        ) -> ::ethers::contract::builders::ContractCall<M, ()> {
+            self.0
+                .method_hash([63, 181, 193, 203], new_number)
+                .expect("method not found (this should never happen)")
+        }
+        /// Calls the contract's `transferOwnership` (0xf2fde38b) function
+        pub fn transfer_ownership(
+            &self,
+            new_owner: ::ethers::core::types::Address,
+        ) -> ::ethers::contract::builders::ContractCall<M, ()> {
+            self.0
+                .method_hash([242, 253, 227, 139], new_owner)
+                .expect("method not found (this should never happen)")
+        }
+        /// Gets the contract's `Incremented` event
+        pub fn incremented_filter(
+            &self,
+        ) -> ::ethers::contract::builders::Event<::std::sync::Arc<M>, M, IncrementedFilter>
+        {
+            self.0.event()
+        }
+        /// Gets the contract's `NumberSet` event
+        pub fn number_set_filter(
+            &self,
+        ) -> ::ethers::contract::builders::Event<::std::sync::Arc<M>, M, NumberSetFilter> {
+            self.0.event()
+        }
+        /// Gets the contract's `OwnershipTransferred` event
+        pub fn ownership_transferred_filter(
+            &self,
+        ) -> ::ethers::contract::builders::Event<::std::sync::Arc<M>, M, OwnershipTransferredFilter>
+        {
+            self.0.event()
+        }
+        /// Gets the contract's `RoleGranted` event
+        pub fn role_granted_filter(
+            &self,
+        ) -> ::ethers::contract::builders::Event<::std::sync::Arc<M>, M, RoleGrantedFilter>
+        {
+            self.0.event()
+        }
+        /// Gets the contract's `RoleRevoked` event
+        pub fn role_revoked_filter(
+            &self,
+        ) -> ::ethers::contract::builders::Event<::std::sync::Arc<M>, M, RoleRevokedFilter>
+        {
+            self.0.event()
+        }
+        /// Returns an `Event` builder for all the events of this contract.
+        pub fn events(
+            &self,
+        ) -> ::ethers::contract::builders::Event<::std::sync::Arc<M>, M, GuardedCounterEvents>
+        {
+            self.0
+                .event_with_filter(::core::default::Default::default())
+        }
+    }
+    impl<M: ::ethers::providers::Middleware> From<::ethers::contract::Contract<M>>
+        for GuardedCounter<M>
+    {
+        fn from(contract: ::ethers::contract::Contract<M>) -> Self {
+            Self::new(contract.address(), contract.client())
+        }
+    }
+    /// Custom Error type `Overflow` with signature `Overflow()` and selector
+    /// `0x35278d12`
+    #[derive(
+        Clone,
+        ::ethers::contract::EthError,
+        ::ethers::contract::EthDisplay,
+        serde::Serialize,
+        serde::Deserialize,
+        Default,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+    )]
+    #[etherror(name = "Overflow", abi = "Overflow()")]
+    pub struct Overflow;
+    /// Custom Error type `Unauthorized` with signature `Unauthorized(address)`
+    /// and selector `0x8e4a23d6`
+    #[derive(
+        Clone,
+        ::ethers::contract::EthError,
+        ::ethers::contract::EthDisplay,
+        serde::Serialize,
+        serde::Deserialize,
+        Default,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+    )]
+    #[etherror(name = "Unauthorized", abi = "Unauthorized(address)")]
+    pub struct Unauthorized {
+        pub caller: ::ethers::core::types::Address,
+    }
+    /// Container type for all of the contract's custom errors
+    #[derive(
+        Clone,
+        ::ethers::contract::EthAbiType,
+        serde::Serialize,
+        serde::Deserialize,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+    )]
+    pub enum GuardedCounterErrors {
+        Overflow(Overflow),
+        Unauthorized(Unauthorized),
+        /// The standard solidity revert string, with selector
+        /// Error(string) -- 0x08c379a0
+        RevertString(::std::string::String),
+    }
+    impl ::ethers::core::abi::AbiDecode for GuardedCounterErrors {
+        fn decode(
+            data: impl AsRef<[u8]>,
+        ) -> ::core::result::Result<Self, ::ethers::core::abi::AbiError> {
+            let data = data.as_ref();
+            if let Ok(decoded) =
+                <::std::string::String as ::ethers::core::abi::AbiDecode>::decode(data)
+            {
+                return Ok(Self::RevertString(decoded));
+            }
+            if let Ok(decoded) = <Overflow as ::ethers::core::abi::AbiDecode>::decode(data) {
+                return Ok(Self::Overflow(decoded));
+            }
+            if let Ok(decoded) = <Unauthorized as ::ethers::core::abi::AbiDecode>::decode(data) {
+                return Ok(Self::Unauthorized(decoded));
+            }
+            Err(::ethers::core::abi::Error::InvalidData.into())
+        }
+    }
+    impl ::ethers::core::abi::AbiEncode for GuardedCounterErrors {
+        fn encode(self) -> ::std::vec::Vec<u8> {
+            match self {
+                Self::Overflow(element) => ::ethers::core::abi::AbiEncode::encode(element),
+                Self::Unauthorized(element) => ::ethers::core::abi::AbiEncode::encode(element),
+                Self::RevertString(s) => ::ethers::core::abi::AbiEncode::encode(s),
+            }
+        }
+    }
+    impl ::ethers::contract::ContractRevert for GuardedCounterErrors {
+        fn valid_selector(selector: [u8; 4]) -> bool {
+            match selector {
+                [0x08, 0xc3, 0x79, 0xa0] => true,
+                _ if selector == <Overflow as ::ethers::contract::EthError>::selector() => true,
+                _ if selector == <Unauthorized as ::ethers::contract::EthError>::selector() => true,
+                _ => false,
+            }
+        }
+    }
+    impl ::core::fmt::Display for GuardedCounterErrors {
+        fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+            match self {
+                Self::Overflow(element) => ::core::fmt::Display::fmt(element, f),
+                Self::Unauthorized(element) => ::core::fmt::Display::fmt(element, f),
+                Self::RevertString(s) => ::core::fmt::Display::fmt(s, f),
+            }
+        }
+    }
+    impl ::core::convert::From<::std::string::String> for GuardedCounterErrors {
+        fn from(value: String) -> Self {
+            Self::RevertString(value)
+        }
+    }
+    impl ::core::convert::From<Overflow> for GuardedCounterErrors {
+        fn from(value: Overflow) -> Self {
+            Self::Overflow(value)
+        }
+    }
+    impl ::core::convert::From<Unauthorized> for GuardedCounterErrors {
+        fn from(value: Unauthorized) -> Self {
+            Self::Unauthorized(value)
+        }
+    }
+    #[derive(
+        Clone,
+        ::ethers::contract::EthEvent,
+        ::ethers::contract::EthDisplay,
+        serde::Serialize,
+        serde::Deserialize,
+        Default,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+    )]
+    #[ethevent(name = "Incremented", abi = "Incremented(uint256)")]
+    pub struct IncrementedFilter {
+        pub new_number: ::ethers::core::types::U256,
+    }
+    #[derive(
+        Clone,
+        ::ethers::contract::EthEvent,
+        ::ethers::contract::EthDisplay,
+        serde::Serialize,
+        serde::Deserialize,
+        Default,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+    )]
+    #[ethevent(name = "NumberSet", abi = "NumberSet(uint256)")]
+    pub struct NumberSetFilter {
+        pub new_number: ::ethers::core::types::U256,
+    }
+    #[derive(
+        Clone,
+        ::ethers::contract::EthEvent,
+        ::ethers::contract::EthDisplay,
+        serde::Serialize,
+        serde::Deserialize,
+        Default,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+    )]
+    #[ethevent(
+        name = "OwnershipTransferred",
+        abi = "OwnershipTransferred(address,address)"
+    )]
+    pub struct OwnershipTransferredFilter {
+        #[ethevent(indexed)]
+        pub previous_owner: ::ethers::core::types::Address,
+        #[ethevent(indexed)]
+        pub new_owner: ::ethers::core::types::Address,
+    }
+    #[derive(
+        Clone,
+        ::ethers::contract::EthEvent,
+        ::ethers::contract::EthDisplay,
+        serde::Serialize,
+        serde::Deserialize,
+        Default,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+    )]
+    #[ethevent(name = "RoleGranted", abi = "RoleGranted(bytes32,address)")]
+    pub struct RoleGrantedFilter {
+        #[ethevent(indexed)]
+        pub role: [u8; 32],
+        #[ethevent(indexed)]
+        pub account: ::ethers::core::types::Address,
+    }
+    #[derive(
+        Clone,
+        ::ethers::contract::EthEvent,
+        ::ethers::contract::EthDisplay,
+        serde::Serialize,
+        serde::Deserialize,
+        Default,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+    )]
+    #[ethevent(name = "RoleRevoked", abi = "RoleRevoked(bytes32,address)")]
+    pub struct RoleRevokedFilter {
+        #[ethevent(indexed)]
+        pub role: [u8; 32],
+        #[ethevent(indexed)]
+        pub account: ::ethers::core::types::Address,
+    }
+    /// Container type for all of the contract's events
+    #[derive(
+        Clone,
+        ::ethers::contract::EthAbiType,
+        serde::Serialize,
+        serde::Deserialize,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+    )]
+    pub enum GuardedCounterEvents {
+        IncrementedFilter(IncrementedFilter),
+        NumberSetFilter(NumberSetFilter),
+        OwnershipTransferredFilter(OwnershipTransferredFilter),
+        RoleGrantedFilter(RoleGrantedFilter),
+        RoleRevokedFilter(RoleRevokedFilter),
+    }
+    impl ::ethers::contract::EthLogDecode for GuardedCounterEvents {
+        fn decode_log(
+            log: &::ethers::core::abi::RawLog,
+        ) -> ::core::result::Result<Self, ::ethers::core::abi::Error> {
+            if let Ok(decoded) = IncrementedFilter::decode_log(log) {
+                return Ok(GuardedCounterEvents::IncrementedFilter(decoded));
+            }
+            if let Ok(decoded) = NumberSetFilter::decode_log(log) {
+                return Ok(GuardedCounterEvents::NumberSetFilter(decoded));
+            }
+            if let Ok(decoded) = OwnershipTransferredFilter::decode_log(log) {
+                return Ok(GuardedCounterEvents::OwnershipTransferredFilter(decoded));
+            }
+            if let Ok(decoded) = RoleGrantedFilter::decode_log(log) {
+                return Ok(GuardedCounterEvents::RoleGrantedFilter(decoded));
+            }
+            if let Ok(decoded) = RoleRevokedFilter::decode_log(log) {
+                return Ok(GuardedCounterEvents::RoleRevokedFilter(decoded));
+            }
+            Err(::ethers::core::abi::Error::InvalidData)
+        }
+    }
+    impl ::core::fmt::Display for GuardedCounterEvents {
+        fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+            match self {
+                Self::IncrementedFilter(element) => ::core::fmt::Display::fmt(element, f),
+                Self::NumberSetFilter(element) => ::core::fmt::Display::fmt(element, f),
+                Self::OwnershipTransferredFilter(element) => ::core::fmt::Display::fmt(element, f),
+                Self::RoleGrantedFilter(element) => ::core::fmt::Display::fmt(element, f),
+                Self::RoleRevokedFilter(element) => ::core::fmt::Display::fmt(element, f),
+            }
+        }
+    }
+    impl ::core::convert::From<IncrementedFilter> for GuardedCounterEvents {
+        fn from(value: IncrementedFilter) -> Self {
+            Self::IncrementedFilter(value)
+        }
+    }
+    impl ::core::convert::From<NumberSetFilter> for GuardedCounterEvents {
+        fn from(value: NumberSetFilter) -> Self {
+            Self::NumberSetFilter(value)
+        }
+    }
+    impl ::core::convert::From<OwnershipTransferredFilter> for GuardedCounterEvents {
+        fn from(value: OwnershipTransferredFilter) -> Self {
+            Self::OwnershipTransferredFilter(value)
+        }
+    }
+    impl ::core::convert::From<RoleGrantedFilter> for GuardedCounterEvents {
+        fn from(value: RoleGrantedFilter) -> Self {
+            Self::RoleGrantedFilter(value)
+        }
+    }
+    impl ::core::convert::From<RoleRevokedFilter> for GuardedCounterEvents {
+        fn from(value: RoleRevokedFilter) -> Self {
+            Self::RoleRevokedFilter(value)
+        }
+    }
+    /// Container type for all input parameters for the `INCREMENTER_ROLE`
+    /// function with signature `INCREMENTER_ROLE()` and selector `0x5f1e2e83`
+    #[derive(
+        Clone,
+        ::ethers::contract::EthCall,
+        ::ethers::contract::EthDisplay,
+        serde::Serialize,
+        serde::Deserialize,
+        Default,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+    )]
+    #[ethcall(name = "INCREMENTER_ROLE", abi = "INCREMENTER_ROLE()")]
+    pub struct IncrementerRoleCall;
+    /// Container type for all input parameters for the `SETTER_ROLE` function
+    /// with signature `SETTER_ROLE()` and selector `0xa2011b3f`
+    #[derive(
+        Clone,
+        ::ethers::contract::EthCall,
+        ::ethers::contract::EthDisplay,
+        serde::Serialize,
+        serde::Deserialize,
+        Default,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+    )]
+    #[ethcall(name = "SETTER_ROLE", abi = "SETTER_ROLE()")]
+    pub struct SetterRoleCall;
+    /// Container type for all input parameters for the `grantRole` function
+    /// with signature `grantRole(bytes32,address)` and selector `0x2f2ff15d`
+    #[derive(
+        Clone,
+        ::ethers::contract::EthCall,
+        ::ethers::contract::EthDisplay,
+        serde::Serialize,
+        serde::Deserialize,
+        Default,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+    )]
+    #[ethcall(name = "grantRole", abi = "grantRole(bytes32,address)")]
+    pub struct GrantRoleCall {
+        pub role: [u8; 32],
+        pub account: ::ethers::core::types::Address,
+    }
+    /// Container type for all input parameters for the `hasRole` function with
+    /// signature `hasRole(bytes32,address)` and selector `0x91d14854`
+    #[derive(
+        Clone,
+        ::ethers::contract::EthCall,
+        ::ethers::contract::EthDisplay,
+        serde::Serialize,
+        serde::Deserialize,
+        Default,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+    )]
+    #[ethcall(name = "hasRole", abi = "hasRole(bytes32,address)")]
+    pub struct HasRoleCall(pub [u8; 32], pub ::ethers::core::types::Address);
+    /// Container type for all input parameters for the `increment` function
+    /// with signature `increment()` and selector `0xd09de08a`
+    #[derive(
+        Clone,
+        ::ethers::contract::EthCall,
+        ::ethers::contract::EthDisplay,
+        serde::Serialize,
+        serde::Deserialize,
+        Default,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+    )]
+    #[ethcall(name = "increment", abi = "increment()")]
+    pub struct IncrementCall;
+    /// Container type for all input parameters for the `maxNumber` function
+    /// with signature `maxNumber()` and selector `0x3a4f6999`
+    #[derive(
+        Clone,
+        ::ethers::contract::EthCall,
+        ::ethers::contract::EthDisplay,
+        serde::Serialize,
+        serde::Deserialize,
+        Default,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+    )]
+    #[ethcall(name = "maxNumber", abi = "maxNumber()")]
+    pub struct MaxNumberCall;
+    /// Container type for all input parameters for the `number` function with
+    /// signature `number()` and selector `0x8381f58a`
+    #[derive(
+        Clone,
+        ::ethers::contract::EthCall,
+        ::ethers::contract::EthDisplay,
+        serde::Serialize,
+        serde::Deserialize,
+        Default,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+    )]
+    #[ethcall(name = "number", abi = "number()")]
+    pub struct NumberCall;
+    /// Container type for all input parameters for the `owner` function with
+    /// signature `owner()` and selector `0x8da5cb5b`
+    #[derive(
+        Clone,
+        ::ethers::contract::EthCall,
+        ::ethers::contract::EthDisplay,
+        serde::Serialize,
+        serde::Deserialize,
+        Default,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+    )]
+    #[ethcall(name = "owner", abi = "owner()")]
+    pub struct OwnerCall;
+    /// Container type for all input parameters for the `revokeRole` function
+    /// with signature `revokeRole(bytes32,address)` and selector `0xd547741f`
+    #[derive(
+        Clone,
+        ::ethers::contract::EthCall,
+        ::ethers::contract::EthDisplay,
+        serde::Serialize,
+        serde::Deserialize,
+        Default,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+    )]
+    #[ethcall(name = "revokeRole", abi = "revokeRole(bytes32,address)")]
+    pub struct RevokeRoleCall {
+        pub role: [u8; 32],
+        pub account: ::ethers::core::types::Address,
+    }
+    /// Container type for all input parameters for the `setNumber` function
+    /// with signature `setNumber(uint256)` and selector `0x3fb5c1cb`
+    #[derive(
+        Clone,
+        ::ethers::contract::EthCall,
+        ::ethers::contract::EthDisplay,
+        serde::Serialize,
+        serde::Deserialize,
+        Default,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+    )]
+    #[ethcall(name = "setNumber", abi = "setNumber(uint256)")]
+    pub struct SetNumberCall {
+        pub new_number: ::ethers::core::types::U256,
+    }
+    /// Container type for all input parameters for the `transferOwnership`
+    /// function with signature `transferOwnership(address)` and selector
+    /// `0xf2fde38b`
+    #[derive(
+        Clone,
+        ::ethers::contract::EthCall,
+        ::ethers::contract::EthDisplay,
+        serde::Serialize,
+        serde::Deserialize,
+        Default,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+    )]
+    #[ethcall(name = "transferOwnership", abi = "transferOwnership(address)")]
+    pub struct TransferOwnershipCall {
+        pub new_owner: ::ethers::core::types::Address,
+    }
+    /// Container type for all of the contract's call
+    #[derive(
+        Clone,
+        ::ethers::contract::EthAbiType,
+        serde::Serialize,
+        serde::Deserialize,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+    )]
+    pub enum GuardedCounterCalls {
+        IncrementerRole(IncrementerRoleCall),
+        SetterRole(SetterRoleCall),
+        GrantRole(GrantRoleCall),
+        HasRole(HasRoleCall),
+        Increment(IncrementCall),
+        MaxNumber(MaxNumberCall),
+        Number(NumberCall),
+        Owner(OwnerCall),
+        RevokeRole(RevokeRoleCall),
+        SetNumber(SetNumberCall),
+        TransferOwnership(TransferOwnershipCall),
+    }
+    impl ::ethers::core::abi::AbiDecode for GuardedCounterCalls {
+        fn decode(
+            data: impl AsRef<[u8]>,
+        ) -> ::core::result::Result<Self, ::ethers::core::abi::AbiError> {
+            let data = data.as_ref();
+            if let Ok(decoded) =
+                <IncrementerRoleCall as ::ethers::core::abi::AbiDecode>::decode(data)
+            {
+                return Ok(Self::IncrementerRole(decoded));
+            }
+            if let Ok(decoded) = <SetterRoleCall as ::ethers::core::abi::AbiDecode>::decode(data) {
+                return Ok(Self::SetterRole(decoded));
+            }
+            if let Ok(decoded) = <GrantRoleCall as ::ethers::core::abi::AbiDecode>::decode(data) {
+                return Ok(Self::GrantRole(decoded));
+            }
+            if let Ok(decoded) = <HasRoleCall as ::ethers::core::abi::AbiDecode>::decode(data) {
+                return Ok(Self::HasRole(decoded));
+            }
+            if let Ok(decoded) = <IncrementCall as ::ethers::core::abi::AbiDecode>::decode(data) {
+                return Ok(Self::Increment(decoded));
+            }
+            if let Ok(decoded) = <MaxNumberCall as ::ethers::core::abi::AbiDecode>::decode(data) {
+                return Ok(Self::MaxNumber(decoded));
+            }
+            if let Ok(decoded) = <NumberCall as ::ethers::core::abi::AbiDecode>::decode(data) {
+                return Ok(Self::Number(decoded));
+            }
+            if let Ok(decoded) = <OwnerCall as ::ethers::core::abi::AbiDecode>::decode(data) {
+                return Ok(Self::Owner(decoded));
+            }
+            if let Ok(decoded) = <RevokeRoleCall as ::ethers::core::abi::AbiDecode>::decode(data) {
+                return Ok(Self::RevokeRole(decoded));
+            }
+            if let Ok(decoded) = <SetNumberCall as ::ethers::core::abi::AbiDecode>::decode(data) {
+                return Ok(Self::SetNumber(decoded));
+            }
+            if let Ok(decoded) =
+                <TransferOwnershipCall as ::ethers::core::abi::AbiDecode>::decode(data)
+            {
+                return Ok(Self::TransferOwnership(decoded));
+            }
+            Err(::ethers::core::abi::Error::InvalidData.into())
+        }
+    }
+    impl ::ethers::core::abi::AbiEncode for GuardedCounterCalls {
+        fn encode(self) -> Vec<u8> {
+            match self {
+                Self::IncrementerRole(element) => ::ethers::core::abi::AbiEncode::encode(element),
+                Self::SetterRole(element) => ::ethers::core::abi::AbiEncode::encode(element),
+                Self::GrantRole(element) => ::ethers::core::abi::AbiEncode::encode(element),
+                Self::HasRole(element) => ::ethers::core::abi::AbiEncode::encode(element),
+                Self::Increment(element) => ::ethers::core::abi::AbiEncode::encode(element),
+                Self::MaxNumber(element) => ::ethers::core::abi::AbiEncode::encode(element),
+                Self::Number(element) => ::ethers::core::abi::AbiEncode::encode(element),
+                Self::Owner(element) => ::ethers::core::abi::AbiEncode::encode(element),
+                Self::RevokeRole(element) => ::ethers::core::abi::AbiEncode::encode(element),
+                Self::SetNumber(element) => ::ethers::core::abi::AbiEncode::encode(element),
+                Self::TransferOwnership(element) => ::ethers::core::abi::AbiEncode::encode(element),
+            }
+        }
+    }
+    impl ::core::fmt::Display for GuardedCounterCalls {
+        fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+            match self {
+                Self::IncrementerRole(element) => ::core::fmt::Display::fmt(element, f),
+                Self::SetterRole(element) => ::core::fmt::Display::fmt(element, f),
+                Self::GrantRole(element) => ::core::fmt::Display::fmt(element, f),
+                Self::HasRole(element) => ::core::fmt::Display::fmt(element, f),
+                Self::Increment(element) => ::core::fmt::Display::fmt(element, f),
+                Self::MaxNumber(element) => ::core::fmt::Display::fmt(element, f),
+                Self::Number(element) => ::core::fmt::Display::fmt(element, f),
+                Self::Owner(element) => ::core::fmt::Display::fmt(element, f),
+                Self::RevokeRole(element) => ::core::fmt::Display::fmt(element, f),
+                Self::SetNumber(element) => ::core::fmt::Display::fmt(element, f),
+                Self::TransferOwnership(element) => ::core::fmt::Display::fmt(element, f),
+            }
+        }
+    }
+    impl ::core::convert::From<IncrementerRoleCall> for GuardedCounterCalls {
+        fn from(value: IncrementerRoleCall) -> Self {
+            Self::IncrementerRole(value)
+        }
+    }
+    impl ::core::convert::From<SetterRoleCall> for GuardedCounterCalls {
+        fn from(value: SetterRoleCall) -> Self {
+            Self::SetterRole(value)
+        }
+    }
+    impl ::core::convert::From<GrantRoleCall> for GuardedCounterCalls {
+        fn from(value: GrantRoleCall) -> Self {
+            Self::GrantRole(value)
+        }
+    }
+    impl ::core::convert::From<HasRoleCall> for GuardedCounterCalls {
+        fn from(value: HasRoleCall) -> Self {
+            Self::HasRole(value)
+        }
+    }
+    impl ::core::convert::From<IncrementCall> for GuardedCounterCalls {
+        fn from(value: IncrementCall) -> Self {
+            Self::Increment(value)
+        }
+    }
+    impl ::core::convert::From<MaxNumberCall> for GuardedCounterCalls {
+        fn from(value: MaxNumberCall) -> Self {
+            Self::MaxNumber(value)
+        }
+    }
+    impl ::core::convert::From<NumberCall> for GuardedCounterCalls {
+        fn from(value: NumberCall) -> Self {
+            Self::Number(value)
+        }
+    }
+    impl ::core::convert::From<OwnerCall> for GuardedCounterCalls {
+        fn from(value: OwnerCall) -> Self {
+            Self::Owner(value)
+        }
+    }
+    impl ::core::convert::From<RevokeRoleCall> for GuardedCounterCalls {
+        fn from(value: RevokeRoleCall) -> Self {
+            Self::RevokeRole(value)
+        }
+    }
+    impl ::core::convert::From<SetNumberCall> for GuardedCounterCalls {
+        fn from(value: SetNumberCall) -> Self {
+            Self::SetNumber(value)
+        }
+    }
+    impl ::core::convert::From<TransferOwnershipCall> for GuardedCounterCalls {
+        fn from(value: TransferOwnershipCall) -> Self {
+            Self::TransferOwnership(value)
+        }
+    }
+    /// Container type for all return fields from the `INCREMENTER_ROLE`
+    /// function with signature `INCREMENTER_ROLE()` and selector `0x5f1e2e83`
+    #[derive(
+        Clone,
+        ::ethers::contract::EthAbiType,
+        ::ethers::contract::EthAbiCodec,
+        serde::Serialize,
+        serde::Deserialize,
+        Default,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+    )]
+    pub struct IncrementerRoleReturn(pub [u8; 32]);
+    /// Container type for all return fields from the `SETTER_ROLE` function
+    /// with signature `SETTER_ROLE()` and selector `0xa2011b3f`
+    #[derive(
+        Clone,
+        ::ethers::contract::EthAbiType,
+        ::ethers::contract::EthAbiCodec,
+        serde::Serialize,
+        serde::Deserialize,
+        Default,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+    )]
+    pub struct SetterRoleReturn(pub [u8; 32]);
+    /// Container type for all return fields from the `hasRole` function with
+    /// signature `hasRole(bytes32,address)` and selector `0x91d14854`
+    #[derive(
+        Clone,
+        ::ethers::contract::EthAbiType,
+        ::ethers::contract::EthAbiCodec,
+        serde::Serialize,
+        serde::Deserialize,
+        Default,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+    )]
+    pub struct HasRoleReturn(pub bool);
+    /// Container type for all return fields from the `maxNumber` function with
+    /// signature `maxNumber()` and selector `0x3a4f6999`
+    #[derive(
+        Clone,
+        ::ethers::contract::EthAbiType,
+        ::ethers::contract::EthAbiCodec,
+        serde::Serialize,
+        serde::Deserialize,
+        Default,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+    )]
+    pub struct MaxNumberReturn(pub ::ethers::core::types::U256);
+    /// Container type for all return fields from the `number` function with
+    /// signature `number()` and selector `0x8381f58a`
+    #[derive(
+        Clone,
+        ::ethers::contract::EthAbiType,
+        ::ethers::contract::EthAbiCodec,
+        serde::Serialize,
+        serde::Deserialize,
+        Default,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+    )]
+    pub struct NumberReturn(pub ::ethers::core::types::U256);
+    /// Container type for all return fields from the `owner` function with
+    /// signature `owner()` and selector `0x8da5cb5b`
+    #[derive(
+        Clone,
+        ::ethers::contract::EthAbiType,
+        ::ethers::contract::EthAbiCodec,
+        serde::Serialize,
+        serde::Deserialize,
+        Default,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+    )]
+    pub struct OwnerReturn(pub ::ethers::core::types::Address);
+}
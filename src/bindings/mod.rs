@@ -1,3 +1,15 @@
 #[allow(clippy::all)]
 #[rustfmt::skip]
+pub mod constant_product_pool;
+#[allow(clippy::all)]
+#[rustfmt::skip]
+pub mod guarded_counter;
+#[allow(clippy::all)]
+#[rustfmt::skip]
 pub mod modified_counter;
+#[allow(clippy::all)]
+#[rustfmt::skip]
+pub mod price_oracle;
+#[allow(clippy::all)]
+#[rustfmt::skip]
+pub mod token;
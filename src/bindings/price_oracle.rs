@@ -0,0 +1,563 @@
+pub use price_oracle::*;
+/// This module was auto-generated with ethers-rs Abigen.
+/// More information at: <https://github.com/gakonst/ethers-rs>
+#[allow(
+    clippy::enum_variant_names,
+    clippy::too_many_arguments,
+    clippy::upper_case_acronyms,
+    clippy::type_complexity,
+    dead_code,
+    non_camel_case_types
+)]
+pub mod price_oracle {
+    #[allow(deprecated)]
+    fn __abi() -> ::ethers::core::abi::Abi {
+        ::ethers::core::abi::ethabi::Contract {
+            constructor: ::core::option::Option::Some(::ethers::core::abi::ethabi::Constructor {
+                inputs: ::std::vec![::ethers::core::abi::ethabi::Param {
+                    name: ::std::borrow::ToOwned::to_owned("initialPrice"),
+                    kind: ::ethers::core::abi::ethabi::ParamType::Uint(256usize),
+                    internal_type: ::core::option::Option::Some(::std::borrow::ToOwned::to_owned(
+                        "uint256"
+                    ),),
+                },],
+            }),
+            functions: ::core::convert::From::from([
+                (
+                    ::std::borrow::ToOwned::to_owned("decimals"),
+                    ::std::vec![::ethers::core::abi::ethabi::Function {
+                        name: ::std::borrow::ToOwned::to_owned("decimals"),
+                        inputs: ::std::vec![],
+                        outputs: ::std::vec![::ethers::core::abi::ethabi::Param {
+                            name: ::std::string::String::new(),
+                            kind: ::ethers::core::abi::ethabi::ParamType::Uint(8usize),
+                            internal_type: ::core::option::Option::Some(
+                                ::std::borrow::ToOwned::to_owned("uint8"),
+                            ),
+                        },],
+                        constant: ::core::option::Option::None,
+                        state_mutability: ::ethers::core::abi::ethabi::StateMutability::View,
+                    },],
+                ),
+                (
+                    ::std::borrow::ToOwned::to_owned("price"),
+                    ::std::vec![::ethers::core::abi::ethabi::Function {
+                        name: ::std::borrow::ToOwned::to_owned("price"),
+                        inputs: ::std::vec![],
+                        outputs: ::std::vec![::ethers::core::abi::ethabi::Param {
+                            name: ::std::string::String::new(),
+                            kind: ::ethers::core::abi::ethabi::ParamType::Uint(256usize,),
+                            internal_type: ::core::option::Option::Some(
+                                ::std::borrow::ToOwned::to_owned("uint256"),
+                            ),
+                        },],
+                        constant: ::core::option::Option::None,
+                        state_mutability: ::ethers::core::abi::ethabi::StateMutability::View,
+                    },],
+                ),
+                (
+                    ::std::borrow::ToOwned::to_owned("setPrice"),
+                    ::std::vec![::ethers::core::abi::ethabi::Function {
+                        name: ::std::borrow::ToOwned::to_owned("setPrice"),
+                        inputs: ::std::vec![::ethers::core::abi::ethabi::Param {
+                            name: ::std::borrow::ToOwned::to_owned("newPrice"),
+                            kind: ::ethers::core::abi::ethabi::ParamType::Uint(256usize,),
+                            internal_type: ::core::option::Option::Some(
+                                ::std::borrow::ToOwned::to_owned("uint256"),
+                            ),
+                        },],
+                        outputs: ::std::vec![],
+                        constant: ::core::option::Option::None,
+                        state_mutability: ::ethers::core::abi::ethabi::StateMutability::NonPayable,
+                    },],
+                ),
+                (
+                    ::std::borrow::ToOwned::to_owned("step"),
+                    ::std::vec![::ethers::core::abi::ethabi::Function {
+                        name: ::std::borrow::ToOwned::to_owned("step"),
+                        inputs: ::std::vec![],
+                        outputs: ::std::vec![::ethers::core::abi::ethabi::Param {
+                            name: ::std::string::String::new(),
+                            kind: ::ethers::core::abi::ethabi::ParamType::Uint(256usize,),
+                            internal_type: ::core::option::Option::Some(
+                                ::std::borrow::ToOwned::to_owned("uint256"),
+                            ),
+                        },],
+                        constant: ::core::option::Option::None,
+                        state_mutability: ::ethers::core::abi::ethabi::StateMutability::View,
+                    },],
+                ),
+                (
+                    ::std::borrow::ToOwned::to_owned("updater"),
+                    ::std::vec![::ethers::core::abi::ethabi::Function {
+                        name: ::std::borrow::ToOwned::to_owned("updater"),
+                        inputs: ::std::vec![],
+                        outputs: ::std::vec![::ethers::core::abi::ethabi::Param {
+                            name: ::std::string::String::new(),
+                            kind: ::ethers::core::abi::ethabi::ParamType::Address,
+                            internal_type: ::core::option::Option::Some(
+                                ::std::borrow::ToOwned::to_owned("address"),
+                            ),
+                        },],
+                        constant: ::core::option::Option::None,
+                        state_mutability: ::ethers::core::abi::ethabi::StateMutability::View,
+                    },],
+                ),
+            ]),
+            events: ::core::convert::From::from([(
+                ::std::borrow::ToOwned::to_owned("PriceUpdated"),
+                ::std::vec![::ethers::core::abi::ethabi::Event {
+                    name: ::std::borrow::ToOwned::to_owned("PriceUpdated"),
+                    inputs: ::std::vec![
+                        ::ethers::core::abi::ethabi::EventParam {
+                            name: ::std::borrow::ToOwned::to_owned("price"),
+                            kind: ::ethers::core::abi::ethabi::ParamType::Uint(256usize,),
+                            indexed: false,
+                        },
+                        ::ethers::core::abi::ethabi::EventParam {
+                            name: ::std::borrow::ToOwned::to_owned("step"),
+                            kind: ::ethers::core::abi::ethabi::ParamType::Uint(256usize,),
+                            indexed: false,
+                        },
+                    ],
+                    anonymous: false,
+                },],
+            )]),
+            errors: ::core::convert::From::from([(
+                ::std::borrow::ToOwned::to_owned("NotUpdater"),
+                ::std::vec![::ethers::core::abi::ethabi::AbiError {
+                    name: ::std::borrow::ToOwned::to_owned("NotUpdater"),
+                    inputs: ::std::vec![::ethers::core::abi::ethabi::Param {
+                        name: ::std::borrow::ToOwned::to_owned("caller"),
+                        kind: ::ethers::core::abi::ethabi::ParamType::Address,
+                        internal_type: ::core::option::Option::Some(
+                            ::std::borrow::ToOwned::to_owned("address"),
+                        ),
+                    },],
+                },],
+            )]),
+            receive: false,
+            fallback: false,
+        }
+    }
+    /// The parsed JSON ABI of the contract.
+    pub static PRICEORACLE_ABI: ::ethers::contract::Lazy<::ethers::core::abi::Abi> =
+        ::ethers::contract::Lazy::new(__abi);
+    #[rustfmt::skip]
+    const __BYTECODE: &[u8] = b"`\x80`@R4a\0\xE2Wa\x04g8\x03\x80a\x02\xC0Ra\x04ga\x04\09a\x02\xC0Q` \x90\x10a\0fWa\x04\0Q`\x80R`\x80Q`\0Ua\x04\0a\x02\xC0Q\x01a\x02\xE0Ra\x03\ta\x01^a\x02\xE0Q93a\x02\xE0Q`\xDD\x01R3a\x02\xE0Qa\x01=\x01Ra\x03\ta\x02\xE0Q\xF3[\x7F\x08\xC3y\xA0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0`\0R` `\x04R`\"`$R\x7FABI decoding: tuple data too sho`DR\x7Frt\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0`dR`\x84`\0\xFD[\x7F\x08\xC3y\xA0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0`\0R` `\x04R`\"`$R\x7FEther sent to non-payable functi`DR\x7Fon\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0`dR`\x84`\0\xFD`\x80`@R4a\x02\x8DW`\x046\x10a\0OW`\x005`\xE0\x1C\x80c1<\xE5g\x14a\0\xCBW\x80c\x91\xB7\xF5\xED\x14a\x01(W\x80c\xA05\xB1\xFE\x14a\x01\x08W\x80c\xDF\x03L\xD0\x14a\0\xDAW\x80c\xE2_\xE1u\x14a\x01\x18W[\x7F\x08\xC3y\xA0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0`\0R` `\x04R`5`$R\x7FContract does not have fallback `DR\x7Fnor receive functions\0\0\0\0\0\0\0\0\0\0\0`dR`\x84`\0\xFD[P`\x12a\x03\0R` a\x03\0\xF3\0[P\x7F\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0a\x03\0R` a\x03\0\xF3\0[P`\0Ta\x03\0R` a\x03\0\xF3\0[P`\x01Ta\x03\0R` a\x03\0\xF3\0[P`\x046\x03` \x90\x10a\x02\x11W`\x045`\x80R\x7F\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\x003\x14a\x01\x90W\x7F\xD7\x04\x1E\x11\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0`\0R3`\x04R`$`\0\xFD[`\x80Q`\0U`\x01`\x01T\x81\x81\x01\x81\x81\x10a\x01\xE2W\x91PP`\x01U`\x80Qa\x03\0R`\x01Ta\x03 R\x7F\x94\\\x1CN\x99\xAA\x89\xF6H\xFB\xFE=\xF4q\xB9\x16\xF7\x19\xE1m\x96\x0F\xCE\xC0s}MV\xBDih8`@a\x03\0\xA1\0[\x7FNH{q\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0`\0R`\x11`\x04R`$`\0\xFD[\x7F\x08\xC3y\xA0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0`\0R` `\x04R`\"`$R\x7FABI decoding: tuple data too sho`DR\x7Frt\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0`dR`\x84`\0\xFD[\x7F\x08\xC3y\xA0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0`\0R` `\x04R`\"`$R\x7FEther sent to non-payable functi`DR\x7Fon\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0`dR`\x84`\0\xFD";
+    /// The bytecode of the contract.
+    pub static PRICEORACLE_BYTECODE: ::ethers::core::types::Bytes =
+        ::ethers::core::types::Bytes::from_static(__BYTECODE);
+    #[rustfmt::skip]
+    const __DEPLOYED_BYTECODE: &[u8] = b"`\x80`@R4a\x02\x8DW`\x046\x10a\0OW`\x005`\xE0\x1C\x80c1<\xE5g\x14a\0\xCBW\x80c\x91\xB7\xF5\xED\x14a\x01(W\x80c\xA05\xB1\xFE\x14a\x01\x08W\x80c\xDF\x03L\xD0\x14a\0\xDAW\x80c\xE2_\xE1u\x14a\x01\x18W[\x7F\x08\xC3y\xA0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0`\0R` `\x04R`5`$R\x7FContract does not have fallback `DR\x7Fnor receive functions\0\0\0\0\0\0\0\0\0\0\0`dR`\x84`\0\xFD[P`\x12a\x03\0R` a\x03\0\xF3\0[P\x7F\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0a\x03\0R` a\x03\0\xF3\0[P`\0Ta\x03\0R` a\x03\0\xF3\0[P`\x01Ta\x03\0R` a\x03\0\xF3\0[P`\x046\x03` \x90\x10a\x02\x11W`\x045`\x80R\x7F\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\x003\x14a\x01\x90W\x7F\xD7\x04\x1E\x11\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0`\0R3`\x04R`$`\0\xFD[`\x80Q`\0U`\x01`\x01T\x81\x81\x01\x81\x81\x10a\x01\xE2W\x91PP`\x01U`\x80Qa\x03\0R`\x01Ta\x03 R\x7F\x94\\\x1CN\x99\xAA\x89\xF6H\xFB\xFE=\xF4q\xB9\x16\xF7\x19\xE1m\x96\x0F\xCE\xC0s}MV\xBDih8`@a\x03\0\xA1\0[\x7FNH{q\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0`\0R`\x11`\x04R`$`\0\xFD[\x7F\x08\xC3y\xA0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0`\0R` `\x04R`\"`$R\x7FABI decoding: tuple data too sho`DR\x7Frt\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0`dR`\x84`\0\xFD[\x7F\x08\xC3y\xA0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0`\0R` `\x04R`\"`$R\x7FEther sent to non-payable functi`DR\x7Fon\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0`dR`\x84`\0\xFD";
+    /// The deployed bytecode of the contract.
+    pub static PRICEORACLE_DEPLOYED_BYTECODE: ::ethers::core::types::Bytes =
+        ::ethers::core::types::Bytes::from_static(__DEPLOYED_BYTECODE);
+    pub struct PriceOracle<M>(::ethers::contract::Contract<M>);
+    impl<M> ::core::clone::Clone for PriceOracle<M> {
+        fn clone(&self) -> Self {
+            Self(::core::clone::Clone::clone(&self.0))
+        }
+    }
+    impl<M> ::core::ops::Deref for PriceOracle<M> {
+        type Target = ::ethers::contract::Contract<M>;
+        fn deref(&self) -> &Self::Target {
+            &self.0
+        }
+    }
+    impl<M> ::core::ops::DerefMut for PriceOracle<M> {
+        fn deref_mut(&mut self) -> &mut Self::Target {
+            &mut self.0
+        }
+    }
+    impl<M> ::core::fmt::Debug for PriceOracle<M> {
+        fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+            f.debug_tuple(::core::stringify!(PriceOracle))
+                .field(&self.address())
+                .finish()
+        }
+    }
+    impl<M: ::ethers::providers::Middleware> PriceOracle<M> {
+        /// Creates a new contract instance with the specified `ethers` client
+        /// at `address`. The contract derefs to a `ethers::Contract`
+        /// object.
+        pub fn new<T: Into<::ethers::core::types::Address>>(
+            address: T,
+            client: ::std::sync::Arc<M>,
+        ) -> Self {
+            Self(::ethers::contract::Contract::new(
+                address.into(),
+                PRICEORACLE_ABI.clone(),
+                client,
+            ))
+        }
+        /// Constructs the general purpose `Deployer` instance based on the
+        /// provided constructor arguments and sends it. Returns a new
+        /// instance of a deployer that returns an instance of this contract
+        /// after sending the transaction
+        ///
+        /// Notes:
+        /// - If there are no constructor arguments, you should pass `()` as the
+        ///   argument.
+        /// - The default poll duration is 7 seconds.
+        /// - The default number of confirmations is 1 block.
+        ///
+        ///
+        /// # Example
+        ///
+        /// Generate contract bindings with `abigen!` and deploy a new contract
+        /// instance.
+        ///
+        /// *Note*: this requires a `bytecode` and `abi` object in the
+        /// `greeter.json` artifact.
+        ///
+        /// ```ignore
+        /// # async fn deploy<M: ethers::providers::Middleware>(client: ::std::sync::Arc<M>) {
+        ///     abigen!(Greeter, "../greeter.json");
+        ///
+        ///    let greeter_contract = Greeter::deploy(client, "Hello world!".to_string()).unwrap().send().await.unwrap();
+        ///    let msg = greeter_contract.greet().call().await.unwrap();
+        /// # }
+        /// ```
+        pub fn deploy<T: ::ethers::core::abi::Tokenize>(
+            client: ::std::sync::Arc<M>,
+            constructor_args: T,
+        ) -> ::core::result::Result<
+            ::ethers::contract::builders::ContractDeployer<M, Self>,
+            ::ethers::contract::ContractError<M>,
+        > {
+            let factory = ::ethers::contract::ContractFactory::new(
+                PRICEORACLE_ABI.clone(),
+                PRICEORACLE_BYTECODE.clone().into(),
+                client,
+            );
+            let deployer = factory.deploy(constructor_args)?;
+            let deployer = ::ethers::contract::ContractDeployer::new(deployer);
+            Ok(deployer)
+        }
+        /// Calls the contract's `decimals` (0x313ce567) function
+        pub fn decimals(&self) -> ::ethers::contract::builders::ContractCall<M, u8> {
+            self.0
+                .method_hash([49, 60, 229, 103], ())
+                .expect("method not found (this should never happen)")
+        }
+        /// Calls the contract's `price` (0xa035b1fe) function
+        pub fn price(
+            &self,
+        ) -> ::ethers::contract::builders::ContractCall<M, ::ethers::core::types::U256> {
+            self.0
+                .method_hash([160, 53, 177, 254], ())
+                .expect("method not found (this should never happen)")
+        }
+        /// Calls the contract's `setPrice` (0x91b7f5ed) function
+        pub fn set_price(
+            &self,
+            new_price: ::ethers::core::types::U256,
+        ) -> ::ethers::contract::builders::ContractCall<M, ()> {
+            self.0
+                .method_hash([145, 183, 245, 237], new_price)
+                .expect("method not found (this should never happen)")
+        }
+        /// Calls the contract's `step` (0xe25fe175) function
+        pub fn step(
+            &self,
+        ) -> ::ethers::contract::builders::ContractCall<M, ::ethers::core::types::U256> {
+            self.0
+                .method_hash([226, 95, 225, 117], ())
+                .expect("method not found (this should never happen)")
+        }
+        /// Calls the contract's `updater` (0xdf034cd0) function
+        pub fn updater(
+            &self,
+        ) -> ::ethers::contract::builders::ContractCall<M, ::ethers::core::types::Address> {
+            self.0
+                .method_hash([223, 3, 76, 208], ())
+                .expect("method not found (this should never happen)")
+        }
+        /// Gets the contract's `PriceUpdated` event
+        pub fn price_updated_filter(
+            &self,
+        ) -> ::ethers::contract::builders::Event<::std::sync::Arc<M>, M, PriceUpdatedFilter>
+        {
+            self.0.event()
+        }
+        /// Returns an `Event` builder for all the events of this contract.
+        pub fn events(
+            &self,
+        ) -> ::ethers::contract::builders::Event<::std::sync::Arc<M>, M, PriceUpdatedFilter>
+        {
+            self.0
+                .event_with_filter(::core::default::Default::default())
+        }
+    }
+    impl<M: ::ethers::providers::Middleware> From<::ethers::contract::Contract<M>> for PriceOracle<M> {
+        fn from(contract: ::ethers::contract::Contract<M>) -> Self {
+            Self::new(contract.address(), contract.client())
+        }
+    }
+    /// Custom Error type `NotUpdater` with signature `NotUpdater(address)` and
+    /// selector `0xd7041e11`
+    #[derive(
+        Clone,
+        ::ethers::contract::EthError,
+        ::ethers::contract::EthDisplay,
+        serde::Serialize,
+        serde::Deserialize,
+        Default,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+    )]
+    #[etherror(name = "NotUpdater", abi = "NotUpdater(address)")]
+    pub struct NotUpdater {
+        pub caller: ::ethers::core::types::Address,
+    }
+    #[derive(
+        Clone,
+        ::ethers::contract::EthEvent,
+        ::ethers::contract::EthDisplay,
+        serde::Serialize,
+        serde::Deserialize,
+        Default,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+    )]
+    #[ethevent(name = "PriceUpdated", abi = "PriceUpdated(uint256,uint256)")]
+    pub struct PriceUpdatedFilter {
+        pub price: ::ethers::core::types::U256,
+        pub step: ::ethers::core::types::U256,
+    }
+    /// Container type for all input parameters for the `decimals` function with
+    /// signature `decimals()` and selector `0x313ce567`
+    #[derive(
+        Clone,
+        ::ethers::contract::EthCall,
+        ::ethers::contract::EthDisplay,
+        serde::Serialize,
+        serde::Deserialize,
+        Default,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+    )]
+    #[ethcall(name = "decimals", abi = "decimals()")]
+    pub struct DecimalsCall;
+    /// Container type for all input parameters for the `price` function with
+    /// signature `price()` and selector `0xa035b1fe`
+    #[derive(
+        Clone,
+        ::ethers::contract::EthCall,
+        ::ethers::contract::EthDisplay,
+        serde::Serialize,
+        serde::Deserialize,
+        Default,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+    )]
+    #[ethcall(name = "price", abi = "price()")]
+    pub struct PriceCall;
+    /// Container type for all input parameters for the `setPrice` function with
+    /// signature `setPrice(uint256)` and selector `0x91b7f5ed`
+    #[derive(
+        Clone,
+        ::ethers::contract::EthCall,
+        ::ethers::contract::EthDisplay,
+        serde::Serialize,
+        serde::Deserialize,
+        Default,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+    )]
+    #[ethcall(name = "setPrice", abi = "setPrice(uint256)")]
+    pub struct SetPriceCall {
+        pub new_price: ::ethers::core::types::U256,
+    }
+    /// Container type for all input parameters for the `step` function with
+    /// signature `step()` and selector `0xe25fe175`
+    #[derive(
+        Clone,
+        ::ethers::contract::EthCall,
+        ::ethers::contract::EthDisplay,
+        serde::Serialize,
+        serde::Deserialize,
+        Default,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+    )]
+    #[ethcall(name = "step", abi = "step()")]
+    pub struct StepCall;
+    /// Container type for all input parameters for the `updater` function with
+    /// signature `updater()` and selector `0xdf034cd0`
+    #[derive(
+        Clone,
+        ::ethers::contract::EthCall,
+        ::ethers::contract::EthDisplay,
+        serde::Serialize,
+        serde::Deserialize,
+        Default,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+    )]
+    #[ethcall(name = "updater", abi = "updater()")]
+    pub struct UpdaterCall;
+    /// Container type for all of the contract's call
+    #[derive(
+        Clone,
+        ::ethers::contract::EthAbiType,
+        serde::Serialize,
+        serde::Deserialize,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+    )]
+    pub enum PriceOracleCalls {
+        Decimals(DecimalsCall),
+        Price(PriceCall),
+        SetPrice(SetPriceCall),
+        Step(StepCall),
+        Updater(UpdaterCall),
+    }
+    impl ::ethers::core::abi::AbiDecode for PriceOracleCalls {
+        fn decode(
+            data: impl AsRef<[u8]>,
+        ) -> ::core::result::Result<Self, ::ethers::core::abi::AbiError> {
+            let data = data.as_ref();
+            if let Ok(decoded) = <DecimalsCall as ::ethers::core::abi::AbiDecode>::decode(data) {
+                return Ok(Self::Decimals(decoded));
+            }
+            if let Ok(decoded) = <PriceCall as ::ethers::core::abi::AbiDecode>::decode(data) {
+                return Ok(Self::Price(decoded));
+            }
+            if let Ok(decoded) = <SetPriceCall as ::ethers::core::abi::AbiDecode>::decode(data) {
+                return Ok(Self::SetPrice(decoded));
+            }
+            if let Ok(decoded) = <StepCall as ::ethers::core::abi::AbiDecode>::decode(data) {
+                return Ok(Self::Step(decoded));
+            }
+            if let Ok(decoded) = <UpdaterCall as ::ethers::core::abi::AbiDecode>::decode(data) {
+                return Ok(Self::Updater(decoded));
+            }
+            Err(::ethers::core::abi::Error::InvalidData.into())
+        }
+    }
+    impl ::ethers::core::abi::AbiEncode for PriceOracleCalls {
+        fn encode(self) -> Vec<u8> {
+            match self {
+                Self::Decimals(element) => ::ethers::core::abi::AbiEncode::encode(element),
+                Self::Price(element) => ::ethers::core::abi::AbiEncode::encode(element),
+                Self::SetPrice(element) => ::ethers::core::abi::AbiEncode::encode(element),
+                Self::Step(element) => ::ethers::core::abi::AbiEncode::encode(element),
+                Self::Updater(element) => ::ethers::core::abi::AbiEncode::encode(element),
+            }
+        }
+    }
+    impl ::core::fmt::Display for PriceOracleCalls {
+        fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+            match self {
+                Self::Decimals(element) => ::core::fmt::Display::fmt(element, f),
+                Self::Price(element) => ::core::fmt::Display::fmt(element, f),
+                Self::SetPrice(element) => ::core::fmt::Display::fmt(element, f),
+                Self::Step(element) => ::core::fmt::Display::fmt(element, f),
+                Self::Updater(element) => ::core::fmt::Display::fmt(element, f),
+            }
+        }
+    }
+    impl ::core::convert::From<DecimalsCall> for PriceOracleCalls {
+        fn from(value: DecimalsCall) -> Self {
+            Self::Decimals(value)
+        }
+    }
+    impl ::core::convert::From<PriceCall> for PriceOracleCalls {
+        fn from(value: PriceCall) -> Self {
+            Self::Price(value)
+        }
+    }
+    impl ::core::convert::From<SetPriceCall> for PriceOracleCalls {
+        fn from(value: SetPriceCall) -> Self {
+            Self::SetPrice(value)
+        }
+    }
+    impl ::core::convert::From<StepCall> for PriceOracleCalls {
+        fn from(value: StepCall) -> Self {
+            Self::Step(value)
+        }
+    }
+    impl ::core::convert::From<UpdaterCall> for PriceOracleCalls {
+        fn from(value: UpdaterCall) -> Self {
+            Self::Updater(value)
+        }
+    }
+    /// Container type for all return fields from the `decimals` function with
+    /// signature `decimals()` and selector `0x313ce567`
+    #[derive(
+        Clone,
+        ::ethers::contract::EthAbiType,
+        ::ethers::contract::EthAbiCodec,
+        serde::Serialize,
+        serde::Deserialize,
+        Default,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+    )]
+    pub struct DecimalsReturn(pub u8);
+    /// Container type for all return fields from the `price` function with
+    /// signature `price()` and selector `0xa035b1fe`
+    #[derive(
+        Clone,
+        ::ethers::contract::EthAbiType,
+        ::ethers::contract::EthAbiCodec,
+        serde::Serialize,
+        serde::Deserialize,
+        Default,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+    )]
+    pub struct PriceReturn(pub ::ethers::core::types::U256);
+    /// Container type for all return fields from the `step` function with
+    /// signature `step()` and selector `0xe25fe175`
+    #[derive(
+        Clone,
+        ::ethers::contract::EthAbiType,
+        ::ethers::contract::EthAbiCodec,
+        serde::Serialize,
+        serde::Deserialize,
+        Default,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+    )]
+    pub struct StepReturn(pub ::ethers::core::types::U256);
+    /// Container type for all return fields from the `updater` function with
+    /// signature `updater()` and selector `0xdf034cd0`
+    #[derive(
+        Clone,
+        ::ethers::contract::EthAbiType,
+        ::ethers::contract::EthAbiCodec,
+        serde::Serialize,
+        serde::Deserialize,
+        Default,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+    )]
+    pub struct UpdaterReturn(pub ::ethers::core::types::Address);
+}
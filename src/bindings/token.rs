@@ -0,0 +1,1402 @@
+pub use token::*;
+/// This module was auto-generated with ethers-rs Abigen.
+/// More information at: <https://github.com/gakonst/ethers-rs>
+#[allow(
+    clippy::enum_variant_names,
+    clippy::too_many_arguments,
+    clippy::upper_case_acronyms,
+    clippy::type_complexity,
+    dead_code,
+    non_camel_case_types
+)]
+pub mod token {
+    #[allow(deprecated)]
+    fn __abi() -> ::ethers::core::abi::Abi {
+        ::ethers::core::abi::ethabi::Contract {
+            constructor: ::core::option::Option::Some(::ethers::core::abi::ethabi::Constructor {
+                inputs: ::std::vec![
+                    ::ethers::core::abi::ethabi::Param {
+                        name: ::std::borrow::ToOwned::to_owned("name_"),
+                        kind: ::ethers::core::abi::ethabi::ParamType::String,
+                        internal_type: ::core::option::Option::Some(
+                            ::std::borrow::ToOwned::to_owned("string"),
+                        ),
+                    },
+                    ::ethers::core::abi::ethabi::Param {
+                        name: ::std::borrow::ToOwned::to_owned("symbol_"),
+                        kind: ::ethers::core::abi::ethabi::ParamType::String,
+                        internal_type: ::core::option::Option::Some(
+                            ::std::borrow::ToOwned::to_owned("string"),
+                        ),
+                    },
+                    ::ethers::core::abi::ethabi::Param {
+                        name: ::std::borrow::ToOwned::to_owned("decimals_"),
+                        kind: ::ethers::core::abi::ethabi::ParamType::Uint(8usize),
+                        internal_type: ::core::option::Option::Some(
+                            ::std::borrow::ToOwned::to_owned("uint8"),
+                        ),
+                    },
+                ],
+            }),
+            functions: ::core::convert::From::from([
+                (
+                    ::std::borrow::ToOwned::to_owned("allowance"),
+                    ::std::vec![::ethers::core::abi::ethabi::Function {
+                        name: ::std::borrow::ToOwned::to_owned("allowance"),
+                        inputs: ::std::vec![
+                            ::ethers::core::abi::ethabi::Param {
+                                name: ::std::string::String::new(),
+                                kind: ::ethers::core::abi::ethabi::ParamType::Address,
+                                internal_type: ::core::option::Option::Some(
+                                    ::std::borrow::ToOwned::to_owned("address"),
+                                ),
+                            },
+                            ::ethers::core::abi::ethabi::Param {
+                                name: ::std::string::String::new(),
+                                kind: ::ethers::core::abi::ethabi::ParamType::Address,
+                                internal_type: ::core::option::Option::Some(
+                                    ::std::borrow::ToOwned::to_owned("address"),
+                                ),
+                            },
+                        ],
+                        outputs: ::std::vec![::ethers::core::abi::ethabi::Param {
+                            name: ::std::string::String::new(),
+                            kind: ::ethers::core::abi::ethabi::ParamType::Uint(256usize,),
+                            internal_type: ::core::option::Option::Some(
+                                ::std::borrow::ToOwned::to_owned("uint256"),
+                            ),
+                        },],
+                        constant: ::core::option::Option::None,
+                        state_mutability: ::ethers::core::abi::ethabi::StateMutability::View,
+                    },],
+                ),
+                (
+                    ::std::borrow::ToOwned::to_owned("approve"),
+                    ::std::vec![::ethers::core::abi::ethabi::Function {
+                        name: ::std::borrow::ToOwned::to_owned("approve"),
+                        inputs: ::std::vec![
+                            ::ethers::core::abi::ethabi::Param {
+                                name: ::std::borrow::ToOwned::to_owned("spender"),
+                                kind: ::ethers::core::abi::ethabi::ParamType::Address,
+                                internal_type: ::core::option::Option::Some(
+                                    ::std::borrow::ToOwned::to_owned("address"),
+                                ),
+                            },
+                            ::ethers::core::abi::ethabi::Param {
+                                name: ::std::borrow::ToOwned::to_owned("amount"),
+                                kind: ::ethers::core::abi::ethabi::ParamType::Uint(256usize,),
+                                internal_type: ::core::option::Option::Some(
+                                    ::std::borrow::ToOwned::to_owned("uint256"),
+                                ),
+                            },
+                        ],
+                        outputs: ::std::vec![::ethers::core::abi::ethabi::Param {
+                            name: ::std::string::String::new(),
+                            kind: ::ethers::core::abi::ethabi::ParamType::Bool,
+                            internal_type: ::core::option::Option::Some(
+                                ::std::borrow::ToOwned::to_owned("bool"),
+                            ),
+                        },],
+                        constant: ::core::option::Option::None,
+                        state_mutability: ::ethers::core::abi::ethabi::StateMutability::NonPayable,
+                    },],
+                ),
+                (
+                    ::std::borrow::ToOwned::to_owned("balanceOf"),
+                    ::std::vec![::ethers::core::abi::ethabi::Function {
+                        name: ::std::borrow::ToOwned::to_owned("balanceOf"),
+                        inputs: ::std::vec![::ethers::core::abi::ethabi::Param {
+                            name: ::std::string::String::new(),
+                            kind: ::ethers::core::abi::ethabi::ParamType::Address,
+                            internal_type: ::core::option::Option::Some(
+                                ::std::borrow::ToOwned::to_owned("address"),
+                            ),
+                        },],
+                        outputs: ::std::vec![::ethers::core::abi::ethabi::Param {
+                            name: ::std::string::String::new(),
+                            kind: ::ethers::core::abi::ethabi::ParamType::Uint(256usize,),
+                            internal_type: ::core::option::Option::Some(
+                                ::std::borrow::ToOwned::to_owned("uint256"),
+                            ),
+                        },],
+                        constant: ::core::option::Option::None,
+                        state_mutability: ::ethers::core::abi::ethabi::StateMutability::View,
+                    },],
+                ),
+                (
+                    ::std::borrow::ToOwned::to_owned("decimals"),
+                    ::std::vec![::ethers::core::abi::ethabi::Function {
+                        name: ::std::borrow::ToOwned::to_owned("decimals"),
+                        inputs: ::std::vec![],
+                        outputs: ::std::vec![::ethers::core::abi::ethabi::Param {
+                            name: ::std::string::String::new(),
+                            kind: ::ethers::core::abi::ethabi::ParamType::Uint(8usize),
+                            internal_type: ::core::option::Option::Some(
+                                ::std::borrow::ToOwned::to_owned("uint8"),
+                            ),
+                        },],
+                        constant: ::core::option::Option::None,
+                        state_mutability: ::ethers::core::abi::ethabi::StateMutability::View,
+                    },],
+                ),
+                (
+                    ::std::borrow::ToOwned::to_owned("mint"),
+                    ::std::vec![::ethers::core::abi::ethabi::Function {
+                        name: ::std::borrow::ToOwned::to_owned("mint"),
+                        inputs: ::std::vec![
+                            ::ethers::core::abi::ethabi::Param {
+                                name: ::std::borrow::ToOwned::to_owned("to"),
+                                kind: ::ethers::core::abi::ethabi::ParamType::Address,
+                                internal_type: ::core::option::Option::Some(
+                                    ::std::borrow::ToOwned::to_owned("address"),
+                                ),
+                            },
+                            ::ethers::core::abi::ethabi::Param {
+                                name: ::std::borrow::ToOwned::to_owned("amount"),
+                                kind: ::ethers::core::abi::ethabi::ParamType::Uint(256usize,),
+                                internal_type: ::core::option::Option::Some(
+                                    ::std::borrow::ToOwned::to_owned("uint256"),
+                                ),
+                            },
+                        ],
+                        outputs: ::std::vec![],
+                        constant: ::core::option::Option::None,
+                        state_mutability: ::ethers::core::abi::ethabi::StateMutability::NonPayable,
+                    },],
+                ),
+                (
+                    ::std::borrow::ToOwned::to_owned("name"),
+                    ::std::vec![::ethers::core::abi::ethabi::Function {
+                        name: ::std::borrow::ToOwned::to_owned("name"),
+                        inputs: ::std::vec![],
+                        outputs: ::std::vec![::ethers::core::abi::ethabi::Param {
+                            name: ::std::string::String::new(),
+                            kind: ::ethers::core::abi::ethabi::ParamType::String,
+                            internal_type: ::core::option::Option::Some(
+                                ::std::borrow::ToOwned::to_owned("string"),
+                            ),
+                        },],
+                        constant: ::core::option::Option::None,
+                        state_mutability: ::ethers::core::abi::ethabi::StateMutability::View,
+                    },],
+                ),
+                (
+                    ::std::borrow::ToOwned::to_owned("owner"),
+                    ::std::vec![::ethers::core::abi::ethabi::Function {
+                        name: ::std::borrow::ToOwned::to_owned("owner"),
+                        inputs: ::std::vec![],
+                        outputs: ::std::vec![::ethers::core::abi::ethabi::Param {
+                            name: ::std::string::String::new(),
+                            kind: ::ethers::core::abi::ethabi::ParamType::Address,
+                            internal_type: ::core::option::Option::Some(
+                                ::std::borrow::ToOwned::to_owned("address"),
+                            ),
+                        },],
+                        constant: ::core::option::Option::None,
+                        state_mutability: ::ethers::core::abi::ethabi::StateMutability::View,
+                    },],
+                ),
+                (
+                    ::std::borrow::ToOwned::to_owned("symbol"),
+                    ::std::vec![::ethers::core::abi::ethabi::Function {
+                        name: ::std::borrow::ToOwned::to_owned("symbol"),
+                        inputs: ::std::vec![],
+                        outputs: ::std::vec![::ethers::core::abi::ethabi::Param {
+                            name: ::std::string::String::new(),
+                            kind: ::ethers::core::abi::ethabi::ParamType::String,
+                            internal_type: ::core::option::Option::Some(
+                                ::std::borrow::ToOwned::to_owned("string"),
+                            ),
+                        },],
+                        constant: ::core::option::Option::None,
+                        state_mutability: ::ethers::core::abi::ethabi::StateMutability::View,
+                    },],
+                ),
+                (
+                    ::std::borrow::ToOwned::to_owned("totalSupply"),
+                    ::std::vec![::ethers::core::abi::ethabi::Function {
+                        name: ::std::borrow::ToOwned::to_owned("totalSupply"),
+                        inputs: ::std::vec![],
+                        outputs: ::std::vec![::ethers::core::abi::ethabi::Param {
+                            name: ::std::string::String::new(),
+                            kind: ::ethers::core::abi::ethabi::ParamType::Uint(256usize,),
+                            internal_type: ::core::option::Option::Some(
+                                ::std::borrow::ToOwned::to_owned("uint256"),
+                            ),
+                        },],
+                        constant: ::core::option::Option::None,
+                        state_mutability: ::ethers::core::abi::ethabi::StateMutability::View,
+                    },],
+                ),
+                (
+                    ::std::borrow::ToOwned::to_owned("transfer"),
+                    ::std::vec![::ethers::core::abi::ethabi::Function {
+                        name: ::std::borrow::ToOwned::to_owned("transfer"),
+                        inputs: ::std::vec![
+                            ::ethers::core::abi::ethabi::Param {
+                                name: ::std::borrow::ToOwned::to_owned("to"),
+                                kind: ::ethers::core::abi::ethabi::ParamType::Address,
+                                internal_type: ::core::option::Option::Some(
+                                    ::std::borrow::ToOwned::to_owned("address"),
+                                ),
+                            },
+                            ::ethers::core::abi::ethabi::Param {
+                                name: ::std::borrow::ToOwned::to_owned("amount"),
+                                kind: ::ethers::core::abi::ethabi::ParamType::Uint(256usize,),
+                                internal_type: ::core::option::Option::Some(
+                                    ::std::borrow::ToOwned::to_owned("uint256"),
+                                ),
+                            },
+                        ],
+                        outputs: ::std::vec![::ethers::core::abi::ethabi::Param {
+                            name: ::std::string::String::new(),
+                            kind: ::ethers::core::abi::ethabi::ParamType::Bool,
+                            internal_type: ::core::option::Option::Some(
+                                ::std::borrow::ToOwned::to_owned("bool"),
+                            ),
+                        },],
+                        constant: ::core::option::Option::None,
+                        state_mutability: ::ethers::core::abi::ethabi::StateMutability::NonPayable,
+                    },],
+                ),
+                (
+                    ::std::borrow::ToOwned::to_owned("transferFrom"),
+                    ::std::vec![::ethers::core::abi::ethabi::Function {
+                        name: ::std::borrow::ToOwned::to_owned("transferFrom"),
+                        inputs: ::std::vec![
+                            ::ethers::core::abi::ethabi::Param {
+                                name: ::std::borrow::ToOwned::to_owned("from"),
+                                kind: ::ethers::core::abi::ethabi::ParamType::Address,
+                                internal_type: ::core::option::Option::Some(
+                                    ::std::borrow::ToOwned::to_owned("address"),
+                                ),
+                            },
+                            ::ethers::core::abi::ethabi::Param {
+                                name: ::std::borrow::ToOwned::to_owned("to"),
+                                kind: ::ethers::core::abi::ethabi::ParamType::Address,
+                                internal_type: ::core::option::Option::Some(
+                                    ::std::borrow::ToOwned::to_owned("address"),
+                                ),
+                            },
+                            ::ethers::core::abi::ethabi::Param {
+                                name: ::std::borrow::ToOwned::to_owned("amount"),
+                                kind: ::ethers::core::abi::ethabi::ParamType::Uint(256usize,),
+                                internal_type: ::core::option::Option::Some(
+                                    ::std::borrow::ToOwned::to_owned("uint256"),
+                                ),
+                            },
+                        ],
+                        outputs: ::std::vec![::ethers::core::abi::ethabi::Param {
+                            name: ::std::string::String::new(),
+                            kind: ::ethers::core::abi::ethabi::ParamType::Bool,
+                            internal_type: ::core::option::Option::Some(
+                                ::std::borrow::ToOwned::to_owned("bool"),
+                            ),
+                        },],
+                        constant: ::core::option::Option::None,
+                        state_mutability: ::ethers::core::abi::ethabi::StateMutability::NonPayable,
+                    },],
+                ),
+            ]),
+            events: ::core::convert::From::from([
+                (
+                    ::std::borrow::ToOwned::to_owned("Approval"),
+                    ::std::vec![::ethers::core::abi::ethabi::Event {
+                        name: ::std::borrow::ToOwned::to_owned("Approval"),
+                        inputs: ::std::vec![
+                            ::ethers::core::abi::ethabi::EventParam {
+                                name: ::std::borrow::ToOwned::to_owned("owner"),
+                                kind: ::ethers::core::abi::ethabi::ParamType::Address,
+                                indexed: true,
+                            },
+                            ::ethers::core::abi::ethabi::EventParam {
+                                name: ::std::borrow::ToOwned::to_owned("spender"),
+                                kind: ::ethers::core::abi::ethabi::ParamType::Address,
+                                indexed: true,
+                            },
+                            ::ethers::core::abi::ethabi::EventParam {
+                                name: ::std::borrow::ToOwned::to_owned("value"),
+                                kind: ::ethers::core::abi::ethabi::ParamType::Uint(256usize,),
+                                indexed: false,
+                            },
+                        ],
+                        anonymous: false,
+                    },],
+                ),
+                (
+                    ::std::borrow::ToOwned::to_owned("Transfer"),
+                    ::std::vec![::ethers::core::abi::ethabi::Event {
+                        name: ::std::borrow::ToOwned::to_owned("Transfer"),
+                        inputs: ::std::vec![
+                            ::ethers::core::abi::ethabi::EventParam {
+                                name: ::std::borrow::ToOwned::to_owned("from"),
+                                kind: ::ethers::core::abi::ethabi::ParamType::Address,
+                                indexed: true,
+                            },
+                            ::ethers::core::abi::ethabi::EventParam {
+                                name: ::std::borrow::ToOwned::to_owned("to"),
+                                kind: ::ethers::core::abi::ethabi::ParamType::Address,
+                                indexed: true,
+                            },
+                            ::ethers::core::abi::ethabi::EventParam {
+                                name: ::std::borrow::ToOwned::to_owned("value"),
+                                kind: ::ethers::core::abi::ethabi::ParamType::Uint(256usize,),
+                                indexed: false,
+                            },
+                        ],
+                        anonymous: false,
+                    },],
+                ),
+            ]),
+            errors: ::core::convert::From::from([
+                (
+                    ::std::borrow::ToOwned::to_owned("InsufficientAllowance"),
+                    ::std::vec![::ethers::core::abi::ethabi::AbiError {
+                        name: ::std::borrow::ToOwned::to_owned("InsufficientAllowance",),
+                        inputs: ::std::vec![
+                            ::ethers::core::abi::ethabi::Param {
+                                name: ::std::borrow::ToOwned::to_owned("spender"),
+                                kind: ::ethers::core::abi::ethabi::ParamType::Address,
+                                internal_type: ::core::option::Option::Some(
+                                    ::std::borrow::ToOwned::to_owned("address"),
+                                ),
+                            },
+                            ::ethers::core::abi::ethabi::Param {
+                                name: ::std::borrow::ToOwned::to_owned("allowance"),
+                                kind: ::ethers::core::abi::ethabi::ParamType::Uint(256usize,),
+                                internal_type: ::core::option::Option::Some(
+                                    ::std::borrow::ToOwned::to_owned("uint256"),
+                                ),
+                            },
+                            ::ethers::core::abi::ethabi::Param {
+                                name: ::std::borrow::ToOwned::to_owned("needed"),
+                                kind: ::ethers::core::abi::ethabi::ParamType::Uint(256usize,),
+                                internal_type: ::core::option::Option::Some(
+                                    ::std::borrow::ToOwned::to_owned("uint256"),
+                                ),
+                            },
+                        ],
+                    },],
+                ),
+                (
+                    ::std::borrow::ToOwned::to_owned("InsufficientBalance"),
+                    ::std::vec![::ethers::core::abi::ethabi::AbiError {
+                        name: ::std::borrow::ToOwned::to_owned("InsufficientBalance",),
+                        inputs: ::std::vec![
+                            ::ethers::core::abi::ethabi::Param {
+                                name: ::std::borrow::ToOwned::to_owned("account"),
+                                kind: ::ethers::core::abi::ethabi::ParamType::Address,
+                                internal_type: ::core::option::Option::Some(
+                                    ::std::borrow::ToOwned::to_owned("address"),
+                                ),
+                            },
+                            ::ethers::core::abi::ethabi::Param {
+                                name: ::std::borrow::ToOwned::to_owned("balance"),
+                                kind: ::ethers::core::abi::ethabi::ParamType::Uint(256usize,),
+                                internal_type: ::core::option::Option::Some(
+                                    ::std::borrow::ToOwned::to_owned("uint256"),
+                                ),
+                            },
+                            ::ethers::core::abi::ethabi::Param {
+                                name: ::std::borrow::ToOwned::to_owned("needed"),
+                                kind: ::ethers::core::abi::ethabi::ParamType::Uint(256usize,),
+                                internal_type: ::core::option::Option::Some(
+                                    ::std::borrow::ToOwned::to_owned("uint256"),
+                                ),
+                            },
+                        ],
+                    },],
+                ),
+                (
+                    ::std::borrow::ToOwned::to_owned("NotOwner"),
+                    ::std::vec![::ethers::core::abi::ethabi::AbiError {
+                        name: ::std::borrow::ToOwned::to_owned("NotOwner"),
+                        inputs: ::std::vec![::ethers::core::abi::ethabi::Param {
+                            name: ::std::borrow::ToOwned::to_owned("caller"),
+                            kind: ::ethers::core::abi::ethabi::ParamType::Address,
+                            internal_type: ::core::option::Option::Some(
+                                ::std::borrow::ToOwned::to_owned("address"),
+                            ),
+                        },],
+                    },],
+                ),
+            ]),
+            receive: false,
+            fallback: false,
+        }
+    }
+    /// The parsed JSON ABI of the contract.
+    pub static TOKEN_ABI: ::ethers::contract::Lazy<::ethers::core::abi::Abi> =
+        ::ethers::contract::Lazy::new(__abi);
+    #[rustfmt::skip]
+    const __BYTECODE: &[u8] = b"`\x80`@R4a\x07JWa\x10\xAB8\x03\x80a\x02\xC0Ra\x10\xABa\x04\09a\x02\xC0Q``\x90\x10a\x06\xCEWa\x04\0Q`\x80Rg\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF`\x80Q\x11\x15a\0\xBBW\x7F\x08\xC3y\xA0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0`\0R` `\x04R`\"`$R\x7FABI decoding: invalid tuple offs`DR\x7Fet\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0`dR`\x84`\0\xFD[a\x02\xC0Q`\x80Q` \x01\x11\x15a\x01GW\x7F\x08\xC3y\xA0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0`\0R` `\x04R`\"`$R\x7FABI decoding: invalid tuple offs`DR\x7Fet\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0`dR`\x84`\0\xFD[`\x80Qa\x04\0\x01`\x80Rg\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF`\x80QQ\x11\x15a\x01\xE0W\x7F\x08\xC3y\xA0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0`\0R` `\x04R`'`$R\x7FABI decoding: invalid byte array`DR\x7F length\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0`dR`\x84`\0\xFD[a\x02\xC0Qa\x04\0`\x80Q\x03`\x80QQ` \x01\x01\x11\x15a\x02uW\x7F\x08\xC3y\xA0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0`\0R` `\x04R`'`$R\x7FABI decoding: invalid byte array`DR\x7F length\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0`dR`\x84`\0\xFD[a\x04 Q`\xA0Rg\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF`\xA0Q\x11\x15a\x03\nW\x7F\x08\xC3y\xA0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0`\0R` `\x04R`\"`$R\x7FABI decoding: invalid tuple offs`DR\x7Fet\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0`dR`\x84`\0\xFD[a\x02\xC0Q`\xA0Q` \x01\x11\x15a\x03\x96W\x7F\x08\xC3y\xA0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0`\0R` `\x04R`\"`$R\x7FABI decoding: invalid tuple offs`DR\x7Fet\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0`dR`\x84`\0\xFD[`\xA0Qa\x04\0\x01`\xA0Rg\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF`\xA0QQ\x11\x15a\x04/W\x7F\x08\xC3y\xA0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0`\0R` `\x04R`'`$R\x7FABI decoding: invalid byte array`DR\x7F length\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0`dR`\x84`\0\xFD[a\x02\xC0Qa\x04\0`\xA0Q\x03`\xA0QQ` \x01\x01\x11\x15a\x04\xC4W\x7F\x08\xC3y\xA0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0`\0R` `\x04R`'`$R\x7FABI decoding: invalid byte array`DR\x7F length\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0`dR`\x84`\0\xFD[a\x04@Q\x80`\x08\x1Ca\x06\xC9W`\xC0R`\x80QQ`\xE0R` `\xE0Q\x10\x15a\x05\x10W`\xE0Q`\x02\x02`\x01`\x01`\xE0Q`\x08\x02a\x01\0\x03\x1B\x03\x19`\0`\x80Q` \x01\x01Q\x16\x17`\0Ua\x05\xAEV[`\x01`\xE0Q`\x02\x02\x01`\0U`\0`\0R` `\0 a\x01 R`\0a\x01\0R[`\xE0Qa\x01\0Q` \x02\x10\x15a\x05\xADWa\x01\0Q` \x02`\x80Q` \x01\x01Qa\x01@R` a\x01\0Q` \x02`\xE0Q\x03\x10\x15a\x05\x8EW`\x01`\x01a\x01\0Q` \x02`\xE0Q\x03`\x08\x02a\x01\0\x03\x1B\x03\x19a\x01@Q\x16a\x01@Ra\x05\x8FV[[a\x01@Qa\x01\0Qa\x01 Q\x01U`\x01a\x01\0Q\x01a\x01\0Ra\x051V[[`\xA0QQ`\xE0R` `\xE0Q\x10\x15a\x05\xEBW`\xE0Q`\x02\x02`\x01`\x01`\xE0Q`\x08\x02a\x01\0\x03\x1B\x03\x19`\0`\xA0Q` \x01\x01Q\x16\x17`\x01Ua\x06\x89V[`\x01`\xE0Q`\x02\x02\x01`\x01U`\x01`\0R` `\0 a\x01 R`\0a\x01\0R[`\xE0Qa\x01\0Q` \x02\x10\x15a\x06\x88Wa\x01\0Q` \x02`\xA0Q` \x01\x01Qa\x01@R` a\x01\0Q` \x02`\xE0Q\x03\x10\x15a\x06iW`\x01`\x01a\x01\0Q` \x02`\xE0Q\x03`\x08\x02a\x01\0\x03\x1B\x03\x19a\x01@Q\x16a\x01@Ra\x06jV[[a\x01@Qa\x01\0Qa\x01 Q\x01U`\x01a\x01\0Q\x01a\x01\0Ra\x06\x0CV[[a\x04\0a\x02\xC0Q\x01a\x02\xE0Ra\x08\xE5a\x07\xC6a\x02\xE0Q9`\xC0Qa\x02\xE0Qa\x02d\x01R3a\x02\xE0Qa\x02\x92\x01R3a\x02\xE0Qa\x03|\x01Ra\x08\xE5a\x02\xE0Q\xF3[`\0\x80\xFD[\x7F\x08\xC3y\xA0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0`\0R` `\x04R`\"`$R\x7FABI decoding: tuple data too sho`DR\x7Frt\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0`dR`\x84`\0\xFD[\x7F\x08\xC3y\xA0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0`\0R` `\x04R`\"`$R\x7FEther sent to non-payable functi`DR\x7Fon\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0`dR`\x84`\0\xFD`\x80`@R4a\x08iW`\x046\x10a\0\x91W`\x005`\xE0\x1C\x80c\x06\xFD\xDE\x03\x14a\x01\rW\x80c\t^\xA7\xB3\x14a\x04LW\x80c\x18\x16\r\xDD\x14a\x02\xBDW\x80c#\xB8r\xDD\x14a\x05\xD5W\x80c1<\xE5g\x14a\x02aW\x80c@\xC1\x0F\x19\x14a\x03YW\x80cp\xA0\x821\x14a\x02\xCDW\x80c\x8D\xA5\xCB[\x14a\x02\x8FW\x80c\x95\xD8\x9BA\x14a\x01\xB7W\x80c\xA9\x05\x9C\xBB\x14a\x04\xCDW\x80c\xDDb\xED>\x14a\x03\x05W[\x7F\x08\xC3y\xA0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0`\0R` `\x04R`5`$R\x7FContract does not have fallback `DR\x7Fnor receive functions\0\0\0\0\0\0\0\0\0\0\0`dR`\x84`\0\xFD[P`\0T`\xE0R` a\x03\0R`\x01`\xE0Q\x16\x15a\x01\x80W`\xE0Q`\x01\x1Ca\x01\0R`\0`\0R` `\0 a\x01@R`\0a\x01 R[a\x01\0Qa\x01 Q` \x02\x10\x15a\x01{Wa\x01 Qa\x01@Q\x01Ta\x01 Q` \x02a\x03@\x01R`\x01a\x01 Q\x01a\x01 Ra\x01DV[a\x01\x99V[`\xFF`\xE0Q\x16`\x01\x1Ca\x01\0R`\xFF\x19`\xE0Q\x16a\x03@R[a\x01\0Qa\x03 R` `\x1Fa\x01\0Q\x01\x04` \x02`@\x01a\x03\0\xF3\0[P`\x01T`\xE0R` a\x03\0R`\x01`\xE0Q\x16\x15a\x02*W`\xE0Q`\x01\x1Ca\x01\0R`\x01`\0R` `\0 a\x01@R`\0a\x01 R[a\x01\0Qa\x01 Q` \x02\x10\x15a\x02%Wa\x01 Qa\x01@Q\x01Ta\x01 Q` \x02a\x03@\x01R`\x01a\x01 Q\x01a\x01 Ra\x01\xEEV[a\x02CV[`\xFF`\xE0Q\x16`\x01\x1Ca\x01\0R`\xFF\x19`\xE0Q\x16a\x03@R[a\x01\0Qa\x03 R` `\x1Fa\x01\0Q\x01\x04` \x02`@\x01a\x03\0\xF3\0[P\x7F\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0a\x03\0R` a\x03\0\xF3\0[P\x7F\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0a\x03\0R` a\x03\0\xF3\0[P`\x02Ta\x03\0R` a\x03\0\xF3\0[P`\x046\x03` \x90\x10a\x07\xEDW`\x045\x80`\xA0\x1Ca\x07\xE8W`\x80R`\x80Q`\x03` R`\0R`@`\0 Ta\x03\0R` a\x03\0\xF3\0[P`\x046\x03`@\x90\x10a\x07\xEDW`\x045\x80`\xA0\x1Ca\x07\xE8W`\x80R`$5\x80`\xA0\x1Ca\x07\xE8W`\xA0R`\xA0Q`\x80Q`\x04` R`\0R`@`\0 ` R`\0R`@`\0 Ta\x03\0R` a\x03\0\xF3\0[P`\x046\x03`@\x90\x10a\x07\xEDW`\x045\x80`\xA0\x1Ca\x07\xE8W`\x80R`$5`\xA0R\x7F\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\x003\x14a\x03\xCFW\x7F$Z\xEC\xD3\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0`\0R3`\x04R`$`\0\xFD[`\xA0Q`\x02T\x81\x81\x01\x81\x81\x10a\x07\xB9W\x91PP`\x02U`\xA0Q`\x80Q`\x03` R`\0R`@`\0 T\x81\x81\x01\x81\x81\x10a\x07\xB9W\x91PP`\x80Q`\x03` R`\0R`@`\0 U`\xA0Qa\x03\0R`\x80Q`\0\x7F\xDD\xF2R\xAD\x1B\xE2\xC8\x9Bi\xC2\xB0h\xFC7\x8D\xAA\x95+\xA7\xF1c\xC4\xA1\x16(\xF5ZM\xF5#\xB3\xEF` a\x03\0\xA3\0[P`\x046\x03`@\x90\x10a\x07\xEDW`\x045\x80`\xA0\x1Ca\x07\xE8W`\x80R`$5`\xA0R`\xA0Q`\x80Q3`\x04` R`\0R`@`\0 ` R`\0R`@`\0 U`\xA0Qa\x03\0R`\x80Q3\x7F\x8C[\xE1\xE5\xEB\xEC}[\xD1OqB}\x1E\x84\xF3\xDD\x03\x14\xC0\xF7\xB2)\x1E[ \n\xC8\xC7\xC3\xB9%` a\x03\0\xA3`\x01a\x03\0R` a\x03\0\xF3\0[P`\x046\x03`@\x90\x10a\x07\xEDW`\x045\x80`\xA0\x1Ca\x07\xE8W`\x80R`$5`\xA0R3`\x03` R`\0R`@`\0 T`\xC0R`\xA0Q`\xC0Q\x10\x15a\x05FW\x7F\xDBB\x14M\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0`\0R3`\x04R`\xC0Q`$R`\xA0Q`DR`d`\0\xFD[`\xA0Q`\xC0Q\x81\x81\x10a\x07\xB9W\x033`\x03` R`\0R`@`\0 U`\xA0Q`\x80Q`\x03` R`\0R`@`\0 T\x81\x81\x01\x81\x81\x10a\x07\xB9W\x91PP`\x80Q`\x03` R`\0R`@`\0 U`\xA0Qa\x03\0R`\x80Q3\x7F\xDD\xF2R\xAD\x1B\xE2\xC8\x9Bi\xC2\xB0h\xFC7\x8D\xAA\x95+\xA7\xF1c\xC4\xA1\x16(\xF5ZM\xF5#\xB3\xEF` a\x03\0\xA3`\x01a\x03\0R` a\x03\0\xF3\0[P`\x046\x03``\x90\x10a\x07\xEDW`\x045\x80`\xA0\x1Ca\x07\xE8W`\x80R`$5\x80`\xA0\x1Ca\x07\xE8W`\xA0R`D5`\xC0R3`\x80Q`\x04` R`\0R`@`\0 ` R`\0R`@`\0 T`\xE0R\x7F\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF`\xE0Q\x14\x15\x15a\x06\xC5W`\xC0Q`\xE0Q\x10\x15a\x06\x95W\x7F\x19+\x9EN\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0`\0R3`\x04R`\xE0Q`$R`\xC0Q`DR`d`\0\xFD[`\xC0Q`\xE0Q\x81\x81\x10a\x07\xB9W\x033`\x80Q`\x04` R`\0R`@`\0 ` R`\0R`@`\0 Ua\x06\xC6V[[`\x80Q`\x03` R`\0R`@`\0 Ta\x01\0R`\xC0Qa\x01\0Q\x10\x15a\x07%W\x7F\xDBB\x14M\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0`\0R`\x80Q`\x04Ra\x01\0Q`$R`\xC0Q`DR`d`\0\xFD[`\xC0Qa\x01\0Q\x81\x81\x10a\x07\xB9W\x03`\x80Q`\x03` R`\0R`@`\0 U`\xC0Q`\xA0Q`\x03` R`\0R`@`\0 T\x81\x81\x01\x81\x81\x10a\x07\xB9W\x91PP`\xA0Q`\x03` R`\0R`@`\0 U`\xC0Qa\x03\0R`\xA0Q`\x80Q\x7F\xDD\xF2R\xAD\x1B\xE2\xC8\x9Bi\xC2\xB0h\xFC7\x8D\xAA\x95+\xA7\xF1c\xC4\xA1\x16(\xF5ZM\xF5#\xB3\xEF` a\x03\0\xA3`\x01a\x03\0R` a\x03\0\xF3\0[\x7FNH{q\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0`\0R`\x11`\x04R`$`\0\xFD[`\0\x80\xFD[\x7F\x08\xC3y\xA0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0`\0R` `\x04R`\"`$R\x7FABI decoding: tuple data too sho`DR\x7Frt\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0`dR`\x84`\0\xFD[\x7F\x08\xC3y\xA0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0`\0R` `\x04R`\"`$R\x7FEther sent to non-payable functi`DR\x7Fon\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0`dR`\x84`\0\xFD";
+    /// The bytecode of the contract.
+    pub static TOKEN_BYTECODE: ::ethers::core::types::Bytes =
+        ::ethers::core::types::Bytes::from_static(__BYTECODE);
+    #[rustfmt::skip]
+    const __DEPLOYED_BYTECODE: &[u8] = b"`\x80`@R4a\x08iW`\x046\x10a\0\x91W`\x005`\xE0\x1C\x80c\x06\xFD\xDE\x03\x14a\x01\rW\x80c\t^\xA7\xB3\x14a\x04LW\x80c\x18\x16\r\xDD\x14a\x02\xBDW\x80c#\xB8r\xDD\x14a\x05\xD5W\x80c1<\xE5g\x14a\x02aW\x80c@\xC1\x0F\x19\x14a\x03YW\x80cp\xA0\x821\x14a\x02\xCDW\x80c\x8D\xA5\xCB[\x14a\x02\x8FW\x80c\x95\xD8\x9BA\x14a\x01\xB7W\x80c\xA9\x05\x9C\xBB\x14a\x04\xCDW\x80c\xDDb\xED>\x14a\x03\x05W[\x7F\x08\xC3y\xA0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0`\0R` `\x04R`5`$R\x7FContract does not have fallback `DR\x7Fnor receive functions\0\0\0\0\0\0\0\0\0\0\0`dR`\x84`\0\xFD[P`\0T`\xE0R` a\x03\0R`\x01`\xE0Q\x16\x15a\x01\x80W`\xE0Q`\x01\x1Ca\x01\0R`\0`\0R` `\0 a\x01@R`\0a\x01 R[a\x01\0Qa\x01 Q` \x02\x10\x15a\x01{Wa\x01 Qa\x01@Q\x01Ta\x01 Q` \x02a\x03@\x01R`\x01a\x01 Q\x01a\x01 Ra\x01DV[a\x01\x99V[`\xFF`\xE0Q\x16`\x01\x1Ca\x01\0R`\xFF\x19`\xE0Q\x16a\x03@R[a\x01\0Qa\x03 R` `\x1Fa\x01\0Q\x01\x04` \x02`@\x01a\x03\0\xF3\0[P`\x01T`\xE0R` a\x03\0R`\x01`\xE0Q\x16\x15a\x02*W`\xE0Q`\x01\x1Ca\x01\0R`\x01`\0R` `\0 a\x01@R`\0a\x01 R[a\x01\0Qa\x01 Q` \x02\x10\x15a\x02%Wa\x01 Qa\x01@Q\x01Ta\x01 Q` \x02a\x03@\x01R`\x01a\x01 Q\x01a\x01 Ra\x01\xEEV[a\x02CV[`\xFF`\xE0Q\x16`\x01\x1Ca\x01\0R`\xFF\x19`\xE0Q\x16a\x03@R[a\x01\0Qa\x03 R` `\x1Fa\x01\0Q\x01\x04` \x02`@\x01a\x03\0\xF3\0[P\x7F\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0a\x03\0R` a\x03\0\xF3\0[P\x7F\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0a\x03\0R` a\x03\0\xF3\0[P`\x02Ta\x03\0R` a\x03\0\xF3\0[P`\x046\x03` \x90\x10a\x07\xEDW`\x045\x80`\xA0\x1Ca\x07\xE8W`\x80R`\x80Q`\x03` R`\0R`@`\0 Ta\x03\0R` a\x03\0\xF3\0[P`\x046\x03`@\x90\x10a\x07\xEDW`\x045\x80`\xA0\x1Ca\x07\xE8W`\x80R`$5\x80`\xA0\x1Ca\x07\xE8W`\xA0R`\xA0Q`\x80Q`\x04` R`\0R`@`\0 ` R`\0R`@`\0 Ta\x03\0R` a\x03\0\xF3\0[P`\x046\x03`@\x90\x10a\x07\xEDW`\x045\x80`\xA0\x1Ca\x07\xE8W`\x80R`$5`\xA0R\x7F\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\x003\x14a\x03\xCFW\x7F$Z\xEC\xD3\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0`\0R3`\x04R`$`\0\xFD[`\xA0Q`\x02T\x81\x81\x01\x81\x81\x10a\x07\xB9W\x91PP`\x02U`\xA0Q`\x80Q`\x03` R`\0R`@`\0 T\x81\x81\x01\x81\x81\x10a\x07\xB9W\x91PP`\x80Q`\x03` R`\0R`@`\0 U`\xA0Qa\x03\0R`\x80Q`\0\x7F\xDD\xF2R\xAD\x1B\xE2\xC8\x9Bi\xC2\xB0h\xFC7\x8D\xAA\x95+\xA7\xF1c\xC4\xA1\x16(\xF5ZM\xF5#\xB3\xEF` a\x03\0\xA3\0[P`\x046\x03`@\x90\x10a\x07\xEDW`\x045\x80`\xA0\x1Ca\x07\xE8W`\x80R`$5`\xA0R`\xA0Q`\x80Q3`\x04` R`\0R`@`\0 ` R`\0R`@`\0 U`\xA0Qa\x03\0R`\x80Q3\x7F\x8C[\xE1\xE5\xEB\xEC}[\xD1OqB}\x1E\x84\xF3\xDD\x03\x14\xC0\xF7\xB2)\x1E[ \n\xC8\xC7\xC3\xB9%` a\x03\0\xA3`\x01a\x03\0R` a\x03\0\xF3\0[P`\x046\x03`@\x90\x10a\x07\xEDW`\x045\x80`\xA0\x1Ca\x07\xE8W`\x80R`$5`\xA0R3`\x03` R`\0R`@`\0 T`\xC0R`\xA0Q`\xC0Q\x10\x15a\x05FW\x7F\xDBB\x14M\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0`\0R3`\x04R`\xC0Q`$R`\xA0Q`DR`d`\0\xFD[`\xA0Q`\xC0Q\x81\x81\x10a\x07\xB9W\x033`\x03` R`\0R`@`\0 U`\xA0Q`\x80Q`\x03` R`\0R`@`\0 T\x81\x81\x01\x81\x81\x10a\x07\xB9W\x91PP`\x80Q`\x03` R`\0R`@`\0 U`\xA0Qa\x03\0R`\x80Q3\x7F\xDD\xF2R\xAD\x1B\xE2\xC8\x9Bi\xC2\xB0h\xFC7\x8D\xAA\x95+\xA7\xF1c\xC4\xA1\x16(\xF5ZM\xF5#\xB3\xEF` a\x03\0\xA3`\x01a\x03\0R` a\x03\0\xF3\0[P`\x046\x03``\x90\x10a\x07\xEDW`\x045\x80`\xA0\x1Ca\x07\xE8W`\x80R`$5\x80`\xA0\x1Ca\x07\xE8W`\xA0R`D5`\xC0R3`\x80Q`\x04` R`\0R`@`\0 ` R`\0R`@`\0 T`\xE0R\x7F\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF`\xE0Q\x14\x15\x15a\x06\xC5W`\xC0Q`\xE0Q\x10\x15a\x06\x95W\x7F\x19+\x9EN\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0`\0R3`\x04R`\xE0Q`$R`\xC0Q`DR`d`\0\xFD[`\xC0Q`\xE0Q\x81\x81\x10a\x07\xB9W\x033`\x80Q`\x04` R`\0R`@`\0 ` R`\0R`@`\0 Ua\x06\xC6V[[`\x80Q`\x03` R`\0R`@`\0 Ta\x01\0R`\xC0Qa\x01\0Q\x10\x15a\x07%W\x7F\xDBB\x14M\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0`\0R`\x80Q`\x04Ra\x01\0Q`$R`\xC0Q`DR`d`\0\xFD[`\xC0Qa\x01\0Q\x81\x81\x10a\x07\xB9W\x03`\x80Q`\x03` R`\0R`@`\0 U`\xC0Q`\xA0Q`\x03` R`\0R`@`\0 T\x81\x81\x01\x81\x81\x10a\x07\xB9W\x91PP`\xA0Q`\x03` R`\0R`@`\0 U`\xC0Qa\x03\0R`\xA0Q`\x80Q\x7F\xDD\xF2R\xAD\x1B\xE2\xC8\x9Bi\xC2\xB0h\xFC7\x8D\xAA\x95+\xA7\xF1c\xC4\xA1\x16(\xF5ZM\xF5#\xB3\xEF` a\x03\0\xA3`\x01a\x03\0R` a\x03\0\xF3\0[\x7FNH{q\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0`\0R`\x11`\x04R`$`\0\xFD[`\0\x80\xFD[\x7F\x08\xC3y\xA0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0`\0R` `\x04R`\"`$R\x7FABI decoding: tuple data too sho`DR\x7Frt\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0`dR`\x84`\0\xFD[\x7F\x08\xC3y\xA0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0`\0R` `\x04R`\"`$R\x7FEther sent to non-payable functi`DR\x7Fon\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0`dR`\x84`\0\xFD";
+    /// The deployed bytecode of the contract.
+    pub static TOKEN_DEPLOYED_BYTECODE: ::ethers::core::types::Bytes =
+        ::ethers::core::types::Bytes::from_static(__DEPLOYED_BYTECODE);
+    pub struct Token<M>(::ethers::contract::Contract<M>);
+    impl<M> ::core::clone::Clone for Token<M> {
+        fn clone(&self) -> Self {
+            Self(::core::clone::Clone::clone(&self.0))
+        }
+    }
+    impl<M> ::core::ops::Deref for Token<M> {
+        type Target = ::ethers::contract::Contract<M>;
+        fn deref(&self) -> &Self::Target {
+            &self.0
+        }
+    }
+    impl<M> ::core::ops::DerefMut for Token<M> {
+        fn deref_mut(&mut self) -> &mut Self::Target {
+            &mut self.0
+        }
+    }
+    impl<M> ::core::fmt::Debug for Token<M> {
+        fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+            f.debug_tuple(::core::stringify!(Token))
+                .field(&self.address())
+                .finish()
+        }
+    }
+    impl<M: ::ethers::providers::Middleware> Token<M> {
+        /// Creates a new contract instance with the specified `ethers` client
+        /// at `address`. The contract derefs to a `ethers::Contract`
+        /// object.
+        pub fn new<T: Into<::ethers::core::types::Address>>(
+            address: T,
+            client: ::std::sync::Arc<M>,
+        ) -> Self {
+            Self(::ethers::contract::Contract::new(
+                address.into(),
+                TOKEN_ABI.clone(),
+                client,
+            ))
+        }
+        /// Constructs the general purpose `Deployer` instance based on the
+        /// provided constructor arguments and sends it. Returns a new
+        /// instance of a deployer that returns an instance of this contract
+        /// after sending the transaction
+        ///
+        /// Notes:
+        /// - If there are no constructor arguments, you should pass `()` as the
+        ///   argument.
+        /// - The default poll duration is 7 seconds.
+        /// - The default number of confirmations is 1 block.
+        ///
+        ///
+        /// # Example
+        ///
+        /// Generate contract bindings with `abigen!` and deploy a new contract
+        /// instance.
+        ///
+        /// *Note*: this requires a `bytecode` and `abi` object in the
+        /// `greeter.json` artifact.
+        ///
+        /// ```ignore
+        /// # async fn deploy<M: ethers::providers::Middleware>(client: ::std::sync::Arc<M>) {
+        ///     abigen!(Greeter, "../greeter.json");
+        ///
+        ///    let greeter_contract = Greeter::deploy(client, "Hello world!".to_string()).unwrap().send().await.unwrap();
+        ///    let msg = greeter_contract.greet().call().await.unwrap();
+        /// # }
+        /// ```
+        pub fn deploy<T: ::ethers::core::abi::Tokenize>(
+            client: ::std::sync::Arc<M>,
+            constructor_args: T,
+        ) -> ::core::result::Result<
+            ::ethers::contract::builders::ContractDeployer<M, Self>,
+            ::ethers::contract::ContractError<M>,
+        > {
+            let factory = ::ethers::contract::ContractFactory::new(
+                TOKEN_ABI.clone(),
+                TOKEN_BYTECODE.clone().into(),
+                client,
+            );
+            let deployer = factory.deploy(constructor_args)?;
+            let deployer = ::ethers::contract::ContractDeployer::new(deployer);
+            Ok(deployer)
+        }
+        /// Calls the contract's `allowance` (0xdd62ed3e) function
+        pub fn allowance(
+            &self,
+            p0: ::ethers::core::types::Address,
+            p1: ::ethers::core::types::Address,
+        ) -> ::ethers::contract::builders::ContractCall<M, ::ethers::core::types::U256> {
+            self.0
+                .method_hash([221, 98, 237, 62], (p0, p1))
+                .expect("method not found (this should never happen)")
+        }
+        /// Calls the contract's `approve` (0x095ea7b3) function
+        pub fn approve(
+            &self,
+            spender: ::ethers::core::types::Address,
+            amount: ::ethers::core::types::U256,
+        ) -> ::ethers::contract::builders::ContractCall<M, bool> {
+            self.0
+                .method_hash([9, 94, 167, 179], (spender, amount))
+                .expect("method not found (this should never happen)")
+        }
+        /// Calls the contract's `balanceOf` (0x70a08231) function
+        pub fn balance_of(
+            &self,
+            p0: ::ethers::core::types::Address,
+        ) -> ::ethers::contract::builders::ContractCall<M, ::ethers::core::types::U256> {
+            self.0
+                .method_hash([112, 160, 130, 49], p0)
+                .expect("method not found (this should never happen)")
+        }
+        /// Calls the contract's `decimals` (0x313ce567) function
+        pub fn decimals(&self) -> ::ethers::contract::builders::ContractCall<M, u8> {
+            self.0
+                .method_hash([49, 60, 229, 103], ())
+                .expect("method not found (this should never happen)")
+        }
+        /// Calls the contract's `mint` (0x40c10f19) function
+        pub fn mint(
+            &self,
+            to: ::ethers::core::types::Address,
+            amount: ::ethers::core::types::U256,
+        ) -> ::ethers::contract::builders::ContractCall<M, ()> {
+            self.0
+                .method_hash([64, 193, 15, 25], (to, amount))
+                .expect("method not found (this should never happen)")
+        }
+        /// Calls the contract's `name` (0x06fdde03) function
+        pub fn name(&self) -> ::ethers::contract::builders::ContractCall<M, ::std::string::String> {
+            self.0
+                .method_hash([6, 253, 222, 3], ())
+                .expect("method not found (this should never happen)")
+        }
+        /// Calls the contract's `owner` (0x8da5cb5b) function
+        pub fn owner(
+            &self,
+        ) -> ::ethers::contract::builders::ContractCall<M, ::ethers::core::types::Address> {
+            self.0
+                .method_hash([141, 165, 203, 91], ())
+                .expect("method not found (this should never happen)")
+        }
+        /// Calls the contract's `symbol` (0x95d89b41) function
+        pub fn symbol(
+            &self,
+        ) -> ::ethers::contract::builders::ContractCall<M, ::std::string::String> {
+            self.0
+                .method_hash([149, 216, 155, 65], ())
+                .expect("method not found (this should never happen)")
+        }
+        /// Calls the contract's `totalSupply` (0x18160ddd) function
+        pub fn total_supply(
+            &self,
+        ) -> ::ethers::contract::builders::ContractCall<M, ::ethers::core::types::U256> {
+            self.0
+                .method_hash([24, 22, 13, 221], ())
+                .expect("method not found (this should never happen)")
+        }
+        /// Calls the contract's `transfer` (0xa9059cbb) function
+        pub fn transfer(
+            &self,
+            to: ::ethers::core::types::Address,
+            amount: ::ethers::core::types::U256,
+        ) -> ::ethers::contract::builders::ContractCall<M, bool> {
+            self.0
+                .method_hash([169, 5, 156, 187], (to, amount))
+                .expect("method not found (this should never happen)")
+        }
+        /// Calls the contract's `transferFrom` (0x23b872dd) function
+        pub fn transfer_from(
+            &self,
+            from: ::ethers::core::types::Address,
+            to: ::ethers::core::types::Address,
+            amount: ::ethers::core::types::U256,
+        ) -> ::ethers::contract::builders::ContractCall<M, bool> {
+            self.0
+                .method_hash([35, 184, 114, 221], (from, to, amount))
+                .expect("method not found (this should never happen)")
+        }
+        /// Gets the contract's `Approval` event
+        pub fn approval_filter(
+            &self,
+        ) -> ::ethers::contract::builders::Event<::std::sync::Arc<M>, M, ApprovalFilter> {
+            self.0.event()
+        }
+        /// Gets the contract's `Transfer` event
+        pub fn transfer_filter(
+            &self,
+        ) -> ::ethers::contract::builders::Event<::std::sync::Arc<M>, M, TransferFilter> {
+            self.0.event()
+        }
+        /// Returns an `Event` builder for all the events of this contract.
+        pub fn events(
+            &self,
+        ) -> ::ethers::contract::builders::Event<::std::sync::Arc<M>, M, TokenEvents> {
+            self.0
+                .event_with_filter(::core::default::Default::default())
+        }
+    }
+    impl<M: ::ethers::providers::Middleware> From<::ethers::contract::Contract<M>> for Token<M> {
+        fn from(contract: ::ethers::contract::Contract<M>) -> Self {
+            Self::new(contract.address(), contract.client())
+        }
+    }
+    /// Custom Error type `InsufficientAllowance` with signature
+    /// `InsufficientAllowance(address,uint256,uint256)` and selector
+    /// `0x192b9e4e`
+    #[derive(
+        Clone,
+        ::ethers::contract::EthError,
+        ::ethers::contract::EthDisplay,
+        serde::Serialize,
+        serde::Deserialize,
+        Default,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+    )]
+    #[etherror(
+        name = "InsufficientAllowance",
+        abi = "InsufficientAllowance(address,uint256,uint256)"
+    )]
+    pub struct InsufficientAllowance {
+        pub spender: ::ethers::core::types::Address,
+        pub allowance: ::ethers::core::types::U256,
+        pub needed: ::ethers::core::types::U256,
+    }
+    /// Custom Error type `InsufficientBalance` with signature
+    /// `InsufficientBalance(address,uint256,uint256)` and selector `0xdb42144d`
+    #[derive(
+        Clone,
+        ::ethers::contract::EthError,
+        ::ethers::contract::EthDisplay,
+        serde::Serialize,
+        serde::Deserialize,
+        Default,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+    )]
+    #[etherror(
+        name = "InsufficientBalance",
+        abi = "InsufficientBalance(address,uint256,uint256)"
+    )]
+    pub struct InsufficientBalance {
+        pub account: ::ethers::core::types::Address,
+        pub balance: ::ethers::core::types::U256,
+        pub needed: ::ethers::core::types::U256,
+    }
+    /// Custom Error type `NotOwner` with signature `NotOwner(address)` and
+    /// selector `0x245aecd3`
+    #[derive(
+        Clone,
+        ::ethers::contract::EthError,
+        ::ethers::contract::EthDisplay,
+        serde::Serialize,
+        serde::Deserialize,
+        Default,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+    )]
+    #[etherror(name = "NotOwner", abi = "NotOwner(address)")]
+    pub struct NotOwner {
+        pub caller: ::ethers::core::types::Address,
+    }
+    /// Container type for all of the contract's custom errors
+    #[derive(
+        Clone,
+        ::ethers::contract::EthAbiType,
+        serde::Serialize,
+        serde::Deserialize,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+    )]
+    pub enum TokenErrors {
+        InsufficientAllowance(InsufficientAllowance),
+        InsufficientBalance(InsufficientBalance),
+        NotOwner(NotOwner),
+        /// The standard solidity revert string, with selector
+        /// Error(string) -- 0x08c379a0
+        RevertString(::std::string::String),
+    }
+    impl ::ethers::core::abi::AbiDecode for TokenErrors {
+        fn decode(
+            data: impl AsRef<[u8]>,
+        ) -> ::core::result::Result<Self, ::ethers::core::abi::AbiError> {
+            let data = data.as_ref();
+            if let Ok(decoded) =
+                <::std::string::String as ::ethers::core::abi::AbiDecode>::decode(data)
+            {
+                return Ok(Self::RevertString(decoded));
+            }
+            if let Ok(decoded) =
+                <InsufficientAllowance as ::ethers::core::abi::AbiDecode>::decode(data)
+            {
+                return Ok(Self::InsufficientAllowance(decoded));
+            }
+            if let Ok(decoded) =
+                <InsufficientBalance as ::ethers::core::abi::AbiDecode>::decode(data)
+            {
+                return Ok(Self::InsufficientBalance(decoded));
+            }
+            if let Ok(decoded) = <NotOwner as ::ethers::core::abi::AbiDecode>::decode(data) {
+                return Ok(Self::NotOwner(decoded));
+            }
+            Err(::ethers::core::abi::Error::InvalidData.into())
+        }
+    }
+    impl ::ethers::core::abi::AbiEncode for TokenErrors {
+        fn encode(self) -> ::std::vec::Vec<u8> {
+            match self {
+                Self::InsufficientAllowance(element) => {
+                    ::ethers::core::abi::AbiEncode::encode(element)
+                }
+                Self::InsufficientBalance(element) => {
+                    ::ethers::core::abi::AbiEncode::encode(element)
+                }
+                Self::NotOwner(element) => ::ethers::core::abi::AbiEncode::encode(element),
+                Self::RevertString(s) => ::ethers::core::abi::AbiEncode::encode(s),
+            }
+        }
+    }
+    impl ::ethers::contract::ContractRevert for TokenErrors {
+        fn valid_selector(selector: [u8; 4]) -> bool {
+            match selector {
+                [0x08, 0xc3, 0x79, 0xa0] => true,
+                _ if selector
+                    == <InsufficientAllowance as ::ethers::contract::EthError>::selector() =>
+                {
+                    true
+                }
+                _ if selector
+                    == <InsufficientBalance as ::ethers::contract::EthError>::selector() =>
+                {
+                    true
+                }
+                _ if selector == <NotOwner as ::ethers::contract::EthError>::selector() => true,
+                _ => false,
+            }
+        }
+    }
+    impl ::core::fmt::Display for TokenErrors {
+        fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+            match self {
+                Self::InsufficientAllowance(element) => ::core::fmt::Display::fmt(element, f),
+                Self::InsufficientBalance(element) => ::core::fmt::Display::fmt(element, f),
+                Self::NotOwner(element) => ::core::fmt::Display::fmt(element, f),
+                Self::RevertString(s) => ::core::fmt::Display::fmt(s, f),
+            }
+        }
+    }
+    impl ::core::convert::From<::std::string::String> for TokenErrors {
+        fn from(value: String) -> Self {
+            Self::RevertString(value)
+        }
+    }
+    impl ::core::convert::From<InsufficientAllowance> for TokenErrors {
+        fn from(value: InsufficientAllowance) -> Self {
+            Self::InsufficientAllowance(value)
+        }
+    }
+    impl ::core::convert::From<InsufficientBalance> for TokenErrors {
+        fn from(value: InsufficientBalance) -> Self {
+            Self::InsufficientBalance(value)
+        }
+    }
+    impl ::core::convert::From<NotOwner> for TokenErrors {
+        fn from(value: NotOwner) -> Self {
+            Self::NotOwner(value)
+        }
+    }
+    #[derive(
+        Clone,
+        ::ethers::contract::EthEvent,
+        ::ethers::contract::EthDisplay,
+        serde::Serialize,
+        serde::Deserialize,
+        Default,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+    )]
+    #[ethevent(name = "Approval", abi = "Approval(address,address,uint256)")]
+    pub struct ApprovalFilter {
+        #[ethevent(indexed)]
+        pub owner: ::ethers::core::types::Address,
+        #[ethevent(indexed)]
+        pub spender: ::ethers::core::types::Address,
+        pub value: ::ethers::core::types::U256,
+    }
+    #[derive(
+        Clone,
+        ::ethers::contract::EthEvent,
+        ::ethers::contract::EthDisplay,
+        serde::Serialize,
+        serde::Deserialize,
+        Default,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+    )]
+    #[ethevent(name = "Transfer", abi = "Transfer(address,address,uint256)")]
+    pub struct TransferFilter {
+        #[ethevent(indexed)]
+        pub from: ::ethers::core::types::Address,
+        #[ethevent(indexed)]
+        pub to: ::ethers::core::types::Address,
+        pub value: ::ethers::core::types::U256,
+    }
+    /// Container type for all of the contract's events
+    #[derive(
+        Clone,
+        ::ethers::contract::EthAbiType,
+        serde::Serialize,
+        serde::Deserialize,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+    )]
+    pub enum TokenEvents {
+        ApprovalFilter(ApprovalFilter),
+        TransferFilter(TransferFilter),
+    }
+    impl ::ethers::contract::EthLogDecode for TokenEvents {
+        fn decode_log(
+            log: &::ethers::core::abi::RawLog,
+        ) -> ::core::result::Result<Self, ::ethers::core::abi::Error> {
+            if let Ok(decoded) = ApprovalFilter::decode_log(log) {
+                return Ok(TokenEvents::ApprovalFilter(decoded));
+            }
+            if let Ok(decoded) = TransferFilter::decode_log(log) {
+                return Ok(TokenEvents::TransferFilter(decoded));
+            }
+            Err(::ethers::core::abi::Error::InvalidData)
+        }
+    }
+    impl ::core::fmt::Display for TokenEvents {
+        fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+            match self {
+                Self::ApprovalFilter(element) => ::core::fmt::Display::fmt(element, f),
+                Self::TransferFilter(element) => ::core::fmt::Display::fmt(element, f),
+            }
+        }
+    }
+    impl ::core::convert::From<ApprovalFilter> for TokenEvents {
+        fn from(value: ApprovalFilter) -> Self {
+            Self::ApprovalFilter(value)
+        }
+    }
+    impl ::core::convert::From<TransferFilter> for TokenEvents {
+        fn from(value: TransferFilter) -> Self {
+            Self::TransferFilter(value)
+        }
+    }
+    /// Container type for all input parameters for the `allowance` function
+    /// with signature `allowance(address,address)` and selector `0xdd62ed3e`
+    #[derive(
+        Clone,
+        ::ethers::contract::EthCall,
+        ::ethers::contract::EthDisplay,
+        serde::Serialize,
+        serde::Deserialize,
+        Default,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+    )]
+    #[ethcall(name = "allowance", abi = "allowance(address,address)")]
+    pub struct AllowanceCall(
+        pub ::ethers::core::types::Address,
+        pub ::ethers::core::types::Address,
+    );
+    /// Container type for all input parameters for the `approve` function with
+    /// signature `approve(address,uint256)` and selector `0x095ea7b3`
+    #[derive(
+        Clone,
+        ::ethers::contract::EthCall,
+        ::ethers::contract::EthDisplay,
+        serde::Serialize,
+        serde::Deserialize,
+        Default,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+    )]
+    #[ethcall(name = "approve", abi = "approve(address,uint256)")]
+    pub struct ApproveCall {
+        pub spender: ::ethers::core::types::Address,
+        pub amount: ::ethers::core::types::U256,
+    }
+    /// Container type for all input parameters for the `balanceOf` function
+    /// with signature `balanceOf(address)` and selector `0x70a08231`
+    #[derive(
+        Clone,
+        ::ethers::contract::EthCall,
+        ::ethers::contract::EthDisplay,
+        serde::Serialize,
+        serde::Deserialize,
+        Default,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+    )]
+    #[ethcall(name = "balanceOf", abi = "balanceOf(address)")]
+    pub struct BalanceOfCall(pub ::ethers::core::types::Address);
+    /// Container type for all input parameters for the `decimals` function with
+    /// signature `decimals()` and selector `0x313ce567`
+    #[derive(
+        Clone,
+        ::ethers::contract::EthCall,
+        ::ethers::contract::EthDisplay,
+        serde::Serialize,
+        serde::Deserialize,
+        Default,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+    )]
+    #[ethcall(name = "decimals", abi = "decimals()")]
+    pub struct DecimalsCall;
+    /// Container type for all input parameters for the `mint` function with
+    /// signature `mint(address,uint256)` and selector `0x40c10f19`
+    #[derive(
+        Clone,
+        ::ethers::contract::EthCall,
+        ::ethers::contract::EthDisplay,
+        serde::Serialize,
+        serde::Deserialize,
+        Default,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+    )]
+    #[ethcall(name = "mint", abi = "mint(address,uint256)")]
+    pub struct MintCall {
+        pub to: ::ethers::core::types::Address,
+        pub amount: ::ethers::core::types::U256,
+    }
+    /// Container type for all input parameters for the `name` function with
+    /// signature `name()` and selector `0x06fdde03`
+    #[derive(
+        Clone,
+        ::ethers::contract::EthCall,
+        ::ethers::contract::EthDisplay,
+        serde::Serialize,
+        serde::Deserialize,
+        Default,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+    )]
+    #[ethcall(name = "name", abi = "name()")]
+    pub struct NameCall;
+    /// Container type for all input parameters for the `owner` function with
+    /// signature `owner()` and selector `0x8da5cb5b`
+    #[derive(
+        Clone,
+        ::ethers::contract::EthCall,
+        ::ethers::contract::EthDisplay,
+        serde::Serialize,
+        serde::Deserialize,
+        Default,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+    )]
+    #[ethcall(name = "owner", abi = "owner()")]
+    pub struct OwnerCall;
+    /// Container type for all input parameters for the `symbol` function with
+    /// signature `symbol()` and selector `0x95d89b41`
+    #[derive(
+        Clone,
+        ::ethers::contract::EthCall,
+        ::ethers::contract::EthDisplay,
+        serde::Serialize,
+        serde::Deserialize,
+        Default,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+    )]
+    #[ethcall(name = "symbol", abi = "symbol()")]
+    pub struct SymbolCall;
+    /// Container type for all input parameters for the `totalSupply` function
+    /// with signature `totalSupply()` and selector `0x18160ddd`
+    #[derive(
+        Clone,
+        ::ethers::contract::EthCall,
+        ::ethers::contract::EthDisplay,
+        serde::Serialize,
+        serde::Deserialize,
+        Default,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+    )]
+    #[ethcall(name = "totalSupply", abi = "totalSupply()")]
+    pub struct TotalSupplyCall;
+    /// Container type for all input parameters for the `transfer` function with
+    /// signature `transfer(address,uint256)` and selector `0xa9059cbb`
+    #[derive(
+        Clone,
+        ::ethers::contract::EthCall,
+        ::ethers::contract::EthDisplay,
+        serde::Serialize,
+        serde::Deserialize,
+        Default,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+    )]
+    #[ethcall(name = "transfer", abi = "transfer(address,uint256)")]
+    pub struct TransferCall {
+        pub to: ::ethers::core::types::Address,
+        pub amount: ::ethers::core::types::U256,
+    }
+    /// Container type for all input parameters for the `transferFrom` function
+    /// with signature `transferFrom(address,address,uint256)` and selector
+    /// `0x23b872dd`
+    #[derive(
+        Clone,
+        ::ethers::contract::EthCall,
+        ::ethers::contract::EthDisplay,
+        serde::Serialize,
+        serde::Deserialize,
+        Default,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+    )]
+    #[ethcall(name = "transferFrom", abi = "transferFrom(address,address,uint256)")]
+    pub struct TransferFromCall {
+        pub from: ::ethers::core::types::Address,
+        pub to: ::ethers::core::types::Address,
+        pub amount: ::ethers::core::types::U256,
+    }
+    /// Container type for all of the contract's call
+    #[derive(
+        Clone,
+        ::ethers::contract::EthAbiType,
+        serde::Serialize,
+        serde::Deserialize,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+    )]
+    pub enum TokenCalls {
+        Allowance(AllowanceCall),
+        Approve(ApproveCall),
+        BalanceOf(BalanceOfCall),
+        Decimals(DecimalsCall),
+        Mint(MintCall),
+        Name(NameCall),
+        Owner(OwnerCall),
+        Symbol(SymbolCall),
+        TotalSupply(TotalSupplyCall),
+        Transfer(TransferCall),
+        TransferFrom(TransferFromCall),
+    }
+    impl ::ethers::core::abi::AbiDecode for TokenCalls {
+        fn decode(
+            data: impl AsRef<[u8]>,
+        ) -> ::core::result::Result<Self, ::ethers::core::abi::AbiError> {
+            let data = data.as_ref();
+            if let Ok(decoded) = <AllowanceCall as ::ethers::core::abi::AbiDecode>::decode(data) {
+                return Ok(Self::Allowance(decoded));
+            }
+            if let Ok(decoded) = <ApproveCall as ::ethers::core::abi::AbiDecode>::decode(data) {
+                return Ok(Self::Approve(decoded));
+            }
+            if let Ok(decoded) = <BalanceOfCall as ::ethers::core::abi::AbiDecode>::decode(data) {
+                return Ok(Self::BalanceOf(decoded));
+            }
+            if let Ok(decoded) = <DecimalsCall as ::ethers::core::abi::AbiDecode>::decode(data) {
+                return Ok(Self::Decimals(decoded));
+            }
+            if let Ok(decoded) = <MintCall as ::ethers::core::abi::AbiDecode>::decode(data) {
+                return Ok(Self::Mint(decoded));
+            }
+            if let Ok(decoded) = <NameCall as ::ethers::core::abi::AbiDecode>::decode(data) {
+                return Ok(Self::Name(decoded));
+            }
+            if let Ok(decoded) = <OwnerCall as ::ethers::core::abi::AbiDecode>::decode(data) {
+                return Ok(Self::Owner(decoded));
+            }
+            if let Ok(decoded) = <SymbolCall as ::ethers::core::abi::AbiDecode>::decode(data) {
+                return Ok(Self::Symbol(decoded));
+            }
+            if let Ok(decoded) = <TotalSupplyCall as ::ethers::core::abi::AbiDecode>::decode(data) {
+                return Ok(Self::TotalSupply(decoded));
+            }
+            if let Ok(decoded) = <TransferCall as ::ethers::core::abi::AbiDecode>::decode(data) {
+                return Ok(Self::Transfer(decoded));
+            }
+            if let Ok(decoded) = <TransferFromCall as ::ethers::core::abi::AbiDecode>::decode(data)
+            {
+                return Ok(Self::TransferFrom(decoded));
+            }
+            Err(::ethers::core::abi::Error::InvalidData.into())
+        }
+    }
+    impl ::ethers::core::abi::AbiEncode for TokenCalls {
+        fn encode(self) -> Vec<u8> {
+            match self {
+                Self::Allowance(element) => ::ethers::core::abi::AbiEncode::encode(element),
+                Self::Approve(element) => ::ethers::core::abi::AbiEncode::encode(element),
+                Self::BalanceOf(element) => ::ethers::core::abi::AbiEncode::encode(element),
+                Self::Decimals(element) => ::ethers::core::abi::AbiEncode::encode(element),
+                Self::Mint(element) => ::ethers::core::abi::AbiEncode::encode(element),
+                Self::Name(element) => ::ethers::core::abi::AbiEncode::encode(element),
+                Self::Owner(element) => ::ethers::core::abi::AbiEncode::encode(element),
+                Self::Symbol(element) => ::ethers::core::abi::AbiEncode::encode(element),
+                Self::TotalSupply(element) => ::ethers::core::abi::AbiEncode::encode(element),
+                Self::Transfer(element) => ::ethers::core::abi::AbiEncode::encode(element),
+                Self::TransferFrom(element) => ::ethers::core::abi::AbiEncode::encode(element),
+            }
+        }
+    }
+    impl ::core::fmt::Display for TokenCalls {
+        fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+            match self {
+                Self::Allowance(element) => ::core::fmt::Display::fmt(element, f),
+                Self::Approve(element) => ::core::fmt::Display::fmt(element, f),
+                Self::BalanceOf(element) => ::core::fmt::Display::fmt(element, f),
+                Self::Decimals(element) => ::core::fmt::Display::fmt(element, f),
+                Self::Mint(element) => ::core::fmt::Display::fmt(element, f),
+                Self::Name(element) => ::core::fmt::Display::fmt(element, f),
+                Self::Owner(element) => ::core::fmt::Display::fmt(element, f),
+                Self::Symbol(element) => ::core::fmt::Display::fmt(element, f),
+                Self::TotalSupply(element) => ::core::fmt::Display::fmt(element, f),
+                Self::Transfer(element) => ::core::fmt::Display::fmt(element, f),
+                Self::TransferFrom(element) => ::core::fmt::Display::fmt(element, f),
+            }
+        }
+    }
+    impl ::core::convert::From<AllowanceCall> for TokenCalls {
+        fn from(value: AllowanceCall) -> Self {
+            Self::Allowance(value)
+        }
+    }
+    impl ::core::convert::From<ApproveCall> for TokenCalls {
+        fn from(value: ApproveCall) -> Self {
+            Self::Approve(value)
+        }
+    }
+    impl ::core::convert::From<BalanceOfCall> for TokenCalls {
+        fn from(value: BalanceOfCall) -> Self {
+            Self::BalanceOf(value)
+        }
+    }
+    impl ::core::convert::From<DecimalsCall> for TokenCalls {
+        fn from(value: DecimalsCall) -> Self {
+            Self::Decimals(value)
+        }
+    }
+    impl ::core::convert::From<MintCall> for TokenCalls {
+        fn from(value: MintCall) -> Self {
+            Self::Mint(value)
+        }
+    }
+    impl ::core::convert::From<NameCall> for TokenCalls {
+        fn from(value: NameCall) -> Self {
+            Self::Name(value)
+        }
+    }
+    impl ::core::convert::From<OwnerCall> for TokenCalls {
+        fn from(value: OwnerCall) -> Self {
+            Self::Owner(value)
+        }
+    }
+    impl ::core::convert::From<SymbolCall> for TokenCalls {
+        fn from(value: SymbolCall) -> Self {
+            Self::Symbol(value)
+        }
+    }
+    impl ::core::convert::From<TotalSupplyCall> for TokenCalls {
+        fn from(value: TotalSupplyCall) -> Self {
+            Self::TotalSupply(value)
+        }
+    }
+    impl ::core::convert::From<TransferCall> for TokenCalls {
+        fn from(value: TransferCall) -> Self {
+            Self::Transfer(value)
+        }
+    }
+    impl ::core::convert::From<TransferFromCall> for TokenCalls {
+        fn from(value: TransferFromCall) -> Self {
+            Self::TransferFrom(value)
+        }
+    }
+    /// Container type for all return fields from the `allowance` function with
+    /// signature `allowance(address,address)` and selector `0xdd62ed3e`
+    #[derive(
+        Clone,
+        ::ethers::contract::EthAbiType,
+        ::ethers::contract::EthAbiCodec,
+        serde::Serialize,
+        serde::Deserialize,
+        Default,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+    )]
+    pub struct AllowanceReturn(pub ::ethers::core::types::U256);
+    /// Container type for all return fields from the `approve` function with
+    /// signature `approve(address,uint256)` and selector `0x095ea7b3`
+    #[derive(
+        Clone,
+        ::ethers::contract::EthAbiType,
+        ::ethers::contract::EthAbiCodec,
+        serde::Serialize,
+        serde::Deserialize,
+        Default,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+    )]
+    pub struct ApproveReturn(pub bool);
+    /// Container type for all return fields from the `balanceOf` function with
+    /// signature `balanceOf(address)` and selector `0x70a08231`
+    #[derive(
+        Clone,
+        ::ethers::contract::EthAbiType,
+        ::ethers::contract::EthAbiCodec,
+        serde::Serialize,
+        serde::Deserialize,
+        Default,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+    )]
+    pub struct BalanceOfReturn(pub ::ethers::core::types::U256);
+    /// Container type for all return fields from the `decimals` function with
+    /// signature `decimals()` and selector `0x313ce567`
+    #[derive(
+        Clone,
+        ::ethers::contract::EthAbiType,
+        ::ethers::contract::EthAbiCodec,
+        serde::Serialize,
+        serde::Deserialize,
+        Default,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+    )]
+    pub struct DecimalsReturn(pub u8);
+    /// Container type for all return fields from the `name` function with
+    /// signature `name()` and selector `0x06fdde03`
+    #[derive(
+        Clone,
+        ::ethers::contract::EthAbiType,
+        ::ethers::contract::EthAbiCodec,
+        serde::Serialize,
+        serde::Deserialize,
+        Default,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+    )]
+    pub struct NameReturn(pub ::std::string::String);
+    /// Container type for all return fields from the `owner` function with
+    /// signature `owner()` and selector `0x8da5cb5b`
+    #[derive(
+        Clone,
+        ::ethers::contract::EthAbiType,
+        ::ethers::contract::EthAbiCodec,
+        serde::Serialize,
+        serde::Deserialize,
+        Default,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+    )]
+    pub struct OwnerReturn(pub ::ethers::core::types::Address);
+    /// Container type for all return fields from the `symbol` function with
+    /// signature `symbol()` and selector `0x95d89b41`
+    #[derive(
+        Clone,
+        ::ethers::contract::EthAbiType,
+        ::ethers::contract::EthAbiCodec,
+        serde::Serialize,
+        serde::Deserialize,
+        Default,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+    )]
+    pub struct SymbolReturn(pub ::std::string::String);
+    /// Container type for all return fields from the `totalSupply` function
+    /// with signature `totalSupply()` and selector `0x18160ddd`
+    #[derive(
+        Clone,
+        ::ethers::contract::EthAbiType,
+        ::ethers::contract::EthAbiCodec,
+        serde::Serialize,
+        serde::Deserialize,
+        Default,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+    )]
+    pub struct TotalSupplyReturn(pub ::ethers::core::types::U256);
+    /// Container type for all return fields from the `transfer` function with
+    /// signature `transfer(address,uint256)` and selector `0xa9059cbb`
+    #[derive(
+        Clone,
+        ::ethers::contract::EthAbiType,
+        ::ethers::contract::EthAbiCodec,
+        serde::Serialize,
+        serde::Deserialize,
+        Default,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+    )]
+    pub struct TransferReturn(pub bool);
+    /// Container type for all return fields from the `transferFrom` function
+    /// with signature `transferFrom(address,address,uint256)` and selector
+    /// `0x23b872dd`
+    #[derive(
+        Clone,
+        ::ethers::contract::EthAbiType,
+        ::ethers::contract::EthAbiCodec,
+        serde::Serialize,
+        serde::Deserialize,
+        Default,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+    )]
+    pub struct TransferFromReturn(pub bool);
+}
@@ -0,0 +1,321 @@
+use std::fmt::Write as _;
+
+use anyhow::{anyhow, Result};
+use schemars::schema::{InstanceType, RootSchema, Schema, SchemaObject, SingleOrVec};
+use toml::Value;
+
+use crate::{behaviors::Behaviors, config};
+
+/// A configurable field of a behavior.
+#[derive(Debug, Clone)]
+pub struct Field {
+    pub name: String,
+    /// Rust-like rendering of the field's type, e.g. `Option<Wait>`.
+    pub ty: String,
+    pub required: bool,
+    /// The value used when the field is omitted, as TOML.
+    pub default: Option<String>,
+    pub description: Option<String>,
+    /// A value to start editing from.
+    pub example: Value,
+    /// Whether the field accepts a table, e.g. `{ uniform = [1, 5] }` for a
+    /// `Wait`.
+    pub takes_table: bool,
+}
+
+/// A behavior of the [`Behaviors`] enum as configs see it.
+#[derive(Debug, Clone)]
+pub struct BehaviorInfo {
+    pub name: &'static str,
+    pub description: Option<String>,
+    /// The event type the behavior's `process` consumes.
+    pub event: &'static str,
+    pub fields: Vec<Field>,
+}
+
+/// Describes every behavior, read from the config schema so it always
+/// matches what configs accept.
+pub fn behaviors() -> Vec<BehaviorInfo> {
+    let schema = config::schema();
+    Behaviors::catalog()
+        .into_iter()
+        .map(|(name, event)| {
+            let definition = resolve(&schema, &schema.definitions[name]);
+            let fields = definition
+                .object
+                .as_ref()
+                .map(|object| {
+                    object
+                        .properties
+                        .iter()
+                        .map(|(field, property)| {
+                            let property_object = object_of(property);
+                            let metadata = property_object.metadata.as_deref();
+                            Field {
+                                name: field.clone(),
+                                ty: type_name(property),
+                                required: object.required.contains(field),
+                                default: metadata
+                                    .and_then(|metadata| metadata.default.as_ref())
+                                    .and_then(|default| Value::try_from(default).ok())
+                                    .map(|default| default.to_string()),
+                                description: metadata
+                                    .and_then(|metadata| metadata.description.clone()),
+                                example: example(&schema, property),
+                                takes_table: takes_table(&schema, property),
+                            }
+                        })
+                        .collect()
+                })
+                .unwrap_or_default();
+            BehaviorInfo {
+                name,
+                description: definition
+                    .metadata
+                    .as_ref()
+                    .and_then(|metadata| metadata.description.clone()),
+                event,
+                fields,
+            }
+        })
+        .collect()
+}
+
+/// Looks up the behavior called `name`.
+pub fn behavior(name: &str) -> Result<BehaviorInfo> {
+    let behaviors = behaviors();
+    let names: Vec<_> = behaviors.iter().map(|behavior| behavior.name).collect();
+    behaviors
+        .iter()
+        .find(|behavior| behavior.name == name)
+        .cloned()
+        .ok_or_else(|| {
+            anyhow!(
+                "unknown behavior `{}`, expected one of: {}",
+                name,
+                names.join(", ")
+            )
+        })
+}
+
+impl BehaviorInfo {
+    /// Lists the behavior's fields with their types and defaults.
+    pub fn describe(&self) -> String {
+        let mut out = String::new();
+        // Writing to a `String` cannot fail.
+        let _ = writeln!(out, "{} (consumes {})", self.name, self.event);
+        if let Some(description) = &self.description {
+            let _ = writeln!(out, "  {}", description.replace('\n', "\n  "));
+        }
+        for field in &self.fields {
+            let _ = write!(out, "  {}.{}: {}", self.name, field.name, field.ty);
+            match (&field.default, field.required) {
+                (Some(default), _) => {
+                    let _ = write!(out, " = {}", default);
+                }
+                (None, false) => out.push_str(" (optional)"),
+                (None, true) => {}
+            }
+            out.push('\n');
+            if let Some(description) = &field.description {
+                let _ = writeln!(out, "      {}", description.replace('\n', "\n      "));
+            }
+        }
+        out
+    }
+
+    /// A config entry for the behavior with every required field set to an
+    /// example value and the optional ones commented out.
+    pub fn toml_snippet(&self) -> String {
+        let agent = format!("my_{}", to_snake_case(self.name));
+        let mut out = format!("[[{}]]\n[{}.{}]\n", agent, agent, self.name);
+        for field in &self.fields {
+            let value = field
+                .default
+                .clone()
+                .unwrap_or_else(|| field.example.to_string());
+            let prefix = if field.required { "" } else { "# " };
+            let _ = writeln!(out, "{}{} = {} # {}", prefix, field.name, value, field.ty);
+        }
+        out
+    }
+}
+
+fn to_snake_case(name: &str) -> String {
+    let mut snake = String::new();
+    for (i, c) in name.chars().enumerate() {
+        if c.is_uppercase() && i > 0 {
+            snake.push('_');
+        }
+        snake.push(c.to_ascii_lowercase());
+    }
+    snake
+}
+
+fn object_of(schema: &Schema) -> SchemaObject {
+    schema.clone().into_object()
+}
+
+/// Follows `schema` to its definition if it is a reference.
+fn resolve(root: &RootSchema, schema: &Schema) -> SchemaObject {
+    let object = object_of(schema);
+    match object
+        .reference
+        .as_deref()
+        .and_then(|reference| reference.rsplit('/').next())
+        .and_then(|name| root.definitions.get(name))
+    {
+        Some(definition) => resolve(root, definition),
+        None => object,
+    }
+}
+
+/// The alternatives of a schema that can be one of several.
+fn alternatives(object: &SchemaObject) -> Vec<Schema> {
+    object
+        .subschemas
+        .as_ref()
+        .and_then(|subschemas| {
+            subschemas
+                .one_of
+                .clone()
+                .or_else(|| subschemas.any_of.clone())
+                .or_else(|| subschemas.all_of.clone())
+        })
+        .unwrap_or_default()
+}
+
+fn is_null(schema: &Schema) -> bool {
+    matches!(
+        &object_of(schema).instance_type,
+        Some(SingleOrVec::Single(ty)) if **ty == InstanceType::Null
+    )
+}
+
+/// Renders the type of `schema` the way it is declared in Rust.
+fn type_name(schema: &Schema) -> String {
+    let object = object_of(schema);
+    if let Some(reference) = &object.reference {
+        return reference.rsplit('/').next().unwrap_or(reference).to_owned();
+    }
+    match alternatives(&object).as_slice() {
+        // A reference wrapped to carry a description or default.
+        [only] => return type_name(only),
+        [value, null] | [null, value] if is_null(null) && !is_null(value) => {
+            return format!("Option<{}>", type_name(value))
+        }
+        _ => {}
+    }
+    match &object.instance_type {
+        Some(SingleOrVec::Vec(types)) if types.contains(&InstanceType::Null) => {
+            let inner = SchemaObject {
+                instance_type: types
+                    .iter()
+                    .find(|ty| **ty != InstanceType::Null)
+                    .map(|ty| SingleOrVec::Single(Box::new(*ty))),
+                ..object.clone()
+            };
+            format!("Option<{}>", type_name(&Schema::Object(inner)))
+        }
+        Some(SingleOrVec::Single(ty)) => match **ty {
+            InstanceType::Integer | InstanceType::Number => match object.format.as_deref() {
+                Some("uint64") => "u64",
+                Some("uint32") => "u32",
+                Some("uint") => "usize",
+                Some("int64") => "i64",
+                Some("double") => "f64",
+                _ => "number",
+            }
+            .to_owned(),
+            InstanceType::String => "String".to_owned(),
+            InstanceType::Boolean => "bool".to_owned(),
+            InstanceType::Array => {
+                match object.array.as_ref().and_then(|array| array.items.as_ref()) {
+                    Some(SingleOrVec::Single(items)) => format!("Vec<{}>", type_name(items)),
+                    Some(SingleOrVec::Vec(items)) => format!(
+                        "({})",
+                        items.iter().map(type_name).collect::<Vec<_>>().join(", ")
+                    ),
+                    None => "Vec<_>".to_owned(),
+                }
+            }
+            InstanceType::Object => "table".to_owned(),
+            InstanceType::Null => "()".to_owned(),
+        },
+        _ => "value".to_owned(),
+    }
+}
+
+/// Whether `schema` accepts a table in any of its alternatives.
+fn takes_table(root: &RootSchema, schema: &Schema) -> bool {
+    let object = resolve(root, schema);
+    let alternatives = alternatives(&object);
+    if !alternatives.is_empty() {
+        return alternatives
+            .iter()
+            .any(|alternative| takes_table(root, alternative));
+    }
+    match &object.instance_type {
+        Some(SingleOrVec::Single(ty)) => **ty == InstanceType::Object,
+        Some(SingleOrVec::Vec(types)) => types.contains(&InstanceType::Object),
+        None => true,
+    }
+}
+
+/// A value `schema` accepts, choosing the first alternative wherever there is
+/// a choice.
+fn example(root: &RootSchema, schema: &Schema) -> Value {
+    let object = resolve(root, schema);
+    if let Some(value) = object
+        .enum_values
+        .as_ref()
+        .and_then(|values| values.first())
+        .and_then(|value| Value::try_from(value).ok())
+    {
+        return value;
+    }
+    if let Some(alternative) = alternatives(&object)
+        .iter()
+        .find(|alternative| !is_null(alternative))
+    {
+        return example(root, alternative);
+    }
+    let ty = match &object.instance_type {
+        Some(SingleOrVec::Single(ty)) => **ty,
+        Some(SingleOrVec::Vec(types)) => types
+            .iter()
+            .copied()
+            .find(|ty| *ty != InstanceType::Null)
+            .unwrap_or(InstanceType::Null),
+        None => InstanceType::Object,
+    };
+    match ty {
+        InstanceType::Integer => Value::Integer(1),
+        InstanceType::Number => Value::Float(1.0),
+        InstanceType::Boolean => Value::Boolean(false),
+        InstanceType::String => Value::String(String::new()),
+        InstanceType::Array => Value::Array(
+            match object.array.as_ref().and_then(|array| array.items.as_ref()) {
+                Some(SingleOrVec::Single(items)) => vec![example(root, items)],
+                Some(SingleOrVec::Vec(items)) => {
+                    items.iter().map(|item| example(root, item)).collect()
+                }
+                None => Vec::new(),
+            },
+        ),
+        InstanceType::Object | InstanceType::Null => Value::Table(
+            object
+                .object
+                .as_ref()
+                .map(|table| {
+                    table
+                        .properties
+                        .iter()
+                        .filter(|(name, _)| table.required.contains(*name))
+                        .map(|(name, property)| (name.clone(), example(root, property)))
+                        .collect()
+                })
+                .unwrap_or_default(),
+        ),
+    }
+}
@@ -0,0 +1,320 @@
+use std::{
+    fs::{self, File},
+    io::{BufWriter, Write},
+    path::{Path, PathBuf},
+    sync::{Arc, Mutex, RwLock},
+};
+
+use anyhow::{Context, Result};
+use arbiter_core::middleware::ArbiterMiddleware;
+use ethers::{
+    contract::LogMeta,
+    types::{Address, H256},
+};
+use futures::{FutureExt, StreamExt};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use tokio::{
+    sync::{oneshot, watch},
+    task::JoinHandle,
+};
+use tracing::{debug, warn};
+
+use crate::{
+    bindings::modified_counter::{ModifiedCounter, ModifiedCounterEvents},
+    replay, tx,
+};
+
+/// The collector currently receiving events, if the simulation configured one.
+static ACTIVE: RwLock<Option<Arc<Collector>>> = RwLock::new(None);
+
+/// The `[collector]` table of a simulation config.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct CollectorConfig {
+    pub path: PathBuf,
+    /// Inferred from the extension of `path` when omitted.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub format: Option<OutputFormat>,
+}
+
+impl CollectorConfig {
+    pub fn format(&self) -> OutputFormat {
+        self.format.unwrap_or_else(
+            || match self.path.extension().and_then(|ext| ext.to_str()) {
+                Some("json" | "jsonl" | "ndjson") => OutputFormat::Json,
+                _ => OutputFormat::Csv,
+            },
+        )
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum OutputFormat {
+    Csv,
+    /// Newline-delimited JSON, one record per line.
+    Json,
+}
+
+/// A single `ModifiedCounter` event as written to the output file.
+#[derive(Debug, Clone, Serialize)]
+pub struct EventRecord {
+    pub block_number: u64,
+    pub timestamp: u64,
+    /// The agent that sent the transaction emitting the event.
+    pub agent: String,
+    pub contract: Address,
+    pub tx_hash: H256,
+    pub event: &'static str,
+    pub new_number: String,
+}
+
+/// A trade of an `Arbitrageur` as written to the trades file. Values are in
+/// quote tokens.
+#[derive(Debug, Clone, Serialize)]
+pub struct TradeRecord {
+    pub block_number: u64,
+    pub agent: String,
+    pub tx_hash: H256,
+    /// `true` if the trade sold base tokens, `false` if it bought them.
+    pub sell_base: bool,
+    pub amount_in: String,
+    pub amount_out: String,
+    /// Price of a base token the trade moved the pool towards.
+    pub reference_price: f64,
+    pub pool_price_before: f64,
+    pub pool_price_after: f64,
+    /// Profit the trade was expected to make, net of gas.
+    pub expected_profit: f64,
+    pub gas_used: u64,
+    pub gas_cost: f64,
+    /// Change of the trader's holdings valued at the reference price, net of
+    /// gas.
+    pub realized_profit: f64,
+}
+
+/// Path of the trades file written next to the events file at `path`, e.g.
+/// `events_trades.csv` for `events.csv`.
+pub fn trades_path(path: &Path) -> PathBuf {
+    let stem = path
+        .file_stem()
+        .map(|stem| stem.to_string_lossy().into_owned())
+        .unwrap_or_default();
+    let file_name = match path.extension() {
+        Some(extension) => format!("{}_trades.{}", stem, extension.to_string_lossy()),
+        None => format!("{}_trades", stem),
+    };
+    path.with_file_name(file_name)
+}
+
+enum Sink {
+    Csv(csv::Writer<File>),
+    Json(BufWriter<File>),
+}
+
+impl Sink {
+    fn create(path: &Path, format: OutputFormat) -> Result<Self> {
+        let file =
+            File::create(path).with_context(|| format!("failed to create {}", path.display()))?;
+        Ok(match format {
+            OutputFormat::Csv => Sink::Csv(csv::Writer::from_writer(file)),
+            OutputFormat::Json => Sink::Json(BufWriter::new(file)),
+        })
+    }
+
+    fn write<R: Serialize>(&mut self, record: &R) -> Result<()> {
+        match self {
+            Sink::Csv(writer) => writer.serialize(record)?,
+            Sink::Json(writer) => {
+                serde_json::to_writer(&mut *writer, record)?;
+                writer.write_all(b"\n")?;
+            }
+        }
+        Ok(())
+    }
+
+    fn flush(&mut self) -> Result<()> {
+        match self {
+            Sink::Csv(writer) => writer.flush()?,
+            Sink::Json(writer) => writer.flush()?,
+        }
+        Ok(())
+    }
+}
+
+/// Streams the events of every registered `ModifiedCounter` to a file, and
+/// the trades of arbitrageurs to a second one created on the first trade.
+pub struct Collector {
+    sink: Mutex<Sink>,
+    trades: Mutex<Option<Sink>>,
+    trades_path: PathBuf,
+    format: OutputFormat,
+    tasks: Mutex<Vec<JoinHandle<Result<()>>>>,
+    /// Tells the tasks to stop once they have written the events already
+    /// emitted.
+    stop: watch::Sender<bool>,
+}
+
+impl Collector {
+    pub fn new(config: &CollectorConfig) -> Result<Self> {
+        if let Some(parent) = config.path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        Ok(Self {
+            sink: Mutex::new(Sink::create(&config.path, config.format())?),
+            trades: Mutex::new(None),
+            trades_path: trades_path(&config.path),
+            format: config.format(),
+            tasks: Mutex::new(Vec::new()),
+            stop: watch::channel(false).0,
+        })
+    }
+
+    /// Subscribes to all events of `counter`. Returns once the subscription is
+    /// live so no event emitted afterwards is missed.
+    pub async fn watch(
+        self: &Arc<Self>,
+        counter: &ModifiedCounter<ArbiterMiddleware>,
+    ) -> Result<()> {
+        let client = counter.client();
+        let events = counter.events();
+        let collector = self.clone();
+        let mut stop = self.stop.subscribe();
+        let (ready_tx, ready_rx) = oneshot::channel();
+        let task = tokio::spawn(async move {
+            let mut stream = match events.stream_with_meta().await {
+                Ok(stream) => {
+                    let _ = ready_tx.send(Ok(()));
+                    stream
+                }
+                Err(e) => {
+                    let _ = ready_tx.send(Err(anyhow::Error::from(e)));
+                    return Ok(());
+                }
+            };
+            let mut stopping = false;
+            loop {
+                let next = if stopping {
+                    // Events emitted before the stop are ready right away.
+                    match stream.next().now_or_never() {
+                        Some(next) => next,
+                        None => return Ok(()),
+                    }
+                } else {
+                    tokio::select! {
+                        biased;
+                        next = stream.next() => next,
+                        _ = stop.changed() => {
+                            stopping = true;
+                            continue;
+                        }
+                    }
+                };
+                let Some(next) = next else {
+                    return Ok(());
+                };
+                match next {
+                    Ok((event, meta)) => collector.collect(&client, event, meta).await?,
+                    Err(e) => warn!("Collector failed to decode event: {}", e),
+                }
+            }
+        });
+        self.tasks.lock().unwrap().push(task);
+        ready_rx
+            .await
+            .context("collector task exited before subscribing")?
+    }
+
+    async fn collect(
+        &self,
+        client: &ArbiterMiddleware,
+        event: ModifiedCounterEvents,
+        meta: LogMeta,
+    ) -> Result<()> {
+        let (name, new_number) = match event {
+            ModifiedCounterEvents::IncrementedFilter(e) => ("Incremented", e.new_number),
+            ModifiedCounterEvents::NumberSetFilter(e) => ("NumberSet", e.new_number),
+        };
+        let block_number = meta.block_number.as_u64();
+        let timestamp = replay::block_timestamp(client, block_number)
+            .await
+            .with_context(|| format!("failed to collect {:?}", meta.transaction_hash))?;
+        let record = EventRecord {
+            block_number,
+            timestamp: timestamp.as_u64(),
+            agent: tx::sender_of(meta.transaction_hash)
+                .await
+                .unwrap_or_default(),
+            contract: meta.address,
+            tx_hash: meta.transaction_hash,
+            event: name,
+            new_number: new_number.to_string(),
+        };
+        debug!("Collected {:?}", record);
+        if let Err(e) = self.sink.lock().unwrap().write(&record) {
+            warn!("Collector failed to write event: {}", e);
+        }
+        Ok(())
+    }
+
+    fn record_trade(&self, record: &TradeRecord) -> Result<()> {
+        let mut trades = self.trades.lock().unwrap();
+        let sink = match trades.take() {
+            Some(sink) => sink,
+            None => Sink::create(&self.trades_path, self.format)?,
+        };
+        trades.insert(sink).write(record)
+    }
+
+    /// Stops all subscriptions once they have written the events already
+    /// emitted, and flushes the output files. Returns the first error a
+    /// subscription stopped with.
+    pub async fn finish(&self) -> Result<()> {
+        self.stop.send_replace(true);
+        let tasks: Vec<_> = self.tasks.lock().unwrap().drain(..).collect();
+        let mut result = Ok(());
+        for task in tasks {
+            let outcome = task.await.context("collector task panicked")?;
+            if result.is_ok() {
+                result = outcome;
+            }
+        }
+        if let Some(trades) = self.trades.lock().unwrap().as_mut() {
+            trades.flush()?;
+        }
+        self.sink.lock().unwrap().flush()?;
+        result
+    }
+}
+
+/// Makes `collector` the destination of [`watch`] for the current run.
+pub fn install(collector: Arc<Collector>) {
+    *ACTIVE.write().unwrap() = Some(collector);
+}
+
+/// Removes and returns the collector of the current run.
+pub fn uninstall() -> Option<Arc<Collector>> {
+    ACTIVE.write().unwrap().take()
+}
+
+/// Registers `counter` with the active collector. Does nothing if the
+/// simulation has no `[collector]` configured.
+pub async fn watch(counter: &ModifiedCounter<ArbiterMiddleware>) -> Result<()> {
+    let collector = ACTIVE.read().unwrap().clone();
+    match collector {
+        Some(collector) => collector.watch(counter).await,
+        None => Ok(()),
+    }
+}
+
+/// Writes `record` to the trades file of the active collector. Does nothing
+/// if the simulation has no `[collector]` configured.
+pub fn record_trade(record: &TradeRecord) {
+    let collector = ACTIVE.read().unwrap().clone();
+    if let Some(collector) = collector {
+        if let Err(e) = collector.record_trade(record) {
+            warn!("Collector failed to write trade: {}", e);
+        }
+    }
+}
@@ -0,0 +1,121 @@
+use std::{
+    collections::BTreeMap,
+    path::{Path, PathBuf},
+};
+
+use anyhow::{ensure, Result};
+use arbiter_engine::{
+    agent::Agent,
+    machine::{CreateStateMachine, Engine, StateMachine},
+    world::World,
+};
+use schemars::{schema::RootSchema, schema_for, JsonSchema};
+use serde::{Deserialize, Serialize};
+use toml::{Table, Value};
+
+use crate::{
+    behaviors::Behaviors,
+    collector::CollectorConfig,
+    limits::{self, Limiter, Limits},
+    replicate,
+    report::Recorded,
+    resolve,
+};
+
+/// A simulation config file.
+///
+/// Every table array is an agent whose entries are the behaviors it runs, the
+/// remaining top-level keys configure the simulation itself.
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
+pub struct SimulationConfig {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub seed: Option<u64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub collector: Option<CollectorConfig>,
+    /// Stop the run once the environment moved this many blocks forward.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_blocks: Option<u64>,
+    /// Stop the run once this many transactions were mined.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_transactions: Option<u64>,
+    /// Stop the run once its transactions emitted this many events.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_events: Option<u64>,
+    /// Stop the run after this many seconds of wall-clock time.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub timeout_secs: Option<u64>,
+    #[serde(flatten)]
+    pub agents: BTreeMap<String, Vec<Behaviors>>,
+}
+
+impl SimulationConfig {
+    pub fn from_file(path: impl AsRef<Path>) -> Result<Self> {
+        Self::from_table(read_table(path)?)
+    }
+
+    /// Interprets a config, creating the agents of tables with a `count`.
+    pub fn from_table(mut table: Table) -> Result<Self> {
+        replicate::expand(&mut table)?;
+        let config = Self::deserialize(Value::Table(table))?;
+        ensure!(
+            config.limits().is_empty() || !config.agents.contains_key(limits::AGENT),
+            "agent `{}` is reserved for enforcing limits",
+            limits::AGENT
+        );
+        Ok(config)
+    }
+
+    /// The limits of the run.
+    pub fn limits(&self) -> Limits {
+        Limits {
+            max_blocks: self.max_blocks,
+            max_transactions: self.max_transactions,
+            max_events: self.max_events,
+            timeout_secs: self.timeout_secs,
+        }
+    }
+
+    /// Points every output file of the run into `dir`, keeping file names.
+    pub fn place_outputs_in(&mut self, dir: &Path) {
+        if let Some(collector) = self.collector.as_mut() {
+            let file_name = collector
+                .path
+                .file_name()
+                .map(PathBuf::from)
+                .unwrap_or_else(|| PathBuf::from("events.csv"));
+            collector.path = dir.join(file_name);
+        }
+    }
+
+    /// Builds a [`World`] with one agent per agent table, plus an agent
+    /// enforcing the limits of the run if it has any.
+    pub fn build_world(self) -> World {
+        let mut world = World::new("world");
+        let limits = self.limits();
+        if !limits.is_empty() {
+            let limiter = Recorded::new(limits::BEHAVIOR, Limiter::new(limits));
+            let limiter: Box<dyn StateMachine> = Box::new(Engine::new(limiter));
+            world.add_agent(Agent::builder(limits::AGENT).with_engine(limiter));
+        }
+        for (id, behaviors) in self.agents {
+            let mut agent = Agent::builder(&id);
+            for behavior in behaviors {
+                agent = agent.with_engine(behavior.create_state_machine());
+            }
+            world.add_agent(agent);
+        }
+        world
+    }
+}
+
+/// JSON Schema of a config file, with every behavior of [`Behaviors`], for
+/// editors to complete and check configs.
+pub fn schema() -> RootSchema {
+    schema_for!(SimulationConfig)
+}
+
+/// Reads a config file without interpreting it, e.g. to expand sweeps first.
+/// Includes, presets and environment variables are already resolved.
+pub fn read_table(path: impl AsRef<Path>) -> Result<Table> {
+    resolve::load(path.as_ref())
+}
@@ -0,0 +1,289 @@
+use arbiter_core::{errors::ArbiterCoreError, middleware::ArbiterMiddleware};
+use arbiter_engine::{errors::ArbiterEngineError, machine::ControlFlow};
+use ethers::{
+    abi::{Abi, AbiDecode, Token},
+    contract::{ContractError, ContractRevert},
+    providers::ProviderError,
+    types::{Bytes, H256, U256},
+};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+use tracing::{error, warn};
+
+use crate::{
+    bindings::{
+        constant_product_pool::CONSTANTPRODUCTPOOL_ABI, guarded_counter::GUARDEDCOUNTER_ABI,
+        price_oracle::PRICEORACLE_ABI, token::TOKEN_ABI,
+    },
+    report,
+};
+
+/// Selector of Solidity's `Error(string)`.
+const ERROR_SELECTOR: [u8; 4] = [0x08, 0xc3, 0x79, 0xa0];
+/// Selector of Solidity's `Panic(uint256)`.
+const PANIC_SELECTOR: [u8; 4] = [0x4e, 0x48, 0x7b, 0x71];
+
+/// Errors behaviors of this project run into.
+#[derive(Debug, Error)]
+pub enum SimulationError {
+    /// A transaction reverted, `reason` is decoded from its revert `data`,
+    /// which is empty if only the receipt told the transaction failed.
+    #[error("transaction reverted: {reason}")]
+    Reverted { reason: String, data: Bytes },
+
+    #[error("transaction {0:?} was sent but has no receipt")]
+    MissingReceipt(H256),
+
+    /// State set up in `startup` was used before `startup` succeeded.
+    #[error("`{0}` is not initialized, did `startup` succeed?")]
+    Uninitialized(&'static str),
+
+    #[error("messaging failed: {0}")]
+    Messaging(String),
+
+    /// A `Stop` arrived while the behavior was still starting up, e.g. waiting
+    /// for a contract to be deployed.
+    #[error("stopped: {0}")]
+    Stopped(String),
+
+    /// Arithmetic on token amounts overflowed or divided by zero.
+    #[error("computing `{0}` overflowed")]
+    Overflow(&'static str),
+
+    #[error(transparent)]
+    Contract(ContractError<ArbiterMiddleware>),
+
+    #[error(transparent)]
+    Provider(#[from] ProviderError),
+}
+
+impl From<ContractError<ArbiterMiddleware>> for SimulationError {
+    fn from(e: ContractError<ArbiterMiddleware>) -> Self {
+        let data = match &e {
+            ContractError::Revert(data) => Some(data.to_vec()),
+            ContractError::MiddlewareError {
+                e: ArbiterCoreError::ExecutionRevert { output, .. },
+            } => Some(output.to_vec()),
+            _ => None,
+        };
+        match data {
+            Some(data) => SimulationError::Reverted {
+                reason: decode_revert(&data),
+                data: data.into(),
+            },
+            None => SimulationError::Contract(e),
+        }
+    }
+}
+
+impl SimulationError {
+    /// Decodes the revert data of a reverted transaction into the custom
+    /// errors of a contract, e.g. `GuardedCounterErrors`. `None` if the
+    /// transaction did not revert or reverted with another error.
+    pub fn revert<E: ContractRevert>(&self) -> Option<E> {
+        match self {
+            SimulationError::Reverted { data, .. } => E::decode_with_selector(data),
+            _ => None,
+        }
+    }
+}
+
+impl From<ArbiterEngineError> for SimulationError {
+    fn from(e: ArbiterEngineError) -> Self {
+        SimulationError::Messaging(e.to_string())
+    }
+}
+
+/// Decodes the revert data of a failed call into a readable reason.
+pub fn decode_revert(data: &[u8]) -> String {
+    if data.is_empty() {
+        return "no reason given".to_owned();
+    }
+    let (selector, args) = data.split_at(data.len().min(4));
+    if selector == ERROR_SELECTOR {
+        if let Ok(reason) = String::decode(args) {
+            return reason;
+        }
+    }
+    if selector == PANIC_SELECTOR {
+        if let Ok(code) = U256::decode(args) {
+            return format!("panic 0x{:02x}", code);
+        }
+    }
+    if let Some(error) = decode_custom_error(selector, args) {
+        return error;
+    }
+    format!("0x{}", hex(data))
+}
+
+/// Decodes a custom error of one of the project's contracts with the ABIs
+/// their bindings are generated from, e.g. into `Unauthorized(0x…)`.
+fn decode_custom_error(selector: &[u8], args: &[u8]) -> Option<String> {
+    let abis: [&Abi; 4] = [
+        &CONSTANTPRODUCTPOOL_ABI,
+        &GUARDEDCOUNTER_ABI,
+        &PRICEORACLE_ABI,
+        &TOKEN_ABI,
+    ];
+    let error = abis
+        .into_iter()
+        .flat_map(Abi::errors)
+        .find(|error| error.signature()[..4] == *selector)?;
+    let tokens = error.decode(args).ok()?;
+    let args: Vec<String> = tokens
+        .iter()
+        .map(|token| match token {
+            Token::Address(address) => format!("{:?}", address),
+            token => token.to_string(),
+        })
+        .collect();
+    Some(format!("{}({})", error.name, args.join(", ")))
+}
+
+fn hex(data: &[u8]) -> String {
+    data.iter().map(|byte| format!("{:02x}", byte)).collect()
+}
+
+/// What a behavior does when handling an event fails, set with `on_error` in
+/// its config entry.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum ErrorPolicy {
+    /// Stop the behavior, e.g. `on_error = "halt"`.
+    #[default]
+    Halt,
+    /// Send a failing transaction again up to this many times before halting,
+    /// e.g. `on_error = { retry = 3 }`. Other errors halt right away.
+    Retry(u32),
+    /// Drop the event and carry on, e.g. `on_error = "skip"`.
+    Skip,
+}
+
+impl ErrorPolicy {
+    /// Number of times a failing transaction is sent again, see
+    /// [`tx::send_retrying`](crate::tx::send_retrying).
+    pub fn retries(&self) -> u32 {
+        match *self {
+            ErrorPolicy::Retry(retries) => retries,
+            ErrorPolicy::Halt | ErrorPolicy::Skip => 0,
+        }
+    }
+
+    /// Applies the policy to the `result` of handling an event, returning
+    /// `None` if the event is skipped.
+    ///
+    /// A behavior that only reacts to events of its own transactions gets no
+    /// further event after a skipped one, so it moves on to its next action
+    /// instead of waiting.
+    pub fn resolve(
+        &self,
+        result: anyhow::Result<ControlFlow>,
+        agent: &str,
+        behavior: &str,
+    ) -> Option<anyhow::Result<ControlFlow>> {
+        let e = match result {
+            Ok(flow) => return Some(Ok(flow)),
+            Err(e) => e,
+        };
+        report::errored(agent);
+        match *self {
+            ErrorPolicy::Skip => {
+                warn!("{} of {} failed, skipping: {:#}", behavior, agent, e);
+                None
+            }
+            ErrorPolicy::Halt | ErrorPolicy::Retry(_) => {
+                error!("{} of {} failed, halting: {:#}", behavior, agent, e);
+                report::failed(agent, behavior, format!("{:#}", e));
+                Some(Ok(ControlFlow::Halt))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ethers::{abi::AbiEncode, types::Address};
+
+    use super::*;
+    use crate::bindings::guarded_counter::{GuardedCounterErrors, Overflow, Unauthorized};
+
+    fn failed() -> anyhow::Result<ControlFlow> {
+        Err(anyhow::anyhow!("boom"))
+    }
+
+    #[test]
+    fn decodes_error_strings_and_panics() {
+        let reason = [&ERROR_SELECTOR[..], &"not enough".to_owned().encode()].concat();
+        assert_eq!(decode_revert(&reason), "not enough");
+        let panic = [&PANIC_SELECTOR[..], &U256::from(0x11).encode()].concat();
+        assert_eq!(decode_revert(&panic), "panic 0x11");
+        assert_eq!(decode_revert(&[]), "no reason given");
+    }
+
+    #[test]
+    fn decodes_custom_errors_of_the_project_contracts() {
+        let caller = Address::from_low_u64_be(7);
+        let unauthorized = GuardedCounterErrors::Unauthorized(Unauthorized { caller }).encode();
+        assert_eq!(
+            decode_revert(&unauthorized),
+            format!("Unauthorized({:?})", caller)
+        );
+        let overflow = GuardedCounterErrors::Overflow(Overflow).encode();
+        assert_eq!(decode_revert(&overflow), "Overflow()");
+
+        let error = SimulationError::Reverted {
+            reason: decode_revert(&unauthorized),
+            data: unauthorized.into(),
+        };
+        assert_eq!(
+            error.revert::<GuardedCounterErrors>(),
+            Some(GuardedCounterErrors::Unauthorized(Unauthorized { caller }))
+        );
+    }
+
+    #[test]
+    fn unknown_revert_data_is_shown_as_hex() {
+        assert_eq!(
+            decode_revert(&[0xde, 0xad, 0xbe, 0xef, 0x01]),
+            "0xdeadbeef01"
+        );
+        assert_eq!(decode_revert(&[0xab]), "0xab");
+        // A known selector with arguments it cannot decode.
+        assert_eq!(
+            decode_revert(&[&ERROR_SELECTOR[..], &[0x01][..]].concat()),
+            "0x08c379a001"
+        );
+    }
+
+    #[test]
+    fn every_policy_passes_successes_through() {
+        for policy in [ErrorPolicy::Halt, ErrorPolicy::Retry(2), ErrorPolicy::Skip] {
+            let flow = policy.resolve(Ok(ControlFlow::Continue), "agent", "Behavior");
+            assert!(matches!(flow, Some(Ok(ControlFlow::Continue))));
+        }
+    }
+
+    #[test]
+    fn halt_stops_the_behavior_on_errors() {
+        let flow = ErrorPolicy::Halt.resolve(failed(), "agent", "Behavior");
+        assert!(matches!(flow, Some(Ok(ControlFlow::Halt))));
+        assert_eq!(ErrorPolicy::Halt.retries(), 0);
+    }
+
+    #[test]
+    fn retry_resends_transactions_and_then_halts() {
+        assert_eq!(ErrorPolicy::Retry(3).retries(), 3);
+        // Once the retries of the transaction are used up, the error halts.
+        let flow = ErrorPolicy::Retry(3).resolve(failed(), "agent", "Behavior");
+        assert!(matches!(flow, Some(Ok(ControlFlow::Halt))));
+    }
+
+    #[test]
+    fn skip_drops_the_event_on_errors() {
+        assert!(ErrorPolicy::Skip
+            .resolve(failed(), "agent", "Behavior")
+            .is_none());
+        assert_eq!(ErrorPolicy::Skip.retries(), 0);
+    }
+}
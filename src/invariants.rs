@@ -0,0 +1,211 @@
+use ethers::{
+    abi::AbiDecode,
+    types::{H256, U256},
+};
+use serde::{Deserialize, Serialize};
+
+use crate::bindings::modified_counter::{ModifiedCounterCalls, ModifiedCounterEvents};
+
+/// A property of a `ModifiedCounter` that must hold after every event it
+/// emits, checked against the operations its transactions called and the
+/// numbers its events report. Configs refer to invariants by `name`, see
+/// [`ALL`].
+pub struct Invariant {
+    pub name: &'static str,
+    pub description: &'static str,
+    /// Returns why the transition from `before` to `after` breaks the
+    /// invariant.
+    pub check: fn(before: &CounterState, after: &CounterState) -> Result<(), String>,
+}
+
+/// Every invariant an `InvariantChecker` can be configured with.
+pub const ALL: &[Invariant] = &[
+    Invariant {
+        name: "number_matches_operations",
+        description: "the reported number is the last `setNumber` value plus the increments since",
+        check: number_matches_operations,
+    },
+    Invariant {
+        name: "increments_by_one",
+        description: "every `increment()` reports a number exactly one above the previous one",
+        check: increments_by_one,
+    },
+];
+
+/// Looks up the invariant called `name`.
+pub fn find(name: &str) -> Option<&'static Invariant> {
+    ALL.iter().find(|invariant| invariant.name == name)
+}
+
+fn number_matches_operations(_before: &CounterState, after: &CounterState) -> Result<(), String> {
+    let expected = after.base + U256::from(after.increments_since_base);
+    if after.number == expected {
+        Ok(())
+    } else {
+        Err(format!(
+            "number is {} but {} + {} increments is {}",
+            after.number, after.base, after.increments_since_base, expected
+        ))
+    }
+}
+
+fn increments_by_one(before: &CounterState, after: &CounterState) -> Result<(), String> {
+    if after.increments == before.increments || after.number == before.number + U256::one() {
+        Ok(())
+    } else {
+        Err(format!(
+            "increment moved number from {} to {}",
+            before.number, after.number
+        ))
+    }
+}
+
+/// What a transaction asked the counter to do.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Operation {
+    Increment,
+    SetNumber(U256),
+}
+
+impl Operation {
+    /// Decodes the calldata of a transaction, `None` if it does not change the
+    /// counter.
+    pub fn decode(calldata: &[u8]) -> Option<Self> {
+        match ModifiedCounterCalls::decode(calldata).ok()? {
+            ModifiedCounterCalls::Increment(_) => Some(Operation::Increment),
+            ModifiedCounterCalls::SetNumber(call) => Some(Operation::SetNumber(call.new_number)),
+            ModifiedCounterCalls::Number(_) => None,
+        }
+    }
+}
+
+/// A counter as the invariant checker sees it, built from the operations its
+/// transactions called and the number each of its events reports.
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CounterState {
+    /// The number the latest event reports.
+    pub number: U256,
+    /// The argument of the last `setNumber` call, or the number when the
+    /// checker started watching.
+    pub base: U256,
+    pub increments_since_base: u64,
+    pub increments: u64,
+    pub sets: u64,
+}
+
+impl CounterState {
+    /// The state right before `operation` emitted `event`, for a checker that
+    /// has not seen any earlier event of the counter.
+    pub fn before(operation: Operation, event: &ModifiedCounterEvents) -> Self {
+        match operation {
+            Operation::Increment => {
+                let number = reported(event).saturating_sub(U256::one());
+                Self {
+                    number,
+                    base: number,
+                    ..Self::default()
+                }
+            }
+            Operation::SetNumber(_) => Self::default(),
+        }
+    }
+
+    /// The state after `operation` emitted `event`.
+    pub fn apply(&self, operation: Operation, event: &ModifiedCounterEvents) -> Self {
+        match operation {
+            Operation::Increment => Self {
+                number: reported(event),
+                increments_since_base: self.increments_since_base + 1,
+                increments: self.increments + 1,
+                ..self.clone()
+            },
+            Operation::SetNumber(value) => Self {
+                number: reported(event),
+                base: value,
+                increments_since_base: 0,
+                sets: self.sets + 1,
+                ..self.clone()
+            },
+        }
+    }
+}
+
+fn reported(event: &ModifiedCounterEvents) -> U256 {
+    match event {
+        ModifiedCounterEvents::IncrementedFilter(e) => e.new_number,
+        ModifiedCounterEvents::NumberSetFilter(e) => e.new_number,
+    }
+}
+
+/// An invariant that failed, with the event that broke it.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Violation {
+    pub invariant: String,
+    pub message: String,
+    pub block_number: u64,
+    pub tx_hash: H256,
+    /// The agent that sent the transaction, empty if unknown.
+    pub agent: String,
+    pub before: CounterState,
+    pub after: CounterState,
+}
+
+#[cfg(test)]
+mod tests {
+    use ethers::abi::AbiEncode;
+
+    use super::*;
+    use crate::bindings::modified_counter::{
+        IncrementCall, IncrementedFilter, NumberCall, NumberSetFilter, SetNumberCall,
+    };
+
+    fn incremented(new_number: u64) -> ModifiedCounterEvents {
+        ModifiedCounterEvents::IncrementedFilter(IncrementedFilter {
+            new_number: new_number.into(),
+        })
+    }
+
+    fn number_set(new_number: u64) -> ModifiedCounterEvents {
+        ModifiedCounterEvents::NumberSetFilter(NumberSetFilter {
+            new_number: new_number.into(),
+        })
+    }
+
+    #[test]
+    fn decodes_operations_from_calldata() {
+        let set = SetNumberCall {
+            new_number: 7.into(),
+        };
+        assert_eq!(
+            Operation::decode(&set.encode()),
+            Some(Operation::SetNumber(7.into()))
+        );
+        assert_eq!(
+            Operation::decode(&IncrementCall.encode()),
+            Some(Operation::Increment)
+        );
+        assert_eq!(Operation::decode(&NumberCall.encode()), None);
+        assert_eq!(Operation::decode(&[0xde, 0xad]), None);
+    }
+
+    #[test]
+    fn number_matches_the_operations_called() {
+        let before = CounterState::before(Operation::SetNumber(5.into()), &number_set(5));
+        let set = before.apply(Operation::SetNumber(5.into()), &number_set(5));
+        assert_eq!(number_matches_operations(&before, &set), Ok(()));
+        let incremented = set.apply(Operation::Increment, &incremented(6));
+        assert_eq!(number_matches_operations(&set, &incremented), Ok(()));
+    }
+
+    #[test]
+    fn number_differing_from_the_set_argument_breaks_the_invariant() {
+        let before = CounterState::before(Operation::SetNumber(5.into()), &number_set(6));
+        let after = before.apply(Operation::SetNumber(5.into()), &number_set(6));
+        assert!(number_matches_operations(&before, &after).is_err());
+        // The number still moved by one per increment.
+        let incremented = after.apply(Operation::Increment, &incremented(7));
+        assert_eq!(increments_by_one(&after, &incremented), Ok(()));
+        assert!(number_matches_operations(&after, &incremented).is_err());
+    }
+}
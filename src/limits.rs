@@ -0,0 +1,308 @@
+use std::{
+    sync::Arc,
+    time::{Duration, Instant},
+};
+
+use anyhow::Result;
+use arbiter_core::middleware::ArbiterMiddleware;
+use arbiter_engine::{
+    machine::{Behavior, ControlFlow, EventStream},
+    messager::Messager,
+};
+use ethers::providers::Middleware;
+use futures::{stream, StreamExt};
+use serde::{Deserialize, Serialize};
+use tracing::{debug, info};
+
+use crate::{
+    errors::SimulationError,
+    messages::{MessagerExt, Stop},
+    report, tx,
+};
+
+/// Name of the agent enforcing the limits of a run.
+pub const AGENT: &str = "limits";
+
+/// Name the limiter is recorded under in the run report.
+pub const BEHAVIOR: &str = "Limiter";
+
+/// How often limits are checked besides after every mined transaction, which
+/// bounds how late `timeout_secs` and `max_blocks` are noticed.
+const CHECK_INTERVAL: Duration = Duration::from_millis(50);
+
+/// The limits of a run, set by the top-level keys of the same names.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct Limits {
+    pub max_blocks: Option<u64>,
+    pub max_transactions: Option<u64>,
+    pub max_events: Option<u64>,
+    pub timeout_secs: Option<u64>,
+}
+
+impl Limits {
+    pub fn is_empty(&self) -> bool {
+        self.max_blocks.is_none()
+            && self.max_transactions.is_none()
+            && self.max_events.is_none()
+            && self.timeout_secs.is_none()
+    }
+
+    /// The first limit `progress` has reached, if any.
+    fn reached(&self, progress: &Progress) -> Option<LimitReached> {
+        let checks = [
+            ("max_blocks", self.max_blocks, progress.blocks),
+            (
+                "max_transactions",
+                self.max_transactions,
+                progress.transactions,
+            ),
+            ("max_events", self.max_events, progress.events),
+            ("timeout_secs", self.timeout_secs, progress.elapsed_secs),
+        ];
+        checks.into_iter().find_map(|(limit, max, value)| {
+            max.filter(|max| value >= *max).map(|max| LimitReached {
+                limit: limit.to_owned(),
+                max,
+                value,
+            })
+        })
+    }
+}
+
+/// How far a run has got, in the units of its [`Limits`].
+#[derive(Debug, Default, Clone, Copy)]
+struct Progress {
+    blocks: u64,
+    transactions: u64,
+    events: u64,
+    elapsed_secs: u64,
+}
+
+/// The limit that stopped a run.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LimitReached {
+    /// The config key of the limit, e.g. `max_blocks`.
+    pub limit: String,
+    pub max: u64,
+    /// The value that reached `max`.
+    pub value: u64,
+}
+
+/// Stops every behavior once a limit of the run is reached. Runs as the
+/// observer of its own agent, added to the world by the runtime whenever a
+/// config sets a limit.
+#[derive(Debug)]
+pub struct Limiter {
+    limits: Limits,
+    client: Option<Arc<ArbiterMiddleware>>,
+    messager: Option<Messager>,
+    first_block: u64,
+    started: Instant,
+}
+
+impl Limiter {
+    pub fn new(limits: Limits) -> Self {
+        Self {
+            limits,
+            client: None,
+            messager: None,
+            first_block: 0,
+            started: Instant::now(),
+        }
+    }
+
+    /// The first limit the run has reached, if any.
+    async fn reached(&self) -> Result<Option<LimitReached>> {
+        let client = self
+            .client
+            .as_ref()
+            .ok_or(SimulationError::Uninitialized("client"))?;
+        let totals = report::totals();
+        let blocks = (client.get_block_number().await?.as_u64()).saturating_sub(self.first_block);
+        Ok(self.limits.reached(&Progress {
+            blocks,
+            transactions: totals.transactions,
+            events: totals.events,
+            elapsed_secs: self.started.elapsed().as_secs(),
+        }))
+    }
+}
+
+/// What the limiter reacts to.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum LimitEvent {
+    Check,
+    Stop(Stop),
+}
+
+#[async_trait::async_trait]
+impl Behavior<LimitEvent> for Limiter {
+    async fn startup(
+        &mut self,
+        client: Arc<ArbiterMiddleware>,
+        messager: Messager,
+    ) -> Result<Option<EventStream<LimitEvent>>> {
+        debug!("Limiter starting up with {:?}", self.limits);
+        self.first_block = client.get_block_number().await?.as_u64();
+        self.started = Instant::now();
+
+        let mut interval = tokio::time::interval(CHECK_INTERVAL);
+        interval.reset();
+        let ticks: EventStream<LimitEvent> =
+            Box::pin(stream::unfold(interval, |mut interval| async move {
+                interval.tick().await;
+                Some((LimitEvent::Check, interval))
+            }));
+        let mined: EventStream<LimitEvent> =
+            Box::pin(tx::stream_mined().map(|_| LimitEvent::Check));
+        let stops: EventStream<LimitEvent> = Box::pin(
+            messager
+                .clone()
+                .subscribe::<Stop>()?
+                .map(|stop| LimitEvent::Stop(stop.message)),
+        );
+        let done: EventStream<LimitEvent> =
+            Box::pin(stream::once(report::workers_halted()).map(|_| {
+                LimitEvent::Stop(Stop {
+                    reason: "all workers halted".to_owned(),
+                })
+            }));
+        self.client = Some(client);
+        self.messager = Some(messager);
+        Ok(Some(Box::pin(stream::select_all([
+            ticks, mined, stops, done,
+        ]))))
+    }
+
+    async fn process(&mut self, event: LimitEvent) -> Result<ControlFlow> {
+        if let LimitEvent::Stop(stop) = event {
+            report::halted(AGENT, BEHAVIOR, format!("stopped: {}", stop.reason));
+            return Ok(ControlFlow::Halt);
+        }
+        let Some(reached) = self.reached().await? else {
+            return Ok(ControlFlow::Continue);
+        };
+        let reason = format!(
+            "limit `{}` of {} reached at {}",
+            reached.limit, reached.max, reached.value
+        );
+        info!("Stopping the run: {}", reason);
+        report::limit_reached(reached);
+        let messager = self
+            .messager
+            .as_ref()
+            .ok_or(SimulationError::Uninitialized("messager"))?;
+        messager
+            .broadcast(Stop {
+                reason: reason.clone(),
+            })
+            .await?;
+        report::halted(AGENT, BEHAVIOR, reason);
+        Ok(ControlFlow::Halt)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn reached(limit: &str, max: u64, value: u64) -> Option<LimitReached> {
+        Some(LimitReached {
+            limit: limit.to_owned(),
+            max,
+            value,
+        })
+    }
+
+    #[test]
+    fn blocks_reach_max_blocks() {
+        let limits = Limits {
+            max_blocks: Some(10),
+            ..Limits::default()
+        };
+        let progress = |blocks| Progress {
+            blocks,
+            ..Progress::default()
+        };
+        assert_eq!(limits.reached(&progress(9)), None);
+        assert_eq!(limits.reached(&progress(10)), reached("max_blocks", 10, 10));
+        assert_eq!(limits.reached(&progress(12)), reached("max_blocks", 10, 12));
+    }
+
+    #[test]
+    fn transactions_reach_max_transactions() {
+        let limits = Limits {
+            max_transactions: Some(5),
+            ..Limits::default()
+        };
+        let progress = |transactions| Progress {
+            transactions,
+            ..Progress::default()
+        };
+        assert_eq!(limits.reached(&progress(4)), None);
+        assert_eq!(
+            limits.reached(&progress(5)),
+            reached("max_transactions", 5, 5)
+        );
+    }
+
+    #[test]
+    fn events_reach_max_events() {
+        let limits = Limits {
+            max_events: Some(3),
+            ..Limits::default()
+        };
+        let progress = |events| Progress {
+            events,
+            ..Progress::default()
+        };
+        assert_eq!(limits.reached(&progress(2)), None);
+        assert_eq!(limits.reached(&progress(3)), reached("max_events", 3, 3));
+    }
+
+    #[test]
+    fn elapsed_time_reaches_timeout_secs() {
+        let limits = Limits {
+            timeout_secs: Some(60),
+            ..Limits::default()
+        };
+        let progress = |elapsed_secs| Progress {
+            elapsed_secs,
+            ..Progress::default()
+        };
+        assert_eq!(limits.reached(&progress(59)), None);
+        assert_eq!(
+            limits.reached(&progress(60)),
+            reached("timeout_secs", 60, 60)
+        );
+    }
+
+    #[test]
+    fn unset_limits_are_never_reached() {
+        let progress = Progress {
+            blocks: u64::MAX,
+            transactions: u64::MAX,
+            events: u64::MAX,
+            elapsed_secs: u64::MAX,
+        };
+        assert!(Limits::default().is_empty());
+        assert_eq!(Limits::default().reached(&progress), None);
+    }
+
+    #[test]
+    fn first_limit_reached_is_reported() {
+        let limits = Limits {
+            max_blocks: Some(100),
+            max_transactions: Some(5),
+            max_events: Some(5),
+            timeout_secs: None,
+        };
+        let progress = Progress {
+            blocks: 1,
+            transactions: 5,
+            events: 8,
+            elapsed_secs: 0,
+        };
+        assert_eq!(limits.reached(&progress), reached("max_transactions", 5, 5));
+    }
+}
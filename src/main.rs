@@ -1,7 +1,124 @@
+pub mod accounts;
+pub mod amounts;
+pub mod batch;
 pub mod behaviors;
 pub mod bindings;
+pub mod catalog;
+pub mod collector;
+pub mod config;
+pub mod errors;
+pub mod invariants;
+pub mod limits;
+pub mod messages;
+pub mod replay;
+pub mod replicate;
+pub mod report;
+pub mod resolve;
+pub mod rng;
+pub mod run_dir;
+pub mod shutdown;
+pub mod simulation;
+pub mod sweep;
+pub mod tx;
+pub mod validate;
 
-use behaviors::Behaviors;
+use std::{fs, path::PathBuf};
+
+use anyhow::{bail, Result};
+use batch::BatchOptions;
+use clap::{ArgAction, CommandFactory, Parser, Subcommand};
+use config::SimulationConfig;
+use tracing::Level;
+use tracing_subscriber::{filter::LevelFilter, fmt, prelude::*};
+
+#[derive(Parser)]
+#[clap(name = "ExampleArbiterProject")]
+#[clap(version = env!("CARGO_PKG_VERSION"))]
+#[clap(about = "Our example to get you started.", long_about = None)]
+#[clap(author)]
+struct Args {
+    #[command(subcommand)]
+    command: Option<Commands>,
+
+    /// Increase log verbosity, repeat for more detail (e.g. `-vvv`).
+    #[clap(short, long, global = true, action = ArgAction::Count)]
+    verbose: u8,
+}
+
+#[derive(Subcommand)]
+enum Commands {
+    /// Run the simulation described by a config file. A config containing
+    /// sweeps runs once per parameter point.
+    Simulate {
+        #[clap(index = 1)]
+        config_path: PathBuf,
+
+        /// Directory under which every run gets its own timestamped
+        /// directory holding its config, metadata, logs, outputs and report.
+        #[clap(long, default_value = "output")]
+        out: PathBuf,
+
+        /// Write the run into this directory instead of a timestamped one
+        /// under `--out`.
+        #[clap(long, conflicts_with = "out")]
+        run_dir: Option<PathBuf>,
+
+        /// Print the config with includes, presets, environment variables,
+        /// sweeps and counts resolved instead of running it.
+        #[clap(long)]
+        print_resolved: bool,
+    },
+    /// Check a config file and report every problem in it.
+    Validate {
+        #[clap(index = 1)]
+        config_path: PathBuf,
+    },
+    /// List every behavior with its fields, defaults and the event type it
+    /// consumes.
+    Behaviors {
+        /// Only describe this behavior.
+        #[clap(index = 1)]
+        name: Option<String>,
+
+        /// Print a ready-to-edit config entry for each behavior instead.
+        #[clap(long)]
+        toml: bool,
+    },
+    /// Print the JSON Schema of config files.
+    Schema {
+        /// Write the schema to this file instead of printing it.
+        #[clap(long)]
+        out: Option<PathBuf>,
+    },
+    /// Re-execute the transactions recorded in a run directory without
+    /// running any behavior and check that they reproduce the run.
+    Replay {
+        #[clap(index = 1)]
+        run_dir: PathBuf,
+    },
+    /// Run a config many times with different seeds and summarize the
+    /// outcomes.
+    Batch {
+        #[clap(index = 1, default_value = "configs/example.toml")]
+        config_path: PathBuf,
+
+        /// Number of runs.
+        #[clap(long, default_value_t = 10)]
+        runs: usize,
+
+        /// Seed every run's seed is derived from.
+        #[clap(long, default_value_t = 0)]
+        seed: u64,
+
+        /// Maximum number of runs executing at the same time.
+        #[clap(long, default_value_t = 4)]
+        concurrency: usize,
+
+        /// Directory that the batch runs and summary are written to.
+        #[clap(long, default_value = "output")]
+        out: PathBuf,
+    },
+}
 
 /// If you forked `arbiter`, then to run this example, you can do the following from the `arbiter/` directory:
 /// ```sh
@@ -23,9 +140,149 @@ use behaviors::Behaviors;
 /// ```sh
 /// cargo run simulate configs/example.toml -vvv
 /// ```
-#[arbiter_macros::main(
-    name = "ExampleArbiterProject",
-    about = "Our example to get you started.",
-    behaviors = Behaviors
-)]
-pub async fn main() {}
+#[tokio::main]
+pub async fn main() -> Result<()> {
+    let args = Args::parse();
+
+    let log_level = match args.verbose {
+        0 => Level::ERROR,
+        1 => Level::WARN,
+        2 => Level::INFO,
+        3 => Level::DEBUG,
+        _ => Level::TRACE,
+    };
+    // Runs additionally log to their run directory, at least at `info` so
+    // the log of a run is useful even when the console is kept quiet.
+    tracing_subscriber::registry()
+        .with(fmt::layer().with_filter(LevelFilter::from_level(log_level)))
+        .with(
+            fmt::layer()
+                .with_ansi(false)
+                .with_writer(|| run_dir::LogWriter)
+                .with_filter(LevelFilter::from_level(log_level.max(Level::INFO))),
+        )
+        .init();
+
+    match args.command {
+        Some(Commands::Simulate {
+            config_path,
+            out,
+            run_dir: dir,
+            print_resolved,
+        }) => {
+            let table = config::read_table(&config_path)?;
+            let mut points = sweep::expand(&table)?;
+            if print_resolved {
+                for point in points {
+                    for (field, value) in &point.parameters {
+                        println!("# {} = {}", field, value);
+                    }
+                    let config = SimulationConfig::from_table(point.table)?;
+                    println!("{}", toml::to_string(&config)?);
+                }
+                return Ok(());
+            }
+            println!("Simulating configuration: {}", config_path.display());
+            shutdown::listen();
+            if points.len() == 1 && points[0].parameters.is_empty() {
+                let dir = dir.unwrap_or_else(|| run_dir::timestamped(&out, &config_path));
+                let mut config = SimulationConfig::from_table(points.remove(0).table)?;
+                run_dir::prepare(&dir, &config_path, &mut config)?;
+                let run_report = simulation::run(config).await;
+                run_dir::finish()?;
+                let run_report = run_report?;
+                println!("{}", run_report.to_markdown());
+                run_report.write(&dir.join(run_dir::REPORT_FILE))?;
+                println!("Run written to {}", dir.display());
+                if let Some(signal) = run_report.interrupted {
+                    std::process::exit(signal.exit_code());
+                }
+                if let Some(failure) = run_report.failure() {
+                    bail!(failure);
+                }
+            } else {
+                println!("Sweeping {} parameter points", points.len());
+                let dir = dir.unwrap_or_else(|| run_dir::timestamped(&out, &config_path));
+                let result = sweep::run(&config_path, points, &dir).await;
+                if let Some(signal) = shutdown::received() {
+                    std::process::exit(signal.exit_code());
+                }
+                result?;
+            }
+        }
+        Some(Commands::Batch {
+            config_path,
+            runs,
+            seed,
+            concurrency,
+            out,
+        }) => {
+            let options = BatchOptions {
+                runs,
+                seed,
+                concurrency,
+                out,
+            };
+            shutdown::listen();
+            let result = batch::run(&config_path, options).await;
+            if let Some(signal) = shutdown::received() {
+                std::process::exit(signal.exit_code());
+            }
+            result?;
+        }
+        Some(Commands::Validate { config_path }) => {
+            let diagnostics = validate::validate(&config_path)?;
+            for diagnostic in &diagnostics {
+                eprintln!("{}:{}", config_path.display(), diagnostic);
+            }
+            if !diagnostics.is_empty() {
+                bail!(
+                    "{} has {} problem(s)",
+                    config_path.display(),
+                    diagnostics.len()
+                );
+            }
+            println!("{} is valid", config_path.display());
+        }
+        Some(Commands::Behaviors { name, toml }) => {
+            let behaviors = match name {
+                Some(name) => vec![catalog::behavior(&name)?],
+                None => catalog::behaviors(),
+            };
+            for behavior in behaviors {
+                if toml {
+                    println!("{}", behavior.toml_snippet());
+                } else {
+                    println!("{}", behavior.describe());
+                }
+            }
+        }
+        Some(Commands::Schema { out }) => {
+            let schema = serde_json::to_string_pretty(&config::schema())?;
+            match out {
+                Some(path) => fs::write(path, schema)?,
+                None => println!("{}", schema),
+            }
+        }
+        Some(Commands::Replay { run_dir: dir }) => {
+            let outcome = replay::replay(&dir).await?;
+            for mismatch in &outcome.mismatches {
+                eprintln!("{}: {}", dir.display(), mismatch);
+            }
+            if !outcome.mismatches.is_empty() {
+                bail!(
+                    "replay of {} differs from the run in {} places",
+                    dir.display(),
+                    outcome.mismatches.len()
+                );
+            }
+            println!(
+                "Replayed {} transactions of {}, events and counters match",
+                outcome.transactions,
+                dir.display()
+            );
+        }
+        None => Args::command().print_long_help()?,
+    }
+    Ok(())
+}
@@ -0,0 +1,140 @@
+use anyhow::Result;
+use arbiter_engine::{
+    machine::EventStream,
+    messager::{self, Messager, To},
+};
+use ethers::types::Address;
+use futures::{future, StreamExt};
+use serde::{Deserialize, Serialize};
+use tracing::trace;
+
+use crate::errors::SimulationError;
+
+/// Every message behaviors of this project exchange over the [`Messager`].
+///
+/// Messages are sent as JSON tagged with their kind, e.g.
+/// `{"kind":"stop","reason":"..."}`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum Message {
+    ContractDeployed(ContractDeployed),
+    Stop(Stop),
+    NewBlock(NewBlock),
+}
+
+/// A contract was deployed and can be attached to under `name`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ContractDeployed {
+    pub name: String,
+    pub address: Address,
+}
+
+/// Asks the receiving behaviors to halt.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Stop {
+    pub reason: String,
+}
+
+/// The environment moved to block `number` at `timestamp`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct NewBlock {
+    pub number: u64,
+    pub timestamp: u64,
+}
+
+macro_rules! message_kinds {
+    ($($kind:ident),* $(,)?) => {
+        $(
+            impl From<$kind> for Message {
+                fn from(message: $kind) -> Self {
+                    Message::$kind(message)
+                }
+            }
+
+            impl TryFrom<Message> for $kind {
+                type Error = Message;
+
+                fn try_from(message: Message) -> Result<Self, Message> {
+                    match message {
+                        Message::$kind(message) => Ok(message),
+                        other => Err(other),
+                    }
+                }
+            }
+        )*
+    };
+}
+
+message_kinds!(ContractDeployed, Stop, NewBlock);
+
+/// A message of kind `K` together with the agent that sent it.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Received<K> {
+    pub from: String,
+    pub message: K,
+}
+
+/// Typed sending and receiving of [`Message`]s.
+#[async_trait::async_trait]
+pub trait MessagerExt {
+    /// Sends `message` to every agent.
+    async fn broadcast<M>(&self, message: M) -> Result<()>
+    where
+        M: Into<Message> + Send;
+
+    /// Sends `message` to the agent called `agent` only.
+    async fn send_to<M>(&self, agent: &str, message: M) -> Result<()>
+    where
+        M: Into<Message> + Send;
+
+    /// Streams the messages of kind `K` addressed to this agent, skipping
+    /// every other kind and anything that is not a [`Message`].
+    fn subscribe<K>(self) -> Result<EventStream<Received<K>>>
+    where
+        K: TryFrom<Message> + Send + Sync + 'static;
+}
+
+#[async_trait::async_trait]
+impl MessagerExt for Messager {
+    async fn broadcast<M>(&self, message: M) -> Result<()>
+    where
+        M: Into<Message> + Send,
+    {
+        self.send(To::All, message.into())
+            .await
+            .map_err(SimulationError::from)?;
+        Ok(())
+    }
+
+    async fn send_to<M>(&self, agent: &str, message: M) -> Result<()>
+    where
+        M: Into<Message> + Send,
+    {
+        self.send(To::Agent(agent.to_owned()), message.into())
+            .await
+            .map_err(SimulationError::from)?;
+        Ok(())
+    }
+
+    fn subscribe<K>(self) -> Result<EventStream<Received<K>>>
+    where
+        K: TryFrom<Message> + Send + Sync + 'static,
+    {
+        let stream =
+            self.stream()
+                .map_err(SimulationError::from)?
+                .filter_map(|raw: messager::Message| {
+                    future::ready(match serde_json::from_str::<Message>(&raw.data) {
+                        Ok(message) => K::try_from(message).ok().map(|message| Received {
+                            from: raw.from,
+                            message,
+                        }),
+                        Err(e) => {
+                            trace!("Ignoring message from {}: {}", raw.from, e);
+                            None
+                        }
+                    })
+                });
+        Ok(Box::pin(stream))
+    }
+}
@@ -0,0 +1,439 @@
+use std::{
+    collections::{BTreeMap, HashMap},
+    fs::{self, File},
+    io::{BufRead, BufReader, BufWriter, Write},
+    path::Path,
+    sync::{Arc, Mutex, RwLock},
+};
+
+use anyhow::{bail, Context, Result};
+use arbiter_core::{environment::Environment, middleware::ArbiterMiddleware};
+use ethers::{
+    providers::Middleware,
+    types::{
+        transaction::eip2718::TypedTransaction, Address, Bytes, Eip1559TransactionRequest, Log,
+        NameOrAddress, TransactionReceipt, H256, U256, U64,
+    },
+};
+use serde::{Deserialize, Serialize};
+use tracing::{debug, warn};
+
+use crate::{bindings::modified_counter::ModifiedCounter, report::RunReport, run_dir};
+
+/// File name of the transaction log in a run directory.
+pub const TRANSACTIONS_FILE: &str = "transactions.jsonl";
+
+/// The transaction log of the run in progress, if it keeps one.
+static ACTIVE: RwLock<Option<Arc<TxLog>>> = RwLock::new(None);
+
+/// Timestamp of every block the run moved to through [`update_block`].
+static TIMESTAMPS: Mutex<BTreeMap<u64, U256>> = Mutex::new(BTreeMap::new());
+
+/// A mined transaction as written to the transaction log.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TxRecord {
+    pub agent: String,
+    pub from: Address,
+    /// `None` for contract deployments.
+    pub to: Option<Address>,
+    pub data: Bytes,
+    pub value: U256,
+    pub block_number: u64,
+    /// Timestamp of the block, `None` if the run never moved to the block, as
+    /// for the one environments start at.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub block_timestamp: Option<U256>,
+    /// Position of the transaction in its block, which orders transactions of
+    /// different agents the way the environment executed them.
+    pub transaction_index: u64,
+    pub tx_hash: H256,
+    /// `false` if the transaction reverted.
+    pub success: bool,
+    /// Address of the deployed contract, for deployments.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub contract_address: Option<Address>,
+    pub events: Vec<LogRecord>,
+}
+
+/// An event log emitted by a recorded transaction.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LogRecord {
+    pub address: Address,
+    pub topics: Vec<H256>,
+    pub data: Bytes,
+}
+
+impl From<&Log> for LogRecord {
+    fn from(log: &Log) -> Self {
+        Self {
+            address: log.address,
+            topics: log.topics.clone(),
+            data: log.data.clone(),
+        }
+    }
+}
+
+impl TxRecord {
+    fn new(
+        agent: &str,
+        tx: &TypedTransaction,
+        receipt: &TransactionReceipt,
+        block_timestamp: Option<U256>,
+    ) -> Self {
+        Self {
+            agent: agent.to_owned(),
+            from: receipt.from,
+            to: tx.to().and_then(NameOrAddress::as_address).copied(),
+            data: tx.data().cloned().unwrap_or_default(),
+            value: tx.value().copied().unwrap_or_default(),
+            block_number: receipt.block_number.unwrap_or_default().as_u64(),
+            block_timestamp,
+            transaction_index: receipt.transaction_index.as_u64(),
+            tx_hash: receipt.transaction_hash,
+            success: succeeded(receipt),
+            contract_address: receipt.contract_address,
+            events: events(receipt),
+        }
+    }
+}
+
+fn succeeded(receipt: &TransactionReceipt) -> bool {
+    receipt.status != Some(U64::zero())
+}
+
+fn events(receipt: &TransactionReceipt) -> Vec<LogRecord> {
+    receipt.logs.iter().map(LogRecord::from).collect()
+}
+
+/// Appends every mined transaction of a run to a JSON lines file.
+pub struct TxLog {
+    writer: Mutex<BufWriter<File>>,
+}
+
+impl TxLog {
+    pub fn create(path: &Path) -> Result<Self> {
+        let file =
+            File::create(path).with_context(|| format!("failed to create {}", path.display()))?;
+        Ok(Self {
+            writer: Mutex::new(BufWriter::new(file)),
+        })
+    }
+
+    fn write(&self, record: &TxRecord) -> Result<()> {
+        let mut writer = self.writer.lock().unwrap();
+        serde_json::to_writer(&mut *writer, record)?;
+        writer.write_all(b"\n")?;
+        Ok(())
+    }
+
+    pub fn finish(&self) -> Result<()> {
+        Ok(self.writer.lock().unwrap().flush()?)
+    }
+}
+
+/// Makes `log` the destination of [`record`] for the current run.
+pub fn install(log: Arc<TxLog>) {
+    *ACTIVE.write().unwrap() = Some(log);
+}
+
+/// Removes and returns the transaction log of the current run.
+pub fn uninstall() -> Option<Arc<TxLog>> {
+    ACTIVE.write().unwrap().take()
+}
+
+/// Records that `agent` sent `tx`, mined with `receipt`. Does nothing if the
+/// run keeps no transaction log.
+pub fn record(agent: &str, tx: &TypedTransaction, receipt: &TransactionReceipt) {
+    let log = ACTIVE.read().unwrap().clone();
+    if let Some(log) = log {
+        let block_number = receipt.block_number.unwrap_or_default().as_u64();
+        let block_timestamp = TIMESTAMPS.lock().unwrap().get(&block_number).copied();
+        let record = TxRecord::new(agent, tx, receipt, block_timestamp);
+        if let Err(e) = log.write(&record) {
+            warn!(
+                "Failed to record transaction {:?}: {}",
+                receipt.transaction_hash, e
+            );
+        }
+    }
+}
+
+/// Forgets the block timestamps of earlier runs, whose block numbers repeat.
+pub fn reset() {
+    TIMESTAMPS.lock().unwrap().clear();
+}
+
+/// Moves the environment of `client` to `block_number` at `timestamp`.
+///
+/// Behaviors move blocks through here rather than `update_block` so that the
+/// transactions mined and the events emitted in the block are recorded with
+/// its timestamp.
+pub async fn update_block(
+    client: &ArbiterMiddleware,
+    block_number: u64,
+    timestamp: U256,
+) -> Result<()> {
+    // Remembered before moving, transactions can be mined in the block as
+    // soon as it starts.
+    TIMESTAMPS.lock().unwrap().insert(block_number, timestamp);
+    client.update_block(block_number, timestamp).await?;
+    Ok(())
+}
+
+/// Looks up the timestamp of `block_number`, which is known for the blocks
+/// moved to through [`update_block`] and for the block the environment of
+/// `client` is still in.
+pub async fn block_timestamp(client: &ArbiterMiddleware, block_number: u64) -> Result<U256> {
+    if let Some(timestamp) = TIMESTAMPS.lock().unwrap().get(&block_number) {
+        return Ok(*timestamp);
+    }
+    if client.get_block_number().await?.as_u64() == block_number {
+        return Ok(client.get_block_timestamp().await?);
+    }
+    bail!("timestamp of block {} is unknown", block_number)
+}
+
+/// Reads the transaction log at `path` in the order the environment executed
+/// the transactions.
+pub fn read(path: &Path) -> Result<Vec<TxRecord>> {
+    let file = File::open(path).with_context(|| format!("failed to read {}", path.display()))?;
+    let mut records = BufReader::new(file)
+        .lines()
+        .enumerate()
+        .map(|(index, line)| {
+            serde_json::from_str(&line?).with_context(|| {
+                format!("invalid record on line {} of {}", index + 1, path.display())
+            })
+        })
+        .collect::<Result<Vec<TxRecord>>>()?;
+    // Agents record their transactions once they get the receipt, which is
+    // not necessarily the order they were mined in.
+    records.sort_by_key(|record| (record.block_number, record.transaction_index));
+    Ok(records)
+}
+
+/// What re-executing the transactions of a run found.
+#[derive(Debug, Default)]
+pub struct ReplayOutcome {
+    pub transactions: usize,
+    /// Every difference from the recorded run.
+    pub mismatches: Vec<String>,
+}
+
+/// Re-executes the transactions recorded in `dir` in a fresh environment,
+/// without running any behavior, and checks that they emit the recorded
+/// events and leave every counter at the `number()` of the run report.
+///
+/// Agents get the same addresses as in the run since clients are derived from
+/// agent names, so deployments land at their recorded addresses too.
+pub async fn replay(dir: &Path) -> Result<ReplayOutcome> {
+    let records = read(&dir.join(TRANSACTIONS_FILE))?;
+    let report_path = dir.join(run_dir::REPORT_FILE);
+    let report: RunReport = serde_json::from_str(
+        &fs::read_to_string(&report_path)
+            .with_context(|| format!("failed to read {}", report_path.display()))?,
+    )?;
+
+    let environment = Environment::builder().build();
+    let mut clients: HashMap<String, Arc<ArbiterMiddleware>> = HashMap::new();
+    let mut outcome = ReplayOutcome::default();
+    let mut block_number = 0;
+    for (index, record) in records.iter().enumerate() {
+        let client = match clients.get(&record.agent) {
+            Some(client) => client.clone(),
+            None => {
+                let client = ArbiterMiddleware::new(&environment, Some(&record.agent))?;
+                clients.insert(record.agent.clone(), client.clone());
+                client
+            }
+        };
+        if client.address() != record.from {
+            bail!(
+                "agent `{}` replays from {:?} instead of {:?}",
+                record.agent,
+                client.address(),
+                record.from
+            );
+        }
+        if record.block_number > block_number {
+            block_number = record.block_number;
+            let timestamp = match record.block_timestamp {
+                Some(timestamp) => timestamp,
+                None => client.get_block_timestamp().await?,
+            };
+            client.update_block(block_number, timestamp).await?;
+        }
+
+        let mut tx = Eip1559TransactionRequest::new()
+            .from(record.from)
+            .data(record.data.clone())
+            .value(record.value);
+        if let Some(to) = record.to {
+            tx = tx.to(to);
+        }
+        debug!("Replaying transaction {} of `{}`", index, record.agent);
+        let receipt = match client.send_transaction(tx, None).await {
+            Ok(pending) => pending.await?,
+            Err(e) => {
+                outcome.mismatches.push(format!(
+                    "transaction {} ({:?}) failed: {}",
+                    index, record.tx_hash, e
+                ));
+                continue;
+            }
+        };
+        outcome.transactions += 1;
+        let Some(receipt) = receipt else {
+            outcome.mismatches.push(format!(
+                "transaction {} ({:?}) has no receipt",
+                index, record.tx_hash
+            ));
+            continue;
+        };
+        if succeeded(&receipt) != record.success {
+            outcome.mismatches.push(format!(
+                "transaction {} ({:?}) {} but {} in the run",
+                index,
+                record.tx_hash,
+                status(succeeded(&receipt)),
+                status(record.success)
+            ));
+        }
+        if receipt.contract_address != record.contract_address {
+            outcome.mismatches.push(format!(
+                "transaction {} ({:?}) deployed {:?} instead of {:?}",
+                index, record.tx_hash, receipt.contract_address, record.contract_address
+            ));
+        }
+        let replayed = events(&receipt);
+        if replayed != record.events {
+            outcome.mismatches.push(format!(
+                "transaction {} ({:?}) emitted {} events that differ from the {} recorded",
+                index,
+                record.tx_hash,
+                replayed.len(),
+                record.events.len()
+            ));
+        }
+    }
+
+    let reader = ArbiterMiddleware::new(&environment, Some("replay"))?;
+    for counter in &report.counters {
+        let Some(expected) = counter.final_number else {
+            continue;
+        };
+        let number = ModifiedCounter::new(counter.address, reader.clone())
+            .number()
+            .call()
+            .await?;
+        if number != expected {
+            outcome.mismatches.push(format!(
+                "counter of `{}` at {:?} ends at {} instead of {}",
+                counter.deployer, counter.address, number, expected
+            ));
+        }
+    }
+    Ok(outcome)
+}
+
+fn status(success: bool) -> &'static str {
+    match success {
+        true => "succeeded",
+        false => "reverted",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ethers::types::TransactionRequest;
+
+    use super::*;
+
+    fn receipt(block_number: u64, transaction_index: u64, tx_hash: u64) -> TransactionReceipt {
+        TransactionReceipt {
+            transaction_hash: H256::from_low_u64_be(tx_hash),
+            transaction_index: transaction_index.into(),
+            block_number: Some(block_number.into()),
+            from: Address::from_low_u64_be(1),
+            status: Some(U64::one()),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn transaction_log_round_trips() {
+        let path = std::env::temp_dir().join(format!("replay-test-{}.jsonl", std::process::id()));
+        install(Arc::new(TxLog::create(&path).unwrap()));
+        TIMESTAMPS.lock().unwrap().insert(2, U256::from(1_000));
+        let counter = Address::from_low_u64_be(2);
+        let call = |data: u8| -> TypedTransaction {
+            TransactionRequest::new()
+                .to(counter)
+                .data(vec![data])
+                .value(5)
+                .into()
+        };
+        let log = Log {
+            address: counter,
+            topics: vec![H256::from_low_u64_be(3)],
+            data: vec![4].into(),
+            ..Default::default()
+        };
+
+        // Recorded in the order agents got their receipts, not the one the
+        // transactions were mined in.
+        record(
+            "bob",
+            &call(0xbb),
+            &TransactionReceipt {
+                logs: vec![log.clone()],
+                ..receipt(2, 0, 20)
+            },
+        );
+        record(
+            "alice",
+            &call(0xaa),
+            &TransactionReceipt {
+                status: Some(U64::zero()),
+                ..receipt(1, 1, 11)
+            },
+        );
+        record(
+            "deployer",
+            &TransactionRequest::new().data(vec![0x60]).into(),
+            &TransactionReceipt {
+                contract_address: Some(counter),
+                ..receipt(1, 0, 10)
+            },
+        );
+        uninstall().unwrap().finish().unwrap();
+        let records = read(&path).unwrap();
+        fs::remove_file(&path).unwrap();
+        reset();
+
+        let agents: Vec<_> = records.iter().map(|record| record.agent.as_str()).collect();
+        assert_eq!(agents, ["deployer", "alice", "bob"]);
+        let [deployment, reverted, call] = &records[..] else {
+            unreachable!("three records were written");
+        };
+
+        assert_eq!(deployment.to, None);
+        assert_eq!(deployment.contract_address, Some(counter));
+        assert_eq!(deployment.data, Bytes::from(vec![0x60]));
+        assert_eq!(deployment.value, U256::zero());
+
+        assert!(!reverted.success);
+        assert_eq!(reverted.tx_hash, H256::from_low_u64_be(11));
+        assert_eq!((reverted.block_number, reverted.transaction_index), (1, 1));
+        // The run never moved to block 1, so its timestamp is unknown.
+        assert_eq!(reverted.block_timestamp, None);
+
+        assert!(call.success);
+        assert_eq!(call.from, Address::from_low_u64_be(1));
+        assert_eq!(call.to, Some(counter));
+        assert_eq!(call.data, Bytes::from(vec![0xbb]));
+        assert_eq!(call.value, U256::from(5));
+        assert_eq!(call.block_timestamp, Some(U256::from(1_000)));
+        assert_eq!(call.events, vec![LogRecord::from(&log)]);
+    }
+}
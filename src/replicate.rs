@@ -0,0 +1,394 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+use anyhow::{anyhow, bail, ensure, Context, Result};
+use rand::{seq::SliceRandom, Rng};
+use rand_distr::{Distribution, Normal};
+use toml::{Table, Value};
+
+use crate::{
+    catalog,
+    rng::{self, SimRng},
+};
+
+/// Key of an agent entry setting how many agents to create from the agent
+/// table, e.g. `count = 100`.
+const COUNT_KEY: &str = "count";
+
+/// Key marking a value drawn for every instance, e.g.
+/// `{ sample = { uniform = [1, 20] } }`. A behavior field can also be given
+/// the distribution alone, see [`instantiate_entry`].
+const SAMPLE_KEY: &str = "sample";
+
+/// Distributions values can be drawn from, see [`sample`].
+const DISTRIBUTIONS: &[&str] = &["uniform", "normal", "choice"];
+
+/// Key marking a list handed out in order, one value per instance, e.g.
+/// `{ each = [1, 5, 10] }`.
+const EACH_KEY: &str = "each";
+
+/// Name of the random stream instances of `agent` are drawn from.
+const STREAM: &str = "count";
+
+/// The fields of each behavior that accept a table themselves, keyed by
+/// behavior name.
+type TableFields = BTreeMap<&'static str, BTreeSet<String>>;
+
+/// Replaces every agent table with a `count` by that many agents named
+/// `<agent>_<index>`, resolving the per-instance values of each.
+///
+/// Values are drawn from the config's `seed`. A config without one gets a
+/// fresh seed written into it so that the run reproduces the same instances.
+pub fn expand(table: &mut Table) -> Result<()> {
+    let mut replicated = Vec::new();
+    for (agent, value) in table.iter() {
+        if let Some(count) =
+            count(value).with_context(|| format!("invalid `count` of agent `{}`", agent))?
+        {
+            replicated.push((agent.clone(), count));
+        }
+    }
+    if replicated.is_empty() {
+        return Ok(());
+    }
+    let seed = match table.get("seed") {
+        Some(seed) => seed
+            .as_integer()
+            .ok_or_else(|| anyhow!("`seed` must be an integer"))? as u64,
+        None => {
+            let seed = rng::random_seed();
+            table.insert("seed".to_owned(), Value::Integer(seed as i64));
+            seed
+        }
+    };
+
+    let table_fields = table_fields();
+    for (agent, count) in replicated {
+        let Some(Value::Array(mut entries)) = table.remove(&agent) else {
+            unreachable!("only agent arrays have a count");
+        };
+        for entry in &mut entries {
+            if let Some(entry) = entry.as_table_mut() {
+                entry.remove(COUNT_KEY);
+            }
+        }
+
+        let mut rng = rng::seeded_stream(seed, &agent, STREAM);
+        let width = count.saturating_sub(1).to_string().len();
+        for index in 0..count {
+            let id = format!("{}_{:0width$}", agent, index, width = width);
+            ensure!(
+                !table.contains_key(&id),
+                "agent `{}` created by `count` already exists",
+                id
+            );
+            let instance = entries
+                .iter()
+                .map(|entry| instantiate_entry(entry, index, &mut rng, &table_fields))
+                .collect::<Result<Vec<_>>>()
+                .with_context(|| format!("failed to create agent `{}`", id))?;
+            table.insert(id, Value::Array(instance));
+        }
+    }
+    Ok(())
+}
+
+/// The `count` of an agent table, `None` if it is not replicated.
+fn count(value: &Value) -> Result<Option<usize>> {
+    let Some(entries) = value.as_array() else {
+        return Ok(None);
+    };
+    let counts: Vec<&Value> = entries
+        .iter()
+        .filter_map(|entry| entry.get(COUNT_KEY))
+        .collect();
+    let Some((first, rest)) = counts.split_first() else {
+        return Ok(None);
+    };
+    ensure!(
+        rest.iter().all(|other| other == first),
+        "entries of one agent must not set different counts"
+    );
+    let count = first
+        .as_integer()
+        .ok_or_else(|| anyhow!("expected an integer, found `{}`", first))?;
+    ensure!(count > 0, "must be at least 1, found {}", count);
+    Ok(Some(count as usize))
+}
+
+/// Strips the `count` of an agent entry and resolves its per-instance values
+/// as the first instance would see them, to check the entry on its own.
+pub fn first_instance(entry: &Value) -> Result<Value> {
+    let mut entry = entry.clone();
+    if let Some(table) = entry.as_table_mut() {
+        table.remove(COUNT_KEY);
+    }
+    instantiate_entry(
+        &entry,
+        0,
+        &mut rng::seeded_stream(0, "", STREAM),
+        &table_fields(),
+    )
+}
+
+fn table_fields() -> TableFields {
+    catalog::behaviors()
+        .into_iter()
+        .map(|behavior| {
+            let fields = behavior
+                .fields
+                .into_iter()
+                .filter(|field| field.takes_table)
+                .map(|field| field.name)
+                .collect();
+            (behavior.name, fields)
+        })
+        .collect()
+}
+
+/// Resolves the per-instance values of an agent `entry` for instance
+/// `index` like [`instantiate`]. A behavior field given a bare distribution,
+/// e.g. `max_number_of_times = { uniform = [1, 20] }`, is drawn from it too,
+/// unless the field takes such a table itself, like the `wait` of an
+/// `Incrementer`.
+fn instantiate_entry(
+    entry: &Value,
+    index: usize,
+    rng: &mut SimRng,
+    table_fields: &TableFields,
+) -> Result<Value> {
+    let Value::Table(behaviors) = entry else {
+        return instantiate(entry, index, rng);
+    };
+    let mut instance = Table::new();
+    for (behavior, fields) in behaviors {
+        let Value::Table(fields) = fields else {
+            instance.insert(behavior.clone(), instantiate(fields, index, rng)?);
+            continue;
+        };
+        let takes_table = table_fields.get(behavior.as_str());
+        let mut resolved = Table::new();
+        for (field, value) in fields {
+            let value = match value {
+                Value::Table(table)
+                    if is_distribution(table)
+                        && !takes_table.is_some_and(|fields| fields.contains(field)) =>
+                {
+                    sample(value, rng).with_context(|| format!("invalid `{}`", field))?
+                }
+                value => instantiate(value, index, rng)?,
+            };
+            resolved.insert(field.clone(), value);
+        }
+        instance.insert(behavior.clone(), Value::Table(resolved));
+    }
+    Ok(Value::Table(instance))
+}
+
+fn is_distribution(table: &Table) -> bool {
+    table.len() == 1 && DISTRIBUTIONS.iter().any(|kind| table.contains_key(*kind))
+}
+
+/// Resolves the per-instance values of `value` for instance `index`, drawing
+/// samples from `rng`.
+pub fn instantiate(value: &Value, index: usize, rng: &mut SimRng) -> Result<Value> {
+    match value {
+        Value::Table(table) if table.len() == 1 && table.contains_key(SAMPLE_KEY) => {
+            sample(&table[SAMPLE_KEY], rng)
+        }
+        Value::Table(table) if table.len() == 1 && table.contains_key(EACH_KEY) => {
+            let values = table[EACH_KEY]
+                .as_array()
+                .filter(|values| !values.is_empty())
+                .ok_or_else(|| anyhow!("`each` must be a non-empty list"))?;
+            Ok(values[index % values.len()].clone())
+        }
+        Value::Table(table) => Ok(Value::Table(
+            table
+                .iter()
+                .map(|(key, value)| Ok((key.clone(), instantiate(value, index, rng)?)))
+                .collect::<Result<Table>>()?,
+        )),
+        Value::Array(array) => Ok(Value::Array(
+            array
+                .iter()
+                .map(|value| instantiate(value, index, rng))
+                .collect::<Result<_>>()?,
+        )),
+        other => Ok(other.clone()),
+    }
+}
+
+/// Draws a value from `distribution`, one of `{ uniform = [low, high] }`
+/// (inclusive for integers), `{ normal = [mean, std_dev] }` or
+/// `{ choice = [a, b, ...] }`.
+fn sample(distribution: &Value, rng: &mut SimRng) -> Result<Value> {
+    let Some((kind, args)) = distribution
+        .as_table()
+        .filter(|table| table.len() == 1)
+        .and_then(|table| table.iter().next())
+    else {
+        bail!("`sample` expects a table with a single distribution");
+    };
+    let args = args
+        .as_array()
+        .ok_or_else(|| anyhow!("`{}` expects a list", kind))?;
+    match (kind.as_str(), args.as_slice()) {
+        ("uniform", [Value::Integer(low), Value::Integer(high)]) => {
+            ensure!(low <= high, "uniform range [{}, {}] is empty", low, high);
+            Ok(Value::Integer(rng.gen_range(*low..=*high)))
+        }
+        ("uniform", [low, high]) => {
+            let (low, high) = (float(low)?, float(high)?);
+            ensure!(low < high, "uniform range [{}, {}) is empty", low, high);
+            Ok(Value::Float(rng.gen_range(low..high)))
+        }
+        ("normal", [mean, std_dev]) => {
+            let normal = Normal::new(float(mean)?, float(std_dev)?)
+                .map_err(|e| anyhow!("invalid normal distribution: {}", e))?;
+            Ok(Value::Float(normal.sample(rng)))
+        }
+        ("choice", values) => values
+            .choose(rng)
+            .cloned()
+            .ok_or_else(|| anyhow!("`choice` needs at least one value")),
+        ("uniform" | "normal", _) => bail!("`{}` expects two numbers", kind),
+        _ => bail!(
+            "unknown distribution `{}`, expected `uniform`, `normal` or `choice`",
+            kind
+        ),
+    }
+}
+
+fn float(value: &Value) -> Result<f64> {
+    match value {
+        Value::Integer(value) => Ok(*value as f64),
+        Value::Float(value) => Ok(*value),
+        other => bail!("expected a number, found `{}`", other),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn table(toml: &str) -> Table {
+        toml::from_str(toml).unwrap()
+    }
+
+    /// The value of `field` of the `Incrementer` of every instance of
+    /// `agent`, in order.
+    fn field_values(table: &Table, agent: &str, field: &str) -> Vec<Value> {
+        let mut instances: Vec<_> = table
+            .iter()
+            .filter(|(id, _)| id.starts_with(&format!("{}_", agent)))
+            .collect();
+        instances.sort_by_key(|(id, _)| id.as_str());
+        instances
+            .into_iter()
+            .map(|(_, entries)| entries[0]["Incrementer"][field].clone())
+            .collect()
+    }
+
+    const SAMPLED: &str = r#"
+        seed = 5
+        [[incrementer]]
+        count = 20
+        Incrementer = { max_number_of_times = { uniform = [1, 20] } }
+        "#;
+
+    #[test]
+    fn count_creates_numbered_agents() {
+        let mut config = table(SAMPLED);
+        expand(&mut config).unwrap();
+        assert!(!config.contains_key("incrementer"));
+        assert!(config.contains_key("incrementer_00"));
+        assert!(config.contains_key("incrementer_19"));
+        assert_eq!(
+            field_values(&config, "incrementer", "max_number_of_times").len(),
+            20
+        );
+    }
+
+    #[test]
+    fn seeded_samples_are_deterministic() {
+        let mut first = table(SAMPLED);
+        let mut second = table(SAMPLED);
+        expand(&mut first).unwrap();
+        expand(&mut second).unwrap();
+        assert_eq!(first, second);
+
+        let values = field_values(&first, "incrementer", "max_number_of_times");
+        assert!(values
+            .iter()
+            .all(|value| (1..=20).contains(&value.as_integer().unwrap())));
+        assert!(values.iter().any(|value| *value != values[0]));
+
+        let mut reseeded = table(&SAMPLED.replace("seed = 5", "seed = 6"));
+        expand(&mut reseeded).unwrap();
+        assert_ne!(
+            field_values(&reseeded, "incrementer", "max_number_of_times"),
+            values
+        );
+    }
+
+    #[test]
+    fn bare_and_explicit_samples_draw_the_same_values() {
+        let mut bare = table(SAMPLED);
+        let mut explicit = table(&SAMPLED.replace(
+            "{ uniform = [1, 20] }",
+            "{ sample = { uniform = [1, 20] } }",
+        ));
+        expand(&mut bare).unwrap();
+        expand(&mut explicit).unwrap();
+        assert_eq!(bare, explicit);
+    }
+
+    #[test]
+    fn config_without_seed_gets_one() {
+        let mut config = table(&SAMPLED.replace("seed = 5", ""));
+        expand(&mut config).unwrap();
+        assert!(config["seed"].as_integer().is_some());
+    }
+
+    #[test]
+    fn each_wraps_around() {
+        let mut config = table(
+            r#"
+            [[incrementer]]
+            count = 5
+            Incrementer = { probability = { each = [1.0, 0.5] } }
+            "#,
+        );
+        expand(&mut config).unwrap();
+        assert_eq!(
+            field_values(&config, "incrementer", "probability"),
+            [1.0, 0.5, 1.0, 0.5, 1.0].map(Value::Float)
+        );
+    }
+
+    #[test]
+    fn fields_taking_a_distribution_keep_it() {
+        let mut config = table(
+            r#"
+            seed = 5
+            [[incrementer]]
+            count = 2
+            Incrementer = { wait = { uniform = [1, 5] } }
+            "#,
+        );
+        expand(&mut config).unwrap();
+        let wait = table("wait = { uniform = [1, 5] }").remove("wait").unwrap();
+        assert_eq!(
+            field_values(&config, "incrementer", "wait"),
+            [wait.clone(), wait]
+        );
+    }
+
+    #[test]
+    fn invalid_counts_are_rejected() {
+        for count in ["0", "-1", "\"ten\""] {
+            let mut config = table(&format!("[[agent]]\ncount = {}\nIncrementer = {{}}", count));
+            assert!(expand(&mut config).is_err(), "count {} accepted", count);
+        }
+    }
+}
@@ -0,0 +1,676 @@
+use std::{
+    collections::BTreeMap,
+    fmt::Write as _,
+    fs,
+    path::Path,
+    sync::{Arc, Mutex, RwLock},
+    time::Instant,
+};
+
+use anyhow::{Context, Result};
+use arbiter_core::middleware::ArbiterMiddleware;
+use arbiter_engine::{
+    machine::{Behavior, ControlFlow, EventStream},
+    messager::Messager,
+};
+use ethers::{
+    abi::RawLog,
+    contract::EthLogDecode,
+    types::{Address, TransactionReceipt, U256},
+};
+use serde::{Deserialize, Serialize};
+use tokio::sync::Notify;
+use tracing::{error, info, warn};
+
+use crate::{
+    behaviors,
+    bindings::{
+        constant_product_pool::ConstantProductPoolEvents,
+        guarded_counter::GuardedCounterEvents,
+        modified_counter::{ModifiedCounter, ModifiedCounterEvents},
+        price_oracle::PriceUpdatedFilter,
+        token::TokenEvents,
+    },
+    errors::SimulationError,
+    invariants::Violation,
+    limits::LimitReached,
+    shutdown::{self, Signal},
+};
+
+/// The recorder of the run in progress.
+static ACTIVE: RwLock<Option<Arc<Recorder>>> = RwLock::new(None);
+
+/// What happened during a single simulation run.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RunReport {
+    pub seed: u64,
+    pub wall_clock_secs: f64,
+    /// Lowest and highest block a transaction of the run was included in.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub blocks: Option<(u64, u64)>,
+    pub agents: BTreeMap<String, AgentOutcome>,
+    pub counters: Vec<CounterOutcome>,
+    /// Gas used by successful transactions, keyed by contract function.
+    #[serde(default)]
+    pub gas: BTreeMap<String, GasUsage>,
+    /// Number of events emitted by the run's transactions, keyed by event.
+    #[serde(default)]
+    pub events: BTreeMap<String, u64>,
+    /// Invariants an `InvariantChecker` found broken.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub violations: Vec<Violation>,
+    /// Set if the world stopped with an error instead of all behaviors
+    /// halting.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+    /// The signal that interrupted the run, which stopped every behavior
+    /// before it was done.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub interrupted: Option<Signal>,
+    /// The limit of the config that stopped every behavior.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub limit: Option<LimitReached>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CounterOutcome {
+    pub address: Address,
+    pub deployer: String,
+    /// `number()` once the run is over, `None` if it could not be read.
+    pub final_number: Option<U256>,
+}
+
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct AgentOutcome {
+    /// Behaviors the agent was configured with.
+    #[serde(default)]
+    pub behaviors: Vec<String>,
+    #[serde(default)]
+    pub transactions: u64,
+    #[serde(default)]
+    pub reverted: u64,
+    pub increments: u64,
+    #[serde(default)]
+    pub number_sets: u64,
+    /// Errors behaviors of the agent ran into while handling events.
+    #[serde(default)]
+    pub errors: u64,
+    pub halts: Vec<HaltRecord>,
+    /// Swaps of an `Arbitrageur` of the agent.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub arbitrage: Option<ArbitrageOutcome>,
+}
+
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct ArbitrageOutcome {
+    pub trades: u64,
+    /// Sum of the realized profits of the trades, net of gas, in quote
+    /// tokens.
+    pub realized_profit: f64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HaltRecord {
+    pub behavior: String,
+    #[serde(default)]
+    pub kind: HaltKind,
+    pub reason: String,
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum HaltKind {
+    /// The behavior returned `ControlFlow::Halt`.
+    #[default]
+    Halted,
+    /// The behavior stopped because of an error.
+    Error,
+}
+
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct GasUsage {
+    pub transactions: u64,
+    pub total: U256,
+}
+
+impl RunReport {
+    pub fn increments(&self) -> u64 {
+        self.agents.values().map(|agent| agent.increments).sum()
+    }
+
+    /// Why the run failed, if the world stopped with an error, an invariant
+    /// was violated or the run was interrupted.
+    pub fn failure(&self) -> Option<String> {
+        match (&self.error, self.violations.first(), self.interrupted) {
+            (Some(error), _, _) => Some(format!("error: {}", error)),
+            (None, Some(violation), _) => Some(format!(
+                "invariant `{}` violated: {}",
+                violation.invariant, violation.message
+            )),
+            (None, None, Some(signal)) => Some(format!("interrupted by {}", signal)),
+            (None, None, None) => None,
+        }
+    }
+
+    /// Number of blocks the run spanned.
+    pub fn simulated_blocks(&self) -> u64 {
+        self.blocks.map_or(0, |(first, last)| last - first)
+    }
+
+    /// Writes the report as JSON to `path` and as Markdown next to it, with
+    /// the extension replaced by `md`.
+    pub fn write(&self, path: &Path) -> Result<()> {
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write(path, serde_json::to_string_pretty(self)?)
+            .with_context(|| format!("failed to write {}", path.display()))?;
+        let markdown = path.with_extension("md");
+        fs::write(&markdown, self.to_markdown())
+            .with_context(|| format!("failed to write {}", markdown.display()))?;
+        Ok(())
+    }
+
+    /// Renders the report as a Markdown document.
+    pub fn to_markdown(&self) -> String {
+        let mut md = String::new();
+        // Writing to a `String` cannot fail.
+        let _ = self.write_markdown(&mut md);
+        md
+    }
+
+    fn write_markdown(&self, md: &mut String) -> std::fmt::Result {
+        writeln!(md, "# Run Summary\n")?;
+        writeln!(md, "- Seed: {}", self.seed)?;
+        writeln!(md, "- Wall clock: {:.3}s", self.wall_clock_secs)?;
+        match self.blocks {
+            Some((first, last)) => writeln!(
+                md,
+                "- Simulated blocks: {} ({} to {})",
+                self.simulated_blocks(),
+                first,
+                last
+            )?,
+            None => writeln!(md, "- Simulated blocks: 0")?,
+        }
+        match (self.failure(), &self.limit) {
+            (Some(failure), _) => writeln!(md, "- Outcome: {}", failure)?,
+            (None, Some(limit)) => writeln!(
+                md,
+                "- Outcome: stopped by limit `{}` of {} at {}",
+                limit.limit, limit.max, limit.value
+            )?,
+            (None, None) => writeln!(md, "- Outcome: completed")?,
+        }
+
+        writeln!(md, "\n## Agents\n")?;
+        writeln!(
+            md,
+            "| Agent | Behaviors | Transactions | Reverted | Increments | Number sets | Errors |"
+        )?;
+        writeln!(md, "|---|---|---|---|---|---|---|")?;
+        for (agent, outcome) in &self.agents {
+            writeln!(
+                md,
+                "| {} | {} | {} | {} | {} | {} | {} |",
+                agent,
+                outcome.behaviors.join(", "),
+                outcome.transactions,
+                outcome.reverted,
+                outcome.increments,
+                outcome.number_sets,
+                outcome.errors
+            )?;
+        }
+
+        writeln!(md, "\n## Halts\n")?;
+        writeln!(md, "| Agent | Behavior | Kind | Reason |")?;
+        writeln!(md, "|---|---|---|---|")?;
+        for (agent, outcome) in &self.agents {
+            for halt in &outcome.halts {
+                let kind = match halt.kind {
+                    HaltKind::Halted => "halted",
+                    HaltKind::Error => "error",
+                };
+                writeln!(
+                    md,
+                    "| {} | {} | {} | {} |",
+                    agent, halt.behavior, kind, halt.reason
+                )?;
+            }
+        }
+
+        writeln!(md, "\n## Gas\n")?;
+        writeln!(md, "| Function | Transactions | Total gas | Average gas |")?;
+        writeln!(md, "|---|---|---|---|")?;
+        for (function, usage) in &self.gas {
+            let average = usage.total / U256::from(usage.transactions.max(1));
+            writeln!(
+                md,
+                "| {} | {} | {} | {} |",
+                function, usage.transactions, usage.total, average
+            )?;
+        }
+
+        if self
+            .agents
+            .values()
+            .any(|outcome| outcome.arbitrage.is_some())
+        {
+            writeln!(md, "\n## Arbitrage\n")?;
+            writeln!(md, "| Agent | Trades | Realized profit |")?;
+            writeln!(md, "|---|---|---|")?;
+            for (agent, outcome) in &self.agents {
+                if let Some(arbitrage) = &outcome.arbitrage {
+                    writeln!(
+                        md,
+                        "| {} | {} | {} |",
+                        agent, arbitrage.trades, arbitrage.realized_profit
+                    )?;
+                }
+            }
+        }
+
+        writeln!(md, "\n## Events\n")?;
+        writeln!(md, "| Event | Count |")?;
+        writeln!(md, "|---|---|")?;
+        for (event, count) in &self.events {
+            writeln!(md, "| {} | {} |", event, count)?;
+        }
+
+        if !self.violations.is_empty() {
+            writeln!(md, "\n## Invariant Violations\n")?;
+            for violation in &self.violations {
+                writeln!(md, "### `{}`\n", violation.invariant)?;
+                writeln!(md, "- {}", violation.message)?;
+                writeln!(md, "- Block: {}", violation.block_number)?;
+                writeln!(md, "- Transaction: {:?}", violation.tx_hash)?;
+                writeln!(md, "- Agent: {}", violation.agent)?;
+                writeln!(md, "- State before: {:?}", violation.before)?;
+                writeln!(md, "- State after: {:?}\n", violation.after)?;
+            }
+        }
+
+        writeln!(md, "\n## Counters\n")?;
+        writeln!(md, "| Address | Deployer | Final number |")?;
+        writeln!(md, "|---|---|---|")?;
+        for counter in &self.counters {
+            let final_number = counter
+                .final_number
+                .map_or_else(|| "?".to_owned(), |number| number.to_string());
+            writeln!(
+                md,
+                "| {:?} | {} | {} |",
+                counter.address, counter.deployer, final_number
+            )?;
+        }
+        Ok(())
+    }
+}
+
+/// Collects the outcome of a run while its behaviors execute.
+pub struct Recorder {
+    started: Instant,
+    counters: Mutex<Vec<(String, ModifiedCounter<ArbiterMiddleware>)>>,
+    agents: Mutex<BTreeMap<String, AgentOutcome>>,
+    gas: Mutex<BTreeMap<String, GasUsage>>,
+    events: Mutex<BTreeMap<String, u64>>,
+    blocks: Mutex<Option<(u64, u64)>>,
+    violations: Mutex<Vec<Violation>>,
+    limit: Mutex<Option<LimitReached>>,
+    /// Behaviors that are not observers and have not halted yet.
+    workers: Mutex<usize>,
+    workers_halted: Notify,
+}
+
+impl Default for Recorder {
+    fn default() -> Self {
+        Self {
+            started: Instant::now(),
+            counters: Default::default(),
+            agents: Default::default(),
+            gas: Default::default(),
+            events: Default::default(),
+            blocks: Default::default(),
+            violations: Default::default(),
+            limit: Default::default(),
+            workers: Default::default(),
+            workers_halted: Notify::new(),
+        }
+    }
+}
+
+impl Recorder {
+    /// Builds the report, reading the final `number()` of every counter
+    /// deployed during the run.
+    pub async fn finish(&self, seed: u64, error: Option<String>) -> RunReport {
+        let wall_clock_secs = self.started.elapsed().as_secs_f64();
+        let deployed = self.counters.lock().unwrap().clone();
+        let mut counters = Vec::with_capacity(deployed.len());
+        for (deployer, counter) in deployed {
+            let final_number = match counter.number().call().await {
+                Ok(number) => Some(number),
+                Err(e) => {
+                    warn!(
+                        "Failed to read final number of {:?}: {}",
+                        counter.address(),
+                        e
+                    );
+                    None
+                }
+            };
+            counters.push(CounterOutcome {
+                address: counter.address(),
+                deployer,
+                final_number,
+            });
+        }
+        RunReport {
+            seed,
+            wall_clock_secs,
+            blocks: *self.blocks.lock().unwrap(),
+            agents: self.agents.lock().unwrap().clone(),
+            counters,
+            gas: self.gas.lock().unwrap().clone(),
+            events: self.events.lock().unwrap().clone(),
+            violations: self.violations.lock().unwrap().clone(),
+            error,
+            interrupted: shutdown::received(),
+            limit: self.limit.lock().unwrap().clone(),
+        }
+    }
+}
+
+/// Makes `recorder` the destination of the recording functions for the
+/// current run.
+pub fn install(recorder: Arc<Recorder>) {
+    *ACTIVE.write().unwrap() = Some(recorder);
+}
+
+/// Removes and returns the recorder of the current run.
+pub fn uninstall() -> Option<Arc<Recorder>> {
+    ACTIVE.write().unwrap().take()
+}
+
+fn with_recorder(f: impl FnOnce(&Recorder)) {
+    if let Some(recorder) = ACTIVE.read().unwrap().as_deref() {
+        f(recorder)
+    }
+}
+
+fn with_agent(agent: &str, f: impl FnOnce(&mut AgentOutcome)) {
+    with_recorder(|recorder| {
+        f(recorder
+            .agents
+            .lock()
+            .unwrap()
+            .entry(agent.to_owned())
+            .or_default())
+    });
+}
+
+/// Records that `agent` runs `behaviors`.
+pub fn agent_started(agent: &str, behaviors: Vec<String>) {
+    let workers = behaviors
+        .iter()
+        .filter(|behavior| !behaviors::OBSERVERS.contains(&behavior.as_str()))
+        .count();
+    with_recorder(|recorder| *recorder.workers.lock().unwrap() += workers);
+    with_agent(agent, |outcome| outcome.behaviors = behaviors);
+}
+
+/// Resolves once every behavior of the run that is not one of the
+/// [`behaviors::OBSERVERS`] has halted, so observers know when to stop.
+/// Never resolves outside of a run.
+pub async fn workers_halted() {
+    let recorder = ACTIVE.read().unwrap().clone();
+    let Some(recorder) = recorder else {
+        return std::future::pending().await;
+    };
+    loop {
+        let halted = recorder.workers_halted.notified();
+        let remaining = *recorder.workers.lock().unwrap();
+        if remaining == 0 {
+            return;
+        }
+        halted.await;
+    }
+}
+
+/// Records that `agent` deployed `counter`.
+pub fn counter_deployed(agent: &str, counter: &ModifiedCounter<ArbiterMiddleware>) {
+    with_recorder(|recorder| {
+        recorder
+            .counters
+            .lock()
+            .unwrap()
+            .push((agent.to_owned(), counter.clone()))
+    });
+}
+
+/// Records a successful transaction of `agent` calling `function`, with the
+/// gas it used and the events it emitted.
+pub fn transaction(agent: &str, function: &str, receipt: &TransactionReceipt) {
+    with_agent(agent, |outcome| outcome.transactions += 1);
+    with_recorder(|recorder| {
+        let mut gas = recorder.gas.lock().unwrap();
+        let usage = gas.entry(function.to_owned()).or_default();
+        usage.transactions += 1;
+        usage.total += receipt.gas_used.unwrap_or_default();
+
+        let mut events = recorder.events.lock().unwrap();
+        for log in &receipt.logs {
+            if let Some(name) = event_name(&log.clone().into()) {
+                *events.entry(name.to_owned()).or_default() += 1;
+            }
+        }
+
+        if let Some(block) = receipt.block_number {
+            let block = block.as_u64();
+            let mut blocks = recorder.blocks.lock().unwrap();
+            *blocks = Some(match *blocks {
+                Some((first, last)) => (first.min(block), last.max(block)),
+                None => (block, block),
+            });
+        }
+    });
+}
+
+/// Name of the event `log` holds, if it is one of the project's contracts.
+fn event_name(log: &RawLog) -> Option<&'static str> {
+    if let Ok(event) = ModifiedCounterEvents::decode_log(log) {
+        return Some(match event {
+            ModifiedCounterEvents::IncrementedFilter(_) => "Incremented",
+            ModifiedCounterEvents::NumberSetFilter(_) => "NumberSet",
+        });
+    }
+    if let Ok(event) = GuardedCounterEvents::decode_log(log) {
+        return Some(match event {
+            GuardedCounterEvents::IncrementedFilter(_) => "Incremented",
+            GuardedCounterEvents::NumberSetFilter(_) => "NumberSet",
+            GuardedCounterEvents::RoleGrantedFilter(_) => "RoleGranted",
+            GuardedCounterEvents::RoleRevokedFilter(_) => "RoleRevoked",
+            GuardedCounterEvents::OwnershipTransferredFilter(_) => "OwnershipTransferred",
+        });
+    }
+    if let Ok(event) = TokenEvents::decode_log(log) {
+        return Some(match event {
+            TokenEvents::TransferFilter(_) => "Transfer",
+            TokenEvents::ApprovalFilter(_) => "Approval",
+        });
+    }
+    if let Ok(event) = ConstantProductPoolEvents::decode_log(log) {
+        return Some(match event {
+            ConstantProductPoolEvents::LiquidityAddedFilter(_) => "LiquidityAdded",
+            ConstantProductPoolEvents::LiquidityRemovedFilter(_) => "LiquidityRemoved",
+            ConstantProductPoolEvents::SwapFilter(_) => "Swap",
+            ConstantProductPoolEvents::SyncFilter(_) => "Sync",
+        });
+    }
+    if PriceUpdatedFilter::decode_log(log).is_ok() {
+        return Some("PriceUpdated");
+    }
+    None
+}
+
+/// Records a transaction of `agent` that reverted.
+pub fn reverted(agent: &str) {
+    with_agent(agent, |outcome| {
+        outcome.transactions += 1;
+        outcome.reverted += 1;
+    });
+}
+
+/// Records that `agent` incremented a counter.
+pub fn incremented(agent: &str) {
+    with_agent(agent, |outcome| outcome.increments += 1);
+}
+
+/// Records that `agent` called `setNumber`.
+pub fn number_set(agent: &str) {
+    with_agent(agent, |outcome| outcome.number_sets += 1);
+}
+
+/// Records a swap of an `Arbitrageur` of `agent` that made
+/// `realized_profit` quote tokens net of gas.
+pub fn arbitraged(agent: &str, realized_profit: f64) {
+    with_agent(agent, |outcome| {
+        let arbitrage = outcome.arbitrage.get_or_insert_with(Default::default);
+        arbitrage.trades += 1;
+        arbitrage.realized_profit += realized_profit;
+    });
+}
+
+/// Records that a behavior of `agent` failed to handle an event.
+pub fn errored(agent: &str) {
+    with_agent(agent, |outcome| outcome.errors += 1);
+}
+
+/// Records that an invariant was found broken.
+pub fn violated(violation: Violation) {
+    with_recorder(|recorder| recorder.violations.lock().unwrap().push(violation));
+}
+
+/// Records that `limit` stopped the run.
+pub fn limit_reached(limit: LimitReached) {
+    with_recorder(|recorder| {
+        recorder.limit.lock().unwrap().get_or_insert(limit);
+    });
+}
+
+/// Transactions mined and events emitted so far in the run.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct Totals {
+    pub transactions: u64,
+    pub events: u64,
+}
+
+/// Totals of the run in progress, zero outside of a run.
+pub fn totals() -> Totals {
+    let mut totals = Totals::default();
+    with_recorder(|recorder| {
+        totals.transactions = recorder
+            .agents
+            .lock()
+            .unwrap()
+            .values()
+            .map(|agent| agent.transactions)
+            .sum();
+        totals.events = recorder.events.lock().unwrap().values().sum();
+    });
+    totals
+}
+
+/// Records that `behavior` of `agent` returned `ControlFlow::Halt` and why.
+pub fn halted(agent: &str, behavior: &str, reason: impl Into<String>) {
+    halt(agent, behavior, HaltKind::Halted, reason.into());
+}
+
+/// Records that `behavior` of `agent` stopped because of `error`.
+pub fn failed(agent: &str, behavior: &str, error: impl Into<String>) {
+    halt(agent, behavior, HaltKind::Error, error.into());
+}
+
+fn halt(agent: &str, behavior: &str, kind: HaltKind, reason: String) {
+    if !behaviors::OBSERVERS.contains(&behavior) {
+        with_recorder(|recorder| {
+            let mut workers = recorder.workers.lock().unwrap();
+            *workers = workers.saturating_sub(1);
+            if *workers == 0 {
+                recorder.workers_halted.notify_waiters();
+            }
+        });
+    }
+    with_agent(agent, |outcome| {
+        outcome.halts.push(HaltRecord {
+            behavior: behavior.to_owned(),
+            kind,
+            reason,
+        })
+    });
+}
+
+/// Runs a behavior and records every error it returns, from `startup` or from
+/// `process`, as a failure of its agent. An error ends the behavior like
+/// `ControlFlow::Halt` does, so without this a behavior failing outside its
+/// error policy would keep [`workers_halted`] from ever resolving.
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct Recorded<B> {
+    behavior: B,
+    #[serde(skip)]
+    name: &'static str,
+    #[serde(skip)]
+    agent: String,
+}
+
+impl<B> Recorded<B> {
+    /// Wraps `behavior`, recorded under `name` in the run report.
+    pub fn new(name: &'static str, behavior: B) -> Self {
+        Self {
+            behavior,
+            name,
+            agent: String::new(),
+        }
+    }
+
+    fn record(&self, e: &anyhow::Error) {
+        error!("{} of {} failed: {:#}", self.name, self.agent, e);
+        errored(&self.agent);
+        failed(&self.agent, self.name, format!("{:#}", e));
+    }
+}
+
+#[async_trait::async_trait]
+impl<B, E> Behavior<E> for Recorded<B>
+where
+    B: Behavior<E>,
+    E: Send + 'static,
+{
+    async fn startup(
+        &mut self,
+        client: Arc<ArbiterMiddleware>,
+        messager: Messager,
+    ) -> Result<Option<EventStream<E>>> {
+        self.agent = messager.id.clone().unwrap_or_default();
+        let result = self.behavior.startup(client, messager).await;
+        if let Err(e) = &result {
+            // Stopped before it got going, which is no failure.
+            if let Some(SimulationError::Stopped(reason)) = e.downcast_ref() {
+                info!("{} of {} stopped: {}", self.name, self.agent, reason);
+                halted(&self.agent, self.name, format!("stopped: {}", reason));
+                return Ok(None);
+            }
+            self.record(e);
+        }
+        result
+    }
+
+    async fn process(&mut self, event: E) -> Result<ControlFlow> {
+        let result = self.behavior.process(event).await;
+        if let Err(e) = &result {
+            self.record(e);
+        }
+        result
+    }
+}
@@ -0,0 +1,414 @@
+use std::{
+    env, fs,
+    path::{Path, PathBuf},
+};
+
+use anyhow::{anyhow, bail, ensure, Context, Result};
+use toml::{Table, Value};
+
+/// Top-level key listing the files a config builds on, e.g.
+/// `include = ["base.toml"]`.
+const INCLUDE_KEY: &str = "include";
+
+/// Top-level table of named behavior entries that agent entries can extend,
+/// e.g. `[presets.fast]`.
+const PRESETS_KEY: &str = "presets";
+
+/// Key of an agent entry naming the preset it extends, e.g.
+/// `extends = "fast"`.
+const EXTENDS_KEY: &str = "extends";
+
+/// Reads the config at `path` and resolves, in order, its includes, the
+/// presets its agents extend and the environment variables its strings refer
+/// to.
+pub fn load(path: &Path) -> Result<Table> {
+    let mut table = load_with_includes(path, &mut Vec::new())?;
+    resolve_presets(&mut table)?;
+    interpolate_table(&mut table, &|name| env::var(name).ok())?;
+    Ok(table)
+}
+
+/// Reads `path` and merges it over the files it includes, which are merged
+/// over each other in order. `stack` holds the files being read to catch
+/// cycles.
+fn load_with_includes(path: &Path, stack: &mut Vec<PathBuf>) -> Result<Table> {
+    let canonical = path
+        .canonicalize()
+        .with_context(|| format!("failed to read {}", path.display()))?;
+    ensure!(
+        !stack.contains(&canonical),
+        "{} includes itself",
+        path.display()
+    );
+    let contents =
+        fs::read_to_string(path).with_context(|| format!("failed to read {}", path.display()))?;
+    let mut table: Table =
+        toml::from_str(&contents).with_context(|| format!("failed to parse {}", path.display()))?;
+
+    let Some(includes) = table.remove(INCLUDE_KEY) else {
+        return Ok(table);
+    };
+    let includes = includes
+        .as_array()
+        .ok_or_else(|| anyhow!("`include` in {} must be a list of paths", path.display()))?;
+    let dir = path.parent().unwrap_or_else(|| Path::new(""));
+    stack.push(canonical);
+    let mut merged = Table::new();
+    for include in includes {
+        let include = include.as_str().ok_or_else(|| {
+            anyhow!(
+                "`include` in {} must be a list of paths, found `{}`",
+                path.display(),
+                include
+            )
+        })?;
+        let included = load_with_includes(&dir.join(include), stack)
+            .with_context(|| format!("failed to include {} from {}", include, path.display()))?;
+        merge(&mut merged, included);
+    }
+    stack.pop();
+    merge(&mut merged, table);
+    Ok(merged)
+}
+
+/// Merges `overrides` into `base`: tables are merged key by key, any other
+/// value of `overrides` replaces the one in `base`.
+fn merge(base: &mut Table, overrides: Table) {
+    for (key, value) in overrides {
+        let value = match (base.get_mut(&key), value) {
+            (Some(Value::Table(base)), Value::Table(overrides)) => {
+                merge(base, overrides);
+                continue;
+            }
+            (_, value) => value,
+        };
+        base.insert(key, value);
+    }
+}
+
+/// Replaces every agent entry that `extends` a preset by the preset merged
+/// with the entry, and drops the presets.
+fn resolve_presets(table: &mut Table) -> Result<()> {
+    let presets = match table.remove(PRESETS_KEY) {
+        Some(Value::Table(presets)) => presets,
+        Some(other) => bail!("`presets` must be a table, found `{}`", other),
+        None => Table::new(),
+    };
+    for (agent, entries) in table.iter_mut() {
+        let Some(entries) = entries.as_array_mut() else {
+            continue;
+        };
+        for entry in entries.iter_mut().filter_map(Value::as_table_mut) {
+            *entry = extend(entry.clone(), &presets, &mut Vec::new())
+                .with_context(|| format!("invalid preset in agent `{}`", agent))?;
+        }
+    }
+    Ok(())
+}
+
+/// Resolves the preset chain of `entry`. `chain` holds the presets already
+/// applied to catch cycles.
+fn extend(mut entry: Table, presets: &Table, chain: &mut Vec<String>) -> Result<Table> {
+    let Some(name) = entry.remove(EXTENDS_KEY) else {
+        return Ok(entry);
+    };
+    let name = name
+        .as_str()
+        .ok_or_else(|| anyhow!("`extends` must be a preset name, found `{}`", name))?
+        .to_owned();
+    ensure!(!chain.contains(&name), "preset `{}` extends itself", name);
+    let preset = presets
+        .get(&name)
+        .and_then(Value::as_table)
+        .ok_or_else(|| {
+            let known: Vec<&str> = presets.keys().map(String::as_str).collect();
+            anyhow!(
+                "unknown preset `{}`, expected one of: {}",
+                name,
+                known.join(", ")
+            )
+        })?
+        .clone();
+    chain.push(name);
+    let mut base = extend(preset, presets, chain)?;
+    merge(&mut base, entry);
+    Ok(base)
+}
+
+/// Looks up the value of an environment variable, `None` if it is not set.
+type Lookup<'a> = &'a dyn Fn(&str) -> Option<String>;
+
+fn interpolate_table(table: &mut Table, lookup: Lookup) -> Result<()> {
+    for (key, value) in table.iter_mut() {
+        interpolate_value(value, lookup).with_context(|| format!("in `{}`", key))?;
+    }
+    Ok(())
+}
+
+fn interpolate_value(value: &mut Value, lookup: Lookup) -> Result<()> {
+    match value {
+        Value::String(string) if string.contains("${") => {
+            let whole = string.starts_with("${")
+                && string.ends_with('}')
+                && string.matches("${").count() == 1;
+            let interpolated = interpolate(string, lookup)?;
+            // A value that is a single variable takes the type of what it
+            // expands to, so `"${SEED}"` can set an integer.
+            *value = match whole {
+                true => scalar(&interpolated),
+                false => Value::String(interpolated),
+            };
+        }
+        Value::Table(table) => interpolate_table(table, lookup)?,
+        Value::Array(array) => {
+            for value in array {
+                interpolate_value(value, lookup)?;
+            }
+        }
+        _ => {}
+    }
+    Ok(())
+}
+
+/// Replaces every `${VAR}` and `${VAR:-default}` in `string`.
+fn interpolate(string: &str, lookup: Lookup) -> Result<String> {
+    let mut out = String::new();
+    let mut rest = string;
+    while let Some(start) = rest.find("${") {
+        out.push_str(&rest[..start]);
+        let end = rest[start..]
+            .find('}')
+            .ok_or_else(|| anyhow!("unterminated `${{` in `{}`", string))?;
+        let reference = &rest[start + 2..start + end];
+        let (name, default) = match reference.split_once(":-") {
+            Some((name, default)) => (name, Some(default)),
+            None => (reference, None),
+        };
+        match (lookup(name), default) {
+            (Some(value), _) => out.push_str(&value),
+            (None, Some(default)) => out.push_str(default),
+            (None, None) => bail!("environment variable `{}` is not set", name),
+        }
+        rest = &rest[start + end + 1..];
+    }
+    out.push_str(rest);
+    Ok(out)
+}
+
+/// Parses `string` as a TOML integer, float or boolean, keeping it a string
+/// otherwise.
+fn scalar(string: &str) -> Value {
+    match toml::from_str::<Table>(&format!("value = {}", string)) {
+        Ok(mut table) => match table.remove("value") {
+            Some(value @ (Value::Integer(_) | Value::Float(_) | Value::Boolean(_))) => value,
+            _ => Value::String(string.to_owned()),
+        },
+        Err(_) => Value::String(string.to_owned()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn table(toml: &str) -> Table {
+        toml::from_str(toml).unwrap()
+    }
+
+    /// Interpolates `value = <toml>` with the environment variables `vars`
+    /// and returns the resolved value.
+    fn interpolated(toml: &str, vars: &[(&str, &str)]) -> Result<Value> {
+        let mut table = table(&format!("value = {}", toml));
+        let lookup = |name: &str| {
+            vars.iter()
+                .find(|(var, _)| *var == name)
+                .map(|(_, value)| value.to_string())
+        };
+        interpolate_table(&mut table, &lookup)?;
+        Ok(table["value"].clone())
+    }
+
+    #[test]
+    fn default_applies_to_unset_variables_only() {
+        let vars = &[("SET", "from env")];
+        assert_eq!(
+            interpolated(r#""${SET:-fallback}""#, vars).unwrap(),
+            Value::String("from env".to_owned())
+        );
+        assert_eq!(
+            interpolated(r#""${UNSET:-fallback}""#, vars).unwrap(),
+            Value::String("fallback".to_owned())
+        );
+        assert_eq!(
+            interpolated(r#""${UNSET:-}""#, vars).unwrap(),
+            Value::String(String::new())
+        );
+    }
+
+    #[test]
+    fn single_reference_takes_the_type_of_its_value() {
+        let vars = &[
+            ("INTEGER", "42"),
+            ("FLOAT", "0.5"),
+            ("BOOLEAN", "true"),
+            ("WORD", "fast"),
+        ];
+        assert_eq!(
+            interpolated(r#""${INTEGER}""#, vars).unwrap(),
+            Value::Integer(42)
+        );
+        assert_eq!(
+            interpolated(r#""${FLOAT}""#, vars).unwrap(),
+            Value::Float(0.5)
+        );
+        assert_eq!(
+            interpolated(r#""${BOOLEAN}""#, vars).unwrap(),
+            Value::Boolean(true)
+        );
+        assert_eq!(
+            interpolated(r#""${WORD}""#, vars).unwrap(),
+            Value::String("fast".to_owned())
+        );
+        assert_eq!(
+            interpolated(r#""${UNSET_TYPED:-7}""#, vars).unwrap(),
+            Value::Integer(7)
+        );
+    }
+
+    #[test]
+    fn embedded_references_stay_strings() {
+        let vars = &[("RUN", "3")];
+        assert_eq!(
+            interpolated(r#""run-${RUN}""#, vars).unwrap(),
+            Value::String("run-3".to_owned())
+        );
+        assert_eq!(
+            interpolated(r#""${RUN}${RUN}""#, vars).unwrap(),
+            Value::String("33".to_owned())
+        );
+        assert_eq!(
+            interpolated(r#"["${RUN}", "x${RUN}"]"#, vars).unwrap(),
+            Value::Array(vec![Value::Integer(3), Value::String("x3".to_owned())])
+        );
+    }
+
+    #[test]
+    fn unset_variable_without_default_is_an_error() {
+        let vars = &[];
+        let error = interpolated(r#""${MISSING}""#, vars).unwrap_err();
+        assert!(format!("{:#}", error).contains("`MISSING` is not set"));
+        assert!(interpolated(r#""${MISSING""#, vars).is_err());
+    }
+
+    #[test]
+    fn tables_merge_and_other_values_replace() {
+        let mut base = table(
+            r#"
+            seed = 1
+            list = [1, 2, 3]
+            [nested]
+            kept = "base"
+            replaced = "base"
+            [nested.deeper]
+            kept = true
+            "#,
+        );
+        merge(
+            &mut base,
+            table(
+                r#"
+                list = [4]
+                [nested]
+                replaced = "override"
+                added = 2
+                [nested.deeper]
+                other = false
+                "#,
+            ),
+        );
+        assert_eq!(
+            base,
+            table(
+                r#"
+                seed = 1
+                list = [4]
+                [nested]
+                kept = "base"
+                replaced = "override"
+                added = 2
+                [nested.deeper]
+                kept = true
+                other = false
+                "#,
+            )
+        );
+
+        let mut base = table("value = { a = 1 }");
+        merge(&mut base, table("value = 2"));
+        assert_eq!(base, table("value = 2"));
+    }
+
+    #[test]
+    fn presets_chain_under_their_entries() {
+        let mut config = table(
+            r#"
+            [presets.base]
+            Incrementer = { max_number_of_times = 5, interval = 1 }
+            [presets.fast]
+            extends = "base"
+            Incrementer = { interval = 0 }
+
+            [[agent]]
+            extends = "fast"
+            Incrementer = { max_number_of_times = 9 }
+            "#,
+        );
+        resolve_presets(&mut config).unwrap();
+        assert_eq!(
+            config,
+            table(
+                r#"
+                [[agent]]
+                Incrementer = { max_number_of_times = 9, interval = 0 }
+                "#
+            )
+        );
+    }
+
+    #[test]
+    fn preset_cycles_are_rejected() {
+        for presets in [
+            r#"
+            [presets.a]
+            extends = "a"
+            "#,
+            r#"
+            [presets.a]
+            extends = "b"
+            [presets.b]
+            extends = "a"
+            "#,
+        ] {
+            let mut config = table(&format!("{}\n[[agent]]\nextends = \"a\"", presets));
+            let error = resolve_presets(&mut config).unwrap_err();
+            assert!(format!("{:#}", error).contains("extends itself"));
+        }
+    }
+
+    #[test]
+    fn unknown_preset_is_rejected() {
+        let mut config = table("[[agent]]\nextends = \"missing\"");
+        assert!(resolve_presets(&mut config).is_err());
+    }
+
+    #[test]
+    fn include_cycles_are_rejected() {
+        let dir = env::temp_dir().join(format!("resolve-test-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(dir.join("a.toml"), "include = [\"b.toml\"]").unwrap();
+        fs::write(dir.join("b.toml"), "include = [\"a.toml\"]").unwrap();
+        let error = load(&dir.join("a.toml")).unwrap_err();
+        fs::remove_dir_all(&dir).unwrap();
+        assert!(format!("{:#}", error).contains("includes itself"));
+    }
+}
@@ -0,0 +1,59 @@
+use std::sync::RwLock;
+
+use rand::SeedableRng;
+use rand_chacha::ChaCha8Rng;
+
+/// The random number generator handed to behaviors.
+pub type SimRng = ChaCha8Rng;
+
+/// The seed of the run in progress.
+static SEED: RwLock<Option<u64>> = RwLock::new(None);
+
+/// Seeds every stream handed out by [`stream`] for the current run.
+pub fn install(seed: u64) {
+    *SEED.write().unwrap() = Some(seed);
+}
+
+pub fn uninstall() {
+    *SEED.write().unwrap() = None;
+}
+
+/// Returns a fresh seed for runs that do not configure one. Kept below `2^63`
+/// so it round-trips through TOML integers.
+pub fn random_seed() -> u64 {
+    rand::random::<u64>() >> 1
+}
+
+/// Returns the random stream of `behavior` running on `agent`, typically
+/// obtained once in `Behavior::startup`.
+///
+/// Every `(agent, behavior)` pair gets its own ChaCha stream of the run seed,
+/// so streams are independent of each other and of the order agents start in,
+/// and rerunning a config with the same seed reproduces them exactly.
+pub fn stream(agent: &str, behavior: &str) -> SimRng {
+    // Outside of a run there is nothing to reproduce, any fixed seed will do.
+    let seed = SEED.read().unwrap().unwrap_or_default();
+    seeded_stream(seed, agent, behavior)
+}
+
+/// Returns the stream [`stream`] would hand out if the run was seeded with
+/// `seed`, for randomness needed before the run starts.
+pub fn seeded_stream(seed: u64, agent: &str, behavior: &str) -> SimRng {
+    let mut rng = SimRng::seed_from_u64(seed);
+    rng.set_stream(stream_id(agent, behavior));
+    rng
+}
+
+/// FNV-1a of `agent` and `behavior`, which unlike `DefaultHasher` is
+/// guaranteed to stay the same across Rust releases.
+fn stream_id(agent: &str, behavior: &str) -> u64 {
+    const OFFSET: u64 = 0xcbf2_9ce4_8422_2325;
+    const PRIME: u64 = 0x0000_0100_0000_01b3;
+    agent
+        .bytes()
+        .chain([0xff])
+        .chain(behavior.bytes())
+        .fold(OFFSET, |hash, byte| {
+            (hash ^ byte as u64).wrapping_mul(PRIME)
+        })
+}
@@ -0,0 +1,152 @@
+use std::{
+    fs::{self, File},
+    io::{self, Write},
+    path::{Path, PathBuf},
+    process::Command,
+    sync::{Arc, Mutex},
+};
+
+use anyhow::{Context, Result};
+use chrono::Utc;
+use serde::Serialize;
+
+use crate::{
+    config::SimulationConfig,
+    replay::{self, TxLog},
+    rng,
+};
+
+/// The log file of the run in progress, receiving a copy of every log line.
+static LOG: Mutex<Option<File>> = Mutex::new(None);
+
+/// File name of the resolved config in a run directory.
+pub const CONFIG_FILE: &str = "config.toml";
+/// File name of the run metadata in a run directory.
+pub const METADATA_FILE: &str = "metadata.json";
+/// File name of the run report in a run directory.
+pub const REPORT_FILE: &str = "report.json";
+/// File name of the tracing log in a run directory.
+pub const LOG_FILE: &str = "trace.log";
+
+/// Everything besides the config needed to reproduce a run.
+#[derive(Debug, Clone, Serialize)]
+pub struct RunMetadata {
+    /// The config file the run was started from.
+    pub config: PathBuf,
+    /// UTC time the run started, in RFC 3339.
+    pub started_at: String,
+    pub seed: u64,
+    /// Commit checked out in the working directory, if it is a git checkout.
+    pub git_commit: Option<String>,
+    /// Whether the checkout had uncommitted changes.
+    pub git_dirty: bool,
+    pub arbiter_core: &'static str,
+    pub arbiter_engine: &'static str,
+}
+
+/// Returns a fresh directory under `root` named after `config_path` and the
+/// current time, e.g. `output/example-20240301-120000`.
+pub fn timestamped(root: &Path, config_path: &Path) -> PathBuf {
+    let stem = config_path
+        .file_stem()
+        .map(|stem| stem.to_string_lossy().into_owned())
+        .unwrap_or_else(|| "run".to_owned());
+    let name = format!("{}-{}", stem, Utc::now().format("%Y%m%d-%H%M%S"));
+    let mut dir = root.join(&name);
+    // Runs started within the same second get a suffix instead of sharing a
+    // directory.
+    let mut suffix = 1;
+    while dir.exists() {
+        dir = root.join(format!("{}-{}", name, suffix));
+        suffix += 1;
+    }
+    dir
+}
+
+/// Sets `dir` up for a run of `config`: fixes the seed so the copy of the
+/// config reproduces the run, points the collector outputs into `dir`, writes
+/// the config and the run metadata, and starts recording transactions and
+/// copying logs to `dir`.
+pub fn prepare(dir: &Path, config_path: &Path, config: &mut SimulationConfig) -> Result<()> {
+    fs::create_dir_all(dir).with_context(|| format!("failed to create {}", dir.display()))?;
+    let seed = *config.seed.get_or_insert_with(rng::random_seed);
+    config.place_outputs_in(dir);
+    write(&dir.join(CONFIG_FILE), &toml::to_string(config)?)?;
+
+    let (git_commit, git_dirty) = git_state();
+    let metadata = RunMetadata {
+        config: config_path.to_path_buf(),
+        started_at: Utc::now().to_rfc3339(),
+        seed,
+        git_commit,
+        git_dirty,
+        arbiter_core: env!("ARBITER_CORE_VERSION"),
+        arbiter_engine: env!("ARBITER_ENGINE_VERSION"),
+    };
+    write(
+        &dir.join(METADATA_FILE),
+        &serde_json::to_string_pretty(&metadata)?,
+    )?;
+
+    let transactions = TxLog::create(&dir.join(replay::TRANSACTIONS_FILE))?;
+    replay::install(Arc::new(transactions));
+
+    let log_path = dir.join(LOG_FILE);
+    let log = File::create(&log_path)
+        .with_context(|| format!("failed to create {}", log_path.display()))?;
+    *LOG.lock().unwrap() = Some(log);
+    Ok(())
+}
+
+/// Stops recording transactions and copying logs to the directory of the
+/// finished run.
+pub fn finish() -> Result<()> {
+    if let Some(mut log) = LOG.lock().unwrap().take() {
+        let _ = log.flush();
+    }
+    match replay::uninstall() {
+        Some(transactions) => transactions.finish(),
+        None => Ok(()),
+    }
+}
+
+fn write(path: &Path, contents: &str) -> Result<()> {
+    fs::write(path, contents).with_context(|| format!("failed to write {}", path.display()))
+}
+
+/// The commit of the working directory and whether it has uncommitted
+/// changes, `None` outside of a git checkout.
+fn git_state() -> (Option<String>, bool) {
+    let git = |args: &[&str]| {
+        Command::new("git")
+            .args(args)
+            .output()
+            .ok()
+            .filter(|output| output.status.success())
+            .map(|output| String::from_utf8_lossy(&output.stdout).trim().to_owned())
+    };
+    let commit = git(&["rev-parse", "HEAD"]);
+    let dirty = commit.is_some()
+        && git(&["status", "--porcelain"]).is_some_and(|status| !status.is_empty());
+    (commit, dirty)
+}
+
+/// Writes log lines to the log file of the run in progress, discarding them
+/// between runs. Used as the writer of the file logging layer.
+pub struct LogWriter;
+
+impl Write for LogWriter {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        match LOG.lock().unwrap().as_mut() {
+            Some(log) => log.write(buf),
+            None => Ok(buf.len()),
+        }
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        match LOG.lock().unwrap().as_mut() {
+            Some(log) => log.flush(),
+            None => Ok(()),
+        }
+    }
+}
@@ -0,0 +1,149 @@
+use std::{
+    fmt,
+    sync::{Mutex, OnceLock},
+};
+
+use arbiter_engine::messager::Messager;
+use serde::{Deserialize, Serialize};
+use tracing::{error, warn};
+
+use crate::messages::{MessagerExt, Stop};
+
+/// The first shutdown signal the process received.
+static RECEIVED: OnceLock<Signal> = OnceLock::new();
+
+/// Messager of the world in progress, used to stop its behaviors.
+static WORLD: Mutex<Option<Messager>> = Mutex::new(None);
+
+/// Agent name shutdown `Stop` messages are sent from.
+const SENDER: &str = "shutdown";
+
+/// A signal asking the process to shut down.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Signal {
+    #[serde(rename = "SIGINT")]
+    Interrupt,
+    #[serde(rename = "SIGTERM")]
+    Terminate,
+}
+
+impl Signal {
+    /// Exit status of a process stopped by the signal, `128` plus its number
+    /// as shells report it.
+    pub fn exit_code(self) -> i32 {
+        match self {
+            Signal::Interrupt => 130,
+            Signal::Terminate => 143,
+        }
+    }
+}
+
+impl fmt::Display for Signal {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Signal::Interrupt => f.write_str("SIGINT"),
+            Signal::Terminate => f.write_str("SIGTERM"),
+        }
+    }
+}
+
+/// Handles SIGINT and SIGTERM for the rest of the process: the first one
+/// stops every behavior of the world in progress so the run can flush its
+/// outputs, a second one exits right away.
+///
+/// The handlers are registered before this returns, so a signal arriving
+/// while the world starts up is not missed.
+pub fn listen() {
+    let mut signals = match Signals::register() {
+        Ok(signals) => signals,
+        Err(e) => {
+            error!("Failed to listen for shutdown signals: {}", e);
+            return;
+        }
+    };
+    tokio::spawn(async move {
+        loop {
+            let signal = signals.next().await;
+            if RECEIVED.set(signal).is_err() {
+                eprintln!("Received {} again, exiting without flushing", signal);
+                std::process::exit(signal.exit_code());
+            }
+            eprintln!(
+                "Received {}, stopping every behavior, repeat to exit right away",
+                signal
+            );
+            stop(signal).await;
+        }
+    });
+}
+
+#[cfg(unix)]
+struct Signals {
+    interrupt: tokio::signal::unix::Signal,
+    terminate: tokio::signal::unix::Signal,
+}
+
+#[cfg(unix)]
+impl Signals {
+    fn register() -> std::io::Result<Self> {
+        use tokio::signal::unix::{signal, SignalKind};
+
+        Ok(Self {
+            interrupt: signal(SignalKind::interrupt())?,
+            terminate: signal(SignalKind::terminate())?,
+        })
+    }
+
+    async fn next(&mut self) -> Signal {
+        tokio::select! {
+            _ = self.interrupt.recv() => Signal::Interrupt,
+            _ = self.terminate.recv() => Signal::Terminate,
+        }
+    }
+}
+
+#[cfg(not(unix))]
+struct Signals {
+    ctrl_c: tokio::signal::windows::CtrlC,
+}
+
+#[cfg(not(unix))]
+impl Signals {
+    fn register() -> std::io::Result<Self> {
+        Ok(Self {
+            ctrl_c: tokio::signal::windows::ctrl_c()?,
+        })
+    }
+
+    async fn next(&mut self) -> Signal {
+        self.ctrl_c.recv().await;
+        Signal::Interrupt
+    }
+}
+
+async fn stop(signal: Signal) {
+    let messager = WORLD.lock().unwrap().clone();
+    let Some(messager) = messager else {
+        return;
+    };
+    let reason = format!("interrupted by {}", signal);
+    if let Err(e) = messager.broadcast(Stop { reason }).await {
+        warn!("Failed to stop behaviors: {}", e);
+    }
+}
+
+/// Makes the world `messager` belongs to the one a shutdown signal stops.
+pub fn install(messager: &Messager) {
+    let mut messager = messager.clone();
+    messager.id = Some(SENDER.to_owned());
+    *WORLD.lock().unwrap() = Some(messager);
+}
+
+pub fn uninstall() {
+    *WORLD.lock().unwrap() = None;
+}
+
+/// The shutdown signal the process received, if any.
+pub fn received() -> Option<Signal> {
+    RECEIVED.get().copied()
+}
@@ -0,0 +1,51 @@
+use std::sync::Arc;
+
+use anyhow::Result;
+use tracing::info;
+
+use crate::{
+    accounts,
+    collector::{self, Collector},
+    config::SimulationConfig,
+    limits, replay,
+    report::{self, Recorder, RunReport},
+    rng, shutdown, tx,
+};
+
+/// Runs a single simulation to completion.
+///
+/// Only failures to set the run up are returned as errors, a world that stops
+/// with an error is recorded in the returned report.
+pub async fn run(mut config: SimulationConfig) -> Result<RunReport> {
+    if let Some(collector_config) = config.collector.take() {
+        collector::install(Arc::new(Collector::new(&collector_config)?));
+        info!("Collecting events to {}", collector_config.path.display());
+    }
+    report::install(Arc::new(Recorder::default()));
+    tx::reset();
+    replay::reset();
+    accounts::reset();
+    let seed = config.seed.unwrap_or_else(rng::random_seed);
+    rng::install(seed);
+    info!("Seeding run with {}", seed);
+
+    for (agent, behaviors) in &config.agents {
+        let names = behaviors.iter().map(|behavior| behavior.name().to_owned());
+        report::agent_started(agent, names.collect());
+    }
+    if !config.limits().is_empty() {
+        report::agent_started(limits::AGENT, vec![limits::BEHAVIOR.to_owned()]);
+    }
+
+    let mut world = config.build_world();
+    shutdown::install(&world.messager);
+    let error = world.run().await.err().map(|e| e.to_string());
+    shutdown::uninstall();
+
+    if let Some(collector) = collector::uninstall() {
+        collector.finish().await?;
+    }
+    rng::uninstall();
+    let recorder = report::uninstall().unwrap_or_default();
+    Ok(recorder.finish(seed, error).await)
+}
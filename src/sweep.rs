@@ -0,0 +1,464 @@
+use std::{
+    collections::BTreeMap,
+    fmt, fs,
+    path::{Path, PathBuf},
+};
+
+use anyhow::{anyhow, bail, ensure, Context, Result};
+use serde::Serialize;
+use toml::{Table, Value};
+use tracing::{error, info};
+
+use crate::{config::SimulationConfig, run_dir, shutdown, simulation};
+
+/// Key marking a config value as swept, e.g. `{ sweep = [1, 5, 10] }` or
+/// `{ sweep = "range(1, 20, 5)" }`.
+const SWEEP_KEY: &str = "sweep";
+
+/// Most values a `range` may produce, which keeps a mistyped bound from
+/// expanding into millions of runs.
+const MAX_RANGE_POINTS: usize = 10_000;
+
+/// One concrete parameter point of a swept config.
+#[derive(Debug, Clone)]
+pub struct SweepPoint {
+    /// The value chosen for every swept field, keyed by its path in the config.
+    pub parameters: BTreeMap<String, Value>,
+    /// The config with every swept field replaced by its value.
+    pub table: Table,
+}
+
+#[derive(Debug, Clone)]
+enum Segment {
+    Key(String),
+    Index(usize),
+}
+
+#[derive(Debug, Clone, Default)]
+struct FieldPath(Vec<Segment>);
+
+impl fmt::Display for FieldPath {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, segment) in self.0.iter().enumerate() {
+            match segment {
+                Segment::Key(key) if i == 0 => write!(f, "{}", key)?,
+                Segment::Key(key) => write!(f, ".{}", key)?,
+                Segment::Index(index) => write!(f, "[{}]", index)?,
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Expands every swept field of `table` into the cartesian product of their
+/// values. A config without sweeps expands to a single point with no
+/// parameters.
+pub fn expand(table: &Table) -> Result<Vec<SweepPoint>> {
+    let mut axes = Vec::new();
+    collect_table(table, &mut FieldPath::default(), &mut axes)?;
+
+    let mut points = vec![SweepPoint {
+        parameters: BTreeMap::new(),
+        table: table.clone(),
+    }];
+    for (path, values) in &axes {
+        let name = &path.to_string();
+        points = points
+            .into_iter()
+            .flat_map(|point| {
+                values.iter().map(move |value| {
+                    let mut point = point.clone();
+                    set(&mut point.table, &path.0, value.clone());
+                    point.parameters.insert(name.clone(), value.clone());
+                    point
+                })
+            })
+            .collect();
+    }
+    Ok(points)
+}
+
+fn collect_table(
+    table: &Table,
+    path: &mut FieldPath,
+    axes: &mut Vec<(FieldPath, Vec<Value>)>,
+) -> Result<()> {
+    for (key, value) in table {
+        path.0.push(Segment::Key(key.clone()));
+        collect_value(value, path, axes)?;
+        path.0.pop();
+    }
+    Ok(())
+}
+
+fn collect_value(
+    value: &Value,
+    path: &mut FieldPath,
+    axes: &mut Vec<(FieldPath, Vec<Value>)>,
+) -> Result<()> {
+    match value {
+        Value::Table(table) => match sweep_values(table) {
+            Some(values) => {
+                let values = values.with_context(|| format!("invalid sweep at `{}`", path))?;
+                ensure!(!values.is_empty(), "sweep at `{}` has no values", path);
+                axes.push((path.clone(), values));
+            }
+            None => collect_table(table, path, axes)?,
+        },
+        Value::Array(array) => {
+            for (index, value) in array.iter().enumerate() {
+                path.0.push(Segment::Index(index));
+                collect_value(value, path, axes)?;
+                path.0.pop();
+            }
+        }
+        _ => {}
+    }
+    Ok(())
+}
+
+/// Returns the values of `table` if it is a sweep.
+fn sweep_values(table: &Table) -> Option<Result<Vec<Value>>> {
+    if table.len() != 1 {
+        return None;
+    }
+    Some(match table.get(SWEEP_KEY)? {
+        Value::Array(values) => Ok(values.clone()),
+        Value::String(range) => parse_range(range),
+        other => Err(anyhow!(
+            "expected a list or `range(start, stop, step)`, found `{}`",
+            other
+        )),
+    })
+}
+
+/// Parses `range(start, stop)` or `range(start, stop, step)`. Like Python's
+/// `range`, `stop` is exclusive. Integer bounds produce integers, any float
+/// bound produces floats.
+fn parse_range(range: &str) -> Result<Vec<Value>> {
+    let args = range
+        .trim()
+        .strip_prefix("range(")
+        .and_then(|rest| rest.strip_suffix(')'))
+        .ok_or_else(|| anyhow!("expected `range(start, stop, step)`, found `{}`", range))?;
+    let args: Vec<&str> = args.split(',').map(str::trim).collect();
+    ensure!(
+        args.len() == 2 || args.len() == 3,
+        "`range` takes 2 or 3 arguments, found {}",
+        args.len()
+    );
+
+    if let Ok(ints) = args
+        .iter()
+        .map(|arg| arg.parse::<i64>())
+        .collect::<Result<Vec<_>, _>>()
+    {
+        let step = ints.get(2).copied().unwrap_or(1);
+        ensure!(step != 0, "`range` step must not be zero");
+        let mut values = Vec::new();
+        let mut next = ints[0];
+        while (step > 0 && next < ints[1]) || (step < 0 && next > ints[1]) {
+            ensure_below_max(range, values.len())?;
+            values.push(Value::Integer(next));
+            // Past `i64` is past `stop` too.
+            let Some(after) = next.checked_add(step) else {
+                break;
+            };
+            next = after;
+        }
+        return Ok(values);
+    }
+
+    let floats = args
+        .iter()
+        .map(|arg| arg.parse::<f64>())
+        .collect::<Result<Vec<_>, _>>()
+        .with_context(|| format!("`range` arguments must be numbers, found `{}`", range))?;
+    ensure!(
+        floats.iter().all(|float| float.is_finite()),
+        "`range` arguments must be finite, found `{}`",
+        range
+    );
+    let step = floats.get(2).copied().unwrap_or(1.0);
+    ensure!(step != 0.0, "`range` step must not be zero");
+    let mut values = Vec::new();
+    let mut i = 0;
+    loop {
+        let next = floats[0] + step * i as f64;
+        if (step > 0.0 && next >= floats[1]) || (step < 0.0 && next <= floats[1]) {
+            break;
+        }
+        ensure_below_max(range, values.len())?;
+        values.push(Value::Float(next));
+        i += 1;
+    }
+    Ok(values)
+}
+
+fn ensure_below_max(range: &str, len: usize) -> Result<()> {
+    ensure!(
+        len < MAX_RANGE_POINTS,
+        "`{}` has more than {} values",
+        range,
+        MAX_RANGE_POINTS
+    );
+    Ok(())
+}
+
+fn set(table: &mut Table, path: &[Segment], value: Value) {
+    let Some((Segment::Key(key), rest)) = path.split_first() else {
+        unreachable!("field paths start at a top-level key");
+    };
+    let slot = table.get_mut(key).expect("swept field exists");
+    set_value(slot, rest, value);
+}
+
+fn set_value(slot: &mut Value, path: &[Segment], value: Value) {
+    match path.split_first() {
+        None => *slot = value,
+        Some((Segment::Key(key), rest)) => {
+            let slot = slot.get_mut(key.as_str()).expect("swept field exists");
+            set_value(slot, rest, value)
+        }
+        Some((Segment::Index(index), rest)) => {
+            let slot = slot.get_mut(*index).expect("swept field exists");
+            set_value(slot, rest, value)
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+struct Manifest {
+    config: PathBuf,
+    runs: Vec<ManifestEntry>,
+}
+
+#[derive(Debug, Serialize)]
+struct ManifestEntry {
+    index: usize,
+    directory: PathBuf,
+    parameters: BTreeMap<String, Value>,
+    status: RunStatus,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    error: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "lowercase")]
+enum RunStatus {
+    Completed,
+    Failed,
+}
+
+/// Runs every point of a sweep one after another. Each point gets its own
+/// run directory under `sweep_dir` holding its resolved config, metadata,
+/// log, run report and collector output, and `manifest.json` links each
+/// point's parameters to its directory.
+pub async fn run(config_path: &Path, points: Vec<SweepPoint>, sweep_dir: &Path) -> Result<()> {
+    fs::create_dir_all(sweep_dir)
+        .with_context(|| format!("failed to create {}", sweep_dir.display()))?;
+
+    let mut manifest = Manifest {
+        config: config_path.to_path_buf(),
+        runs: Vec::with_capacity(points.len()),
+    };
+    let total = points.len();
+    for (index, point) in points.into_iter().enumerate() {
+        if shutdown::received().is_some() {
+            info!("Sweep interrupted, skipping the remaining runs");
+            break;
+        }
+        let run_dir = sweep_dir.join(format!("run_{:04}", index));
+        info!("Sweep run {}/{}: {:?}", index + 1, total, point.parameters);
+        let result = run_point(config_path, &point, &run_dir).await;
+        if let Err(e) = &result {
+            error!("Sweep run {} failed: {:#}", index, e);
+        }
+        manifest.runs.push(ManifestEntry {
+            index,
+            directory: run_dir,
+            parameters: point.parameters,
+            status: match result {
+                Ok(()) => RunStatus::Completed,
+                Err(_) => RunStatus::Failed,
+            },
+            error: result.err().map(|e| format!("{:#}", e)),
+        });
+    }
+
+    let manifest_path = sweep_dir.join("manifest.json");
+    fs::write(&manifest_path, serde_json::to_string_pretty(&manifest)?)
+        .with_context(|| format!("failed to write {}", manifest_path.display()))?;
+    println!("Sweep manifest written to {}", manifest_path.display());
+
+    let failed = manifest
+        .runs
+        .iter()
+        .filter(|run| matches!(run.status, RunStatus::Failed))
+        .count();
+    if failed > 0 {
+        bail!("{} of {} sweep runs failed", failed, total);
+    }
+    Ok(())
+}
+
+async fn run_point(config_path: &Path, point: &SweepPoint, dir: &Path) -> Result<()> {
+    let mut config = SimulationConfig::from_table(point.table.clone())?;
+    run_dir::prepare(dir, config_path, &mut config)?;
+    let report = simulation::run(config).await;
+    run_dir::finish()?;
+    let report = report?;
+    report.write(&dir.join(run_dir::REPORT_FILE))?;
+    match report.failure() {
+        Some(failure) => Err(anyhow!(failure)),
+        None => Ok(()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn integers(values: &[i64]) -> Vec<Value> {
+        values.iter().copied().map(Value::Integer).collect()
+    }
+
+    fn floats(values: &[f64]) -> Vec<Value> {
+        values.iter().copied().map(Value::Float).collect()
+    }
+
+    #[test]
+    fn range_excludes_stop() {
+        assert_eq!(
+            parse_range("range(1, 20, 5)").unwrap(),
+            integers(&[1, 6, 11, 16])
+        );
+        assert_eq!(parse_range("range(0, 3)").unwrap(), integers(&[0, 1, 2]));
+        assert_eq!(
+            parse_range("range(0, 15, 5)").unwrap(),
+            integers(&[0, 5, 10])
+        );
+        assert_eq!(parse_range("range(3, 3)").unwrap(), integers(&[]));
+    }
+
+    #[test]
+    fn negative_range_step_counts_down() {
+        assert_eq!(
+            parse_range("range(5, 0, -2)").unwrap(),
+            integers(&[5, 3, 1])
+        );
+        assert_eq!(parse_range("range(1, 5, -1)").unwrap(), integers(&[]));
+    }
+
+    #[test]
+    fn zero_range_step_is_rejected() {
+        assert!(parse_range("range(0, 5, 0)").is_err());
+        assert!(parse_range("range(0, 5, 0.0)").is_err());
+    }
+
+    #[test]
+    fn float_range_bound_produces_floats() {
+        assert_eq!(
+            parse_range("range(0, 1, 0.25)").unwrap(),
+            floats(&[0.0, 0.25, 0.5, 0.75])
+        );
+        assert_eq!(
+            parse_range("range(0.5, 3)").unwrap(),
+            floats(&[0.5, 1.5, 2.5])
+        );
+        assert_eq!(
+            parse_range("range(1, 0, -0.5)").unwrap(),
+            floats(&[1.0, 0.5])
+        );
+    }
+
+    #[test]
+    fn range_stops_at_the_end_of_integers() {
+        assert_eq!(
+            parse_range(&format!("range({}, {}, 2)", i64::MAX - 3, i64::MAX)).unwrap(),
+            integers(&[i64::MAX - 3, i64::MAX - 1])
+        );
+        assert_eq!(
+            parse_range(&format!("range({}, {}, -5)", i64::MIN + 3, i64::MIN)).unwrap(),
+            integers(&[i64::MIN + 3])
+        );
+    }
+
+    #[test]
+    fn range_with_too_many_values_is_rejected() {
+        assert_eq!(
+            parse_range(&format!("range(0, {})", MAX_RANGE_POINTS))
+                .unwrap()
+                .len(),
+            MAX_RANGE_POINTS
+        );
+        assert!(parse_range(&format!("range(0, {})", MAX_RANGE_POINTS + 1)).is_err());
+        assert!(parse_range("range(0, 1, 0.00001)").is_err());
+        assert!(parse_range("range(0, inf)").is_err());
+        assert!(parse_range("range(0, 1, NaN)").is_err());
+    }
+
+    #[test]
+    fn malformed_range_is_rejected() {
+        for range in [
+            "range(1, 2",
+            "range 1, 2)",
+            "1, 2",
+            "range(1)",
+            "range(1, 2, 3, 4)",
+            "range(a, 2)",
+            "range(1, , 2)",
+        ] {
+            assert!(parse_range(range).is_err(), "{} parsed", range);
+        }
+    }
+
+    #[test]
+    fn sweeps_expand_to_cartesian_product() {
+        let table: Table = toml::from_str(
+            r#"
+            [[my_agent]]
+            Incrementer = { max_number_of_times = { sweep = "range(1, 20, 5)" } }
+
+            [[other_agent]]
+            Incrementer = { max_number_of_times = { sweep = [2, 8] } }
+            "#,
+        )
+        .unwrap();
+        let points = expand(&table).unwrap();
+        assert_eq!(points.len(), 8);
+
+        let mine = "my_agent[0].Incrementer.max_number_of_times";
+        let other = "other_agent[0].Incrementer.max_number_of_times";
+        let mut seen: Vec<_> = points
+            .iter()
+            .map(|point| {
+                let value = &point.table["other_agent"][0]["Incrementer"]["max_number_of_times"];
+                assert_eq!(value, &point.parameters[other]);
+                (
+                    point.parameters[mine].as_integer().unwrap(),
+                    value.as_integer().unwrap(),
+                )
+            })
+            .collect();
+        seen.sort();
+        seen.dedup();
+        assert_eq!(seen.len(), 8);
+    }
+
+    #[test]
+    fn config_without_sweeps_is_one_point() {
+        let table: Table =
+            toml::from_str("[[agent]]\nIncrementer = { max_number_of_times = 3 }").unwrap();
+        let points = expand(&table).unwrap();
+        assert_eq!(points.len(), 1);
+        assert!(points[0].parameters.is_empty());
+        assert_eq!(points[0].table, table);
+    }
+
+    #[test]
+    fn empty_sweep_is_rejected() {
+        let table: Table = toml::from_str("value = { sweep = \"range(5, 1)\" }").unwrap();
+        assert!(expand(&table).is_err());
+    }
+}
@@ -0,0 +1,227 @@
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex, OnceLock},
+    time::Duration,
+};
+
+use arbiter_core::middleware::ArbiterMiddleware;
+use arbiter_engine::machine::EventStream;
+use ethers::{
+    abi::Detokenize,
+    contract::{Contract, ContractCall, ContractDeployer, EthLogDecode, Event, LogMeta},
+    types::{Bytes, TransactionReceipt, H256, U64},
+};
+use futures::{channel::mpsc, stream, StreamExt};
+use tokio::sync::{
+    broadcast::{self, error::RecvError},
+    oneshot, Notify,
+};
+use tracing::warn;
+
+use crate::{errors::SimulationError, replay, report};
+
+/// Number of mined transactions a slow subscriber may fall behind by before
+/// it misses some.
+const MINED_CAPACITY: usize = 1024;
+
+/// How long a lookup waits for the agent that sent a transaction to be
+/// registered before giving up.
+const SENDER_TIMEOUT: Duration = Duration::from_secs(1);
+
+static MINED: OnceLock<broadcast::Sender<H256>> = OnceLock::new();
+
+static SENDERS: OnceLock<Senders> = OnceLock::new();
+
+/// The agent behind every transaction sent through [`send`], and what it
+/// called.
+#[derive(Default)]
+struct Senders {
+    sent: Mutex<HashMap<H256, Sent>>,
+    registered: Notify,
+}
+
+#[derive(Clone)]
+struct Sent {
+    agent: String,
+    calldata: Bytes,
+}
+
+fn senders() -> &'static Senders {
+    SENDERS.get_or_init(Senders::default)
+}
+
+/// Forgets the senders of earlier runs, whose transaction hashes can repeat.
+pub fn reset() {
+    senders().sent.lock().unwrap().clear();
+}
+
+/// Looks up the agent that sent `tx_hash`, waiting a little for it to be
+/// registered since its events can be streamed before [`send`] returns.
+pub async fn sender_of(tx_hash: H256) -> Option<String> {
+    Some(sent(tx_hash).await?.agent)
+}
+
+/// Looks up the calldata of `tx_hash`, waiting for it like [`sender_of`].
+pub async fn calldata_of(tx_hash: H256) -> Option<Bytes> {
+    Some(sent(tx_hash).await?.calldata)
+}
+
+async fn sent(tx_hash: H256) -> Option<Sent> {
+    let senders = senders();
+    let deadline = tokio::time::Instant::now() + SENDER_TIMEOUT;
+    loop {
+        let registered = senders.registered.notified();
+        if let Some(sent) = senders.sent.lock().unwrap().get(&tx_hash) {
+            return Some(sent.clone());
+        }
+        if tokio::time::timeout_at(deadline, registered).await.is_err() {
+            warn!("No agent registered for transaction {:?}", tx_hash);
+            return None;
+        }
+    }
+}
+
+fn mined() -> &'static broadcast::Sender<H256> {
+    MINED.get_or_init(|| broadcast::channel(MINED_CAPACITY).0)
+}
+
+/// Streams the hash of every transaction sent through [`send`] once it is
+/// mined, whether it reverted or not.
+pub fn stream_mined() -> EventStream<H256> {
+    let receiver = mined().subscribe();
+    Box::pin(stream::unfold(receiver, |mut receiver| async move {
+        loop {
+            match receiver.recv().await {
+                Ok(tx_hash) => return Some((tx_hash, receiver)),
+                Err(RecvError::Lagged(missed)) => {
+                    warn!("Missed {} mined transactions", missed)
+                }
+                Err(RecvError::Closed) => return None,
+            }
+        }
+    }))
+}
+
+/// Sends `call` on behalf of `agent` and waits for its receipt.
+///
+/// Behaviors send every transaction through here so that the events it emits
+/// can be attributed to `agent`, it shows up in the run report and it is
+/// recorded for replay.
+pub async fn send<D: Detokenize>(
+    agent: &str,
+    call: ContractCall<ArbiterMiddleware, D>,
+) -> Result<TransactionReceipt, SimulationError> {
+    let result = async {
+        let pending = call.send().await?;
+        let tx_hash = pending.tx_hash();
+        let senders = senders();
+        senders.sent.lock().unwrap().insert(
+            tx_hash,
+            Sent {
+                agent: agent.to_owned(),
+                calldata: call.tx.data().cloned().unwrap_or_default(),
+            },
+        );
+        senders.registered.notify_waiters();
+        let receipt = pending
+            .await?
+            .ok_or(SimulationError::MissingReceipt(tx_hash))?;
+        replay::record(agent, &call.tx, &receipt);
+        // Nobody listening is fine.
+        let _ = mined().send(tx_hash);
+        if receipt.status == Some(U64::zero()) {
+            return Err(SimulationError::Reverted {
+                reason: "receipt has failed status".to_owned(),
+                data: Bytes::new(),
+            });
+        }
+        Ok(receipt)
+    }
+    .await;
+    match &result {
+        Ok(receipt) => report::transaction(agent, &call.function.name, receipt),
+        Err(SimulationError::Reverted { .. }) => report::reverted(agent),
+        Err(_) => {}
+    }
+    result
+}
+
+/// Sends `call` like [`send`], sending it again up to `retries` times while it
+/// fails, e.g. for `on_error = { retry = <n> }`. Only the transaction is
+/// repeated, whatever the behavior did before sending it is not.
+pub async fn send_retrying<D: Detokenize>(
+    agent: &str,
+    call: ContractCall<ArbiterMiddleware, D>,
+    retries: u32,
+) -> Result<TransactionReceipt, SimulationError> {
+    let mut attempts = 0;
+    loop {
+        match send(agent, call.clone()).await {
+            Err(e) if attempts < retries => {
+                attempts += 1;
+                report::errored(agent);
+                warn!(
+                    "{} of {} failed, retrying ({}/{}): {}",
+                    call.function.name, agent, attempts, retries, e
+                );
+            }
+            result => return result,
+        }
+    }
+}
+
+/// Deploys the contract of `deployment`, e.g. `Token::deploy(client, args)?`,
+/// on behalf of `agent` and records the deployment for replay.
+pub async fn deploy<C>(
+    agent: &str,
+    deployment: ContractDeployer<ArbiterMiddleware, C>,
+) -> Result<C, SimulationError>
+where
+    C: From<Contract<ArbiterMiddleware>>,
+{
+    let tx = deployment.deployer.tx.clone();
+    let (contract, receipt) = deployment.send_with_receipt().await?;
+    replay::record(agent, &tx, &receipt);
+    Ok(contract)
+}
+
+/// Streams the events `event` filters for along with the log each was decoded
+/// from, e.g. to tell which transaction emitted it. Returns once the
+/// subscription is live, so no event emitted afterwards is missed.
+pub async fn stream_with_meta<D>(
+    event: Event<Arc<ArbiterMiddleware>, ArbiterMiddleware, D>,
+) -> Result<EventStream<(D, LogMeta)>, SimulationError>
+where
+    D: EthLogDecode + Send + 'static,
+{
+    // The event stream borrows `event`, so it lives in a task that forwards
+    // what it sees.
+    let (sender, receiver) = mpsc::unbounded();
+    let (ready_tx, ready_rx) = oneshot::channel();
+    tokio::spawn(async move {
+        let mut stream = match event.stream_with_meta().await {
+            Ok(stream) => {
+                let _ = ready_tx.send(Ok(()));
+                stream
+            }
+            Err(e) => {
+                let _ = ready_tx.send(Err(SimulationError::from(e)));
+                return;
+            }
+        };
+        while let Some(next) = stream.next().await {
+            match next {
+                Ok(next) => {
+                    if sender.unbounded_send(next).is_err() {
+                        return;
+                    }
+                }
+                Err(e) => warn!("Failed to decode event: {}", e),
+            }
+        }
+    });
+    ready_rx.await.map_err(|_| {
+        SimulationError::Messaging("event stream exited before subscribing".to_owned())
+    })??;
+    Ok(Box::pin(receiver))
+}
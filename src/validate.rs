@@ -0,0 +1,354 @@
+use std::{collections::BTreeMap, fmt, fs, ops::Range, path::Path};
+
+use anyhow::{Context, Result};
+use serde::{
+    de::{self, MapAccess, SeqAccess, Visitor},
+    Deserialize, Deserializer,
+};
+use toml::{Spanned, Table, Value};
+
+use crate::{
+    behaviors::Behaviors,
+    config::{self, SimulationConfig},
+    replicate, resolve, sweep,
+};
+
+/// Top-level keys of a config that are not agents, i.e. the fields of
+/// [`SimulationConfig`] in its schema.
+fn settings() -> Vec<String> {
+    config::schema()
+        .schema
+        .object
+        .map(|object| object.properties.keys().cloned().collect())
+        .unwrap_or_default()
+}
+
+/// A problem found in a config file.
+#[derive(Debug, Clone)]
+pub struct Diagnostic {
+    pub line: usize,
+    pub column: usize,
+    pub message: String,
+}
+
+/// Checks that every setting and every behavior entry of the config at `path`
+/// deserializes, collecting all problems instead of stopping at the first.
+///
+/// Only failures to read the file are returned as errors. Swept values are
+/// checked with the first value of each sweep. Problems in values that come
+/// from an included file are reported at the start of `path`.
+pub fn validate(path: &Path) -> Result<Vec<Diagnostic>> {
+    let source =
+        fs::read_to_string(path).with_context(|| format!("failed to read {}", path.display()))?;
+    let mut validator = Validator {
+        source: &source,
+        diagnostics: Vec::new(),
+    };
+    validator.check(path);
+    Ok(validator.diagnostics)
+}
+
+struct Validator<'a> {
+    source: &'a str,
+    diagnostics: Vec<Diagnostic>,
+}
+
+impl Validator<'_> {
+    fn check(&mut self, path: &Path) {
+        let nodes = match toml::from_str::<Node>(self.source) {
+            Ok(Node::Table(nodes)) => nodes,
+            Err(e) => {
+                self.report(e.span().unwrap_or(0..0), e.message().to_owned());
+                return;
+            }
+            Ok(_) => unreachable!("a TOML document is a table"),
+        };
+        let settings = settings();
+        let table = match resolve::load(path).and_then(|table| sweep::expand(&table)) {
+            Ok(mut points) => points.swap_remove(0).table,
+            Err(e) => {
+                self.report(0..0, format!("{:#}", e));
+                return;
+            }
+        };
+
+        for (key, value) in table {
+            let node = nodes.get(&key);
+            if settings.contains(&key) {
+                let setting = Table::from_iter([(key.clone(), value)]);
+                if let Err(e) = SimulationConfig::deserialize(Value::Table(setting)) {
+                    self.report_serde(node, e.message());
+                }
+                continue;
+            }
+            let Value::Array(entries) = value else {
+                let mut message = format!("`{}` is neither a setting nor an agent", key);
+                if let Some(suggestion) = suggest(&key, settings.iter().map(String::as_str)) {
+                    message += &format!(", did you mean `{}`?", suggestion);
+                }
+                self.report(node.map_or(0..0, Spanned::span), message);
+                continue;
+            };
+            let entry_nodes = match node.map(Spanned::get_ref) {
+                Some(Node::Array(entry_nodes)) => entry_nodes.iter().map(Some).collect(),
+                _ => vec![None; entries.len()],
+            };
+            for (entry, entry_node) in entries.iter().zip(entry_nodes) {
+                let entry = match replicate::first_instance(entry) {
+                    Ok(entry) => entry,
+                    Err(e) => {
+                        self.report(entry_node.map_or(0..0, Spanned::span), format!("{:#}", e));
+                        continue;
+                    }
+                };
+                if let Err(e) = Behaviors::deserialize(entry) {
+                    self.report_serde(entry_node, e.message());
+                }
+            }
+        }
+    }
+
+    /// Reports a serde error found in `node`, pointing at the offending key
+    /// and suggesting the closest expected name if one was misspelled.
+    fn report_serde(&mut self, node: Option<&Spanned<Node>>, message: &str) {
+        let names = backticked(message);
+        let mut message = message.to_owned();
+        let mut span = node.map_or(0..0, Spanned::span);
+        if let Some((name, expected)) = names.split_first() {
+            if message.starts_with("unknown field") || message.starts_with("unknown variant") {
+                if let Some(suggestion) = suggest(name, expected.iter().copied()) {
+                    message += &format!(", did you mean `{}`?", suggestion);
+                }
+            }
+            if let Some(found) = node.and_then(|node| node.get_ref().find(name)) {
+                span = found;
+            }
+        }
+        self.report(span, message);
+    }
+
+    fn report(&mut self, span: Range<usize>, message: String) {
+        let (line, column) = position(self.source, span.start);
+        self.diagnostics.push(Diagnostic {
+            line,
+            column,
+            message,
+        });
+    }
+}
+
+/// 1-based line and column of byte `offset` in `source`.
+fn position(source: &str, offset: usize) -> (usize, usize) {
+    let before = &source[..offset.min(source.len())];
+    let line = before.matches('\n').count() + 1;
+    let column = before
+        .rfind('\n')
+        .map_or(before, |newline| &before[newline + 1..])
+        .chars()
+        .count()
+        + 1;
+    (line, column)
+}
+
+/// The names quoted in backticks in a serde error message, e.g. the unknown
+/// field followed by the expected ones.
+fn backticked(message: &str) -> Vec<&str> {
+    message.split('`').skip(1).step_by(2).collect()
+}
+
+/// The candidate closest to `name`, if it is close enough to be a typo.
+fn suggest<'a>(name: &str, candidates: impl Iterator<Item = &'a str>) -> Option<&'a str> {
+    candidates
+        .map(|candidate| (edit_distance(name, candidate), candidate))
+        .filter(|(distance, _)| *distance <= (name.len() / 3).max(2))
+        .min_by_key(|(distance, _)| *distance)
+        .map(|(_, candidate)| candidate)
+}
+
+/// Levenshtein distance between `a` and `b`.
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut previous: Vec<usize> = (0..=b.len()).collect();
+    for (i, a) in a.chars().enumerate() {
+        let mut current = vec![i + 1];
+        for (j, b) in b.iter().enumerate() {
+            let substitution = previous[j] + usize::from(a != *b);
+            current.push(substitution.min(previous[j + 1] + 1).min(current[j] + 1));
+        }
+        previous = current;
+    }
+    previous[b.len()]
+}
+
+impl fmt::Display for Diagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}: {}", self.line, self.column, self.message)
+    }
+}
+
+/// The shape of a TOML document with the location of every value.
+enum Node {
+    Table(BTreeMap<String, Spanned<Node>>),
+    Array(Vec<Spanned<Node>>),
+    Scalar,
+}
+
+impl Node {
+    /// Location of the value of the first key called `name`, searching depth
+    /// first.
+    fn find(&self, name: &str) -> Option<Range<usize>> {
+        match self {
+            Node::Table(table) => table.iter().find_map(|(key, node)| {
+                if key == name {
+                    Some(node.span())
+                } else {
+                    node.get_ref().find(name)
+                }
+            }),
+            Node::Array(array) => array.iter().find_map(|node| node.get_ref().find(name)),
+            Node::Scalar => None,
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for Node {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_any(NodeVisitor)
+    }
+}
+
+struct NodeVisitor;
+
+impl<'de> Visitor<'de> for NodeVisitor {
+    type Value = Node;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("a TOML value")
+    }
+
+    fn visit_bool<E: de::Error>(self, _: bool) -> Result<Node, E> {
+        Ok(Node::Scalar)
+    }
+
+    fn visit_i64<E: de::Error>(self, _: i64) -> Result<Node, E> {
+        Ok(Node::Scalar)
+    }
+
+    fn visit_u64<E: de::Error>(self, _: u64) -> Result<Node, E> {
+        Ok(Node::Scalar)
+    }
+
+    fn visit_f64<E: de::Error>(self, _: f64) -> Result<Node, E> {
+        Ok(Node::Scalar)
+    }
+
+    fn visit_str<E: de::Error>(self, _: &str) -> Result<Node, E> {
+        Ok(Node::Scalar)
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Node, A::Error> {
+        let mut array = Vec::new();
+        while let Some(node) = seq.next_element()? {
+            array.push(node);
+        }
+        Ok(Node::Array(array))
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Node, A::Error> {
+        let mut table = BTreeMap::new();
+        while let Some((key, node)) = map.next_entry()? {
+            table.insert(key, node);
+        }
+        Ok(Node::Table(table))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::env;
+
+    use super::*;
+
+    /// Validates `config`, written to a file named after `test`.
+    fn diagnostics(test: &str, config: &str) -> Vec<Diagnostic> {
+        let path = env::temp_dir().join(format!("validate-{}-{}.toml", test, std::process::id()));
+        fs::write(&path, config).unwrap();
+        let diagnostics = validate(&path).unwrap();
+        fs::remove_file(&path).unwrap();
+        diagnostics
+    }
+
+    #[test]
+    fn misspelled_field_is_located_and_corrected() {
+        let diagnostics = diagnostics(
+            "field",
+            "seed = 1\n\n[[agent]]\nIncrementer = { max_numbr_of_times = 5 }\n",
+        );
+        assert_eq!(diagnostics.len(), 1, "{:?}", diagnostics);
+        let diagnostic = &diagnostics[0];
+        assert!(diagnostic
+            .message
+            .starts_with("unknown field `max_numbr_of_times`"));
+        assert!(diagnostic
+            .message
+            .ends_with("did you mean `max_number_of_times`?"));
+        assert_eq!((diagnostic.line, diagnostic.column), (4, 38));
+    }
+
+    #[test]
+    fn misspelled_behavior_is_located_and_corrected() {
+        let diagnostics = diagnostics(
+            "behavior",
+            "[[agent]]\nIncrementer = { max_number_of_times = 5 }\n\n[[other]]\n  Incrementr = {}",
+        );
+        assert_eq!(diagnostics.len(), 1, "{:?}", diagnostics);
+        let diagnostic = &diagnostics[0];
+        assert!(diagnostic
+            .message
+            .starts_with("unknown variant `Incrementr`"));
+        assert!(diagnostic.message.ends_with("did you mean `Incrementer`?"));
+        assert_eq!((diagnostic.line, diagnostic.column), (5, 16));
+    }
+
+    #[test]
+    fn misspelled_setting_is_corrected() {
+        let diagnostics = diagnostics("setting", "seeed = 1\n");
+        assert_eq!(diagnostics.len(), 1, "{:?}", diagnostics);
+        assert_eq!(
+            diagnostics[0].to_string(),
+            "1:9: `seeed` is neither a setting nor an agent, did you mean `seed`?"
+        );
+    }
+
+    #[test]
+    fn valid_config_has_no_diagnostics() {
+        let config = "seed = 1\n[[agent]]\nIncrementer = { max_number_of_times = 5 }\n";
+        assert!(diagnostics("valid", config).is_empty());
+    }
+
+    #[test]
+    fn settings_are_the_config_fields() {
+        assert_eq!(
+            settings(),
+            [
+                "seed",
+                "collector",
+                "max_blocks",
+                "max_transactions",
+                "max_events",
+                "timeout_secs",
+            ]
+        );
+    }
+
+    #[test]
+    fn distant_names_get_no_suggestion() {
+        let settings = settings();
+        assert_eq!(
+            suggest("seeed", settings.iter().map(String::as_str)),
+            Some("seed")
+        );
+        assert_eq!(suggest("limit", settings.iter().map(String::as_str)), None);
+        assert_eq!(edit_distance("kitten", "sitting"), 3);
+    }
+}